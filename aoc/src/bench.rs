@@ -0,0 +1,74 @@
+use aoc_core::bench::Spread;
+use aoc_core::params::Params;
+use aoc_core::problem_solver::read_lines;
+use aoc_core::registry::{find, Format, Solver};
+use std::io;
+
+use super::check::{manifest_path, read_manifest};
+
+
+const HEADER: &str = "\
+| day | part | input | runs | parse min | parse median | parse max \
+| solve min | solve median | solve max |
+|----:|-----:|:------|-----:|----------:|-------------:|----------:\
+|----------:|-------------:|----------:|";
+
+/* times every part against each input listed in its day's manifest, as a
+markdown table */
+pub fn bench(solvers: &[Solver], days: &[u8], runs: usize) -> String {
+  let mut table = vec![HEADER.to_string()];
+
+  for &day in days {
+    let expectations = match read_manifest(day) {
+      Ok(expectations) => expectations,
+      Err(err) => {
+        eprintln!("{}", err.render(&manifest_path(day).to_string_lossy()));
+        continue;
+      }
+    };
+
+    for expectation in expectations {
+      let Some(solver) = find(solvers, day, expectation.part) else {
+        continue;
+      };
+      let origin = expectation.input.display().to_string();
+      let lines = read_lines(expectation.input_path())
+        .and_then(|lines| lines.collect::<io::Result<Vec<_>>>());
+      let lines = match lines {
+        Ok(lines) => lines,
+        Err(err) => {
+          eprintln!("Failed to read input file {origin}: {err}");
+          continue;
+        }
+      };
+      eprintln!("bench day {day} part {} {origin}", expectation.part);
+
+      let solved =
+        match (solver.run)(&lines, &Params::new(), Format::Quiet, runs) {
+          Ok(solved) => solved,
+          Err(err) => {
+            eprintln!("{}", err.render(&origin));
+            continue;
+          }
+        };
+      let parse = Spread::of(solved.phases.iter().map(|p| p.parse));
+      let solve = Spread::of(solved.phases.iter().map(|p| p.solve));
+      let input = expectation.input.file_name().unwrap_or_default();
+
+      table.push(format!(
+        "| {day} | {} | {} | {runs} | {:.3?} | {:.3?} | {:.3?} | {:.3?} | \
+         {:.3?} | {:.3?} |",
+        expectation.part,
+        input.to_string_lossy(),
+        parse.min,
+        parse.median,
+        parse.max,
+        solve.min,
+        solve.median,
+        solve.max,
+      ));
+    }
+  }
+
+  table.join("\n")
+}
@@ -0,0 +1,180 @@
+use aoc_core::problem_solver::{read_lines, ParseError};
+use aoc_core::registry::{find, Solver};
+use std::{
+  env, io,
+  path::{Path, PathBuf},
+  process::Command,
+};
+
+
+/* every day folder may list the answers it is expected to produce, one
+`<part> <input file> <last line of output>` entry per line */
+const MANIFEST: &str = "expected_answers.txt";
+/* the day folders are found next to this crate, wherever `aoc` runs from */
+const WORKSPACE: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/..");
+
+/* `input` is relative to the workspace, see `Expectation::input_path` */
+#[derive(Debug)]
+pub struct Expectation {
+  pub part: u8,
+  pub input: PathBuf,
+  answer: String,
+}
+
+impl Expectation {
+  pub fn input_path(&self) -> PathBuf {
+    workspace_path(&self.input)
+  }
+}
+
+enum Outcome {
+  Pass,
+  Fail { actual: String },
+  Error(String),
+}
+
+pub fn workspace_path(path: impl AsRef<Path>) -> PathBuf {
+  Path::new(WORKSPACE).join(path)
+}
+
+/* relative to the workspace */
+pub fn manifest_path(day: u8) -> PathBuf {
+  Path::new(&format!("day_{day}")).join(MANIFEST)
+}
+
+pub fn read_manifest(day: u8) -> Result<Vec<Expectation>, ParseError> {
+  let path = manifest_path(day);
+  let lines = read_lines(workspace_path(&path))
+    .and_then(|lines| lines.collect::<io::Result<Vec<_>>>())
+    .map_err(|e| ParseError::whole_input(format!("cannot read: {e}")))?;
+
+  parse_manifest(&path, lines.into_iter())
+}
+
+/* the entries of the manifest at `path`, whose inputs must exist next to
+it. blank lines and `#` comments are skipped */
+fn parse_manifest(
+  path: &Path,
+  lines: impl Iterator<Item = String>,
+) -> Result<Vec<Expectation>, ParseError> {
+  lines
+    .enumerate()
+    .filter(|(_, line)| !line.trim().is_empty() && !line.starts_with('#'))
+    .map(|(index, line)| {
+      let mut fields = line.splitn(3, ' ');
+      let (Some(part), Some(file), Some(answer)) =
+        (fields.next(), fields.next(), fields.next())
+      else {
+        return Err(ParseError::new(
+          index,
+          &line,
+          "expected `<part> <input file> <answer>`",
+        ));
+      };
+      let part = part.parse::<u8>().map_err(|_| {
+        ParseError::new(index, &line, "part is not a number").at(part)
+      })?;
+      let input = path.with_file_name(file);
+      if !workspace_path(&input).is_file() {
+        return Err(
+          ParseError::new(index, &line, "no such input file").at(file),
+        );
+      }
+      let answer = answer.trim().to_string();
+
+      Ok(Expectation { part, input, answer })
+    })
+    .collect()
+}
+
+/* runs the solver in a child process so its printed output can be compared */
+fn evaluate(day: u8, expectation: &Expectation) -> Outcome {
+  let exe = match env::current_exe() {
+    Ok(exe) => exe,
+    Err(e) => return Outcome::Error(e.to_string()),
+  };
+  let output = Command::new(exe)
+    .arg("run")
+    .arg(day.to_string())
+    .arg(expectation.part.to_string())
+    .arg(expectation.input_path())
+    .output();
+  let output = match output {
+    Ok(output) => output,
+    Err(e) => return Outcome::Error(e.to_string()),
+  };
+  if !output.status.success() {
+    return Outcome::Error(String::from_utf8_lossy(&output.stderr).into());
+  }
+
+  let stdout = String::from_utf8_lossy(&output.stdout);
+  let actual = last_line(&stdout);
+
+  if actual == expectation.answer {
+    Outcome::Pass
+  } else {
+    Outcome::Fail { actual: actual.to_string() }
+  }
+}
+
+/* the answer is the last line printed that is not blank */
+fn last_line(stdout: &str) -> &str {
+  stdout
+    .lines()
+    .map(str::trim)
+    .rfind(|l| !l.is_empty())
+    .unwrap_or_default()
+}
+
+/* checks the manifests of the given days, returns whether all of them passed */
+pub fn check(solvers: &[Solver], days: &[u8]) -> bool {
+  let (mut passed, mut failed) = (0, 0);
+
+  for &day in days {
+    let expectations = match read_manifest(day) {
+      Ok(expectations) => expectations,
+      Err(err) => {
+        eprintln!("{}", err.render(&manifest_path(day).to_string_lossy()));
+        failed += 1;
+        continue;
+      }
+    };
+
+    for expectation in expectations {
+      let label = format!(
+        "day {day} part {} {}",
+        expectation.part,
+        expectation.input.display()
+      );
+      if find(solvers, day, expectation.part).is_none() {
+        println!("SKIP {label} (no solver registered)");
+        continue;
+      }
+
+      match evaluate(day, &expectation) {
+        Outcome::Pass => {
+          println!("PASS {label}");
+          passed += 1;
+        }
+        Outcome::Fail { actual } => {
+          println!("FAIL {label}");
+          println!("  - {}", expectation.answer);
+          println!("  + {}", actual);
+          failed += 1;
+        }
+        Outcome::Error(message) => {
+          println!("FAIL {label}");
+          message.lines().for_each(|line| println!("  ! {line}"));
+          failed += 1;
+        }
+      }
+    }
+  }
+  println!("{passed} passed, {failed} failed");
+
+  failed == 0
+}
+
+#[cfg(test)]
+#[path = "./tests/check_tests.rs"]
+mod check_tests;
@@ -0,0 +1,197 @@
+use aoc_core::bench::{report, Spread};
+use aoc_core::params::Params;
+use aoc_core::problem_solver::InputSource;
+use aoc_core::record::{input_hash, Record};
+use aoc_core::registry::{find, Format, Solver};
+use aoc_core::trace::{self, LevelFilter};
+use std::{env, fs, process};
+
+mod bench;
+mod check;
+mod registry;
+use bench::bench;
+use check::{check, manifest_path, workspace_path};
+use registry::SOLVERS;
+
+
+const USAGE: &str = "usage:
+  aoc list                      show every registered day and part
+  aoc run <day> <part> <input>  solve a part against an input file, `-` for
+                                stdin or `--input-text <text>`
+      [--format text|json]      print the solver's text or a json record
+      [--bench <runs>]          repeat and report the timing of each phase
+      [--trace]                 log the solver's steps to stderr (or RUST_LOG)
+      [--<param> <value>]       a parameter of the solver, like day 1's --top
+  aoc check [<day>...]          compare answers with each day's manifest
+  aoc bench [--runs <runs>]     time every manifest input as a markdown table
+      [--output <file>]         write the table to a file";
+
+const BENCH_RUNS: usize = 5;
+
+struct Options {
+  format: Format,
+  runs: Option<usize>,
+  trace: bool,
+  params: Params,
+}
+
+fn main() {
+  let args: Vec<String> = env::args().skip(1).collect();
+  let args: Vec<&str> = args.iter().map(String::as_str).collect();
+
+  match args.as_slice() {
+    ["list"] => list(SOLVERS),
+    ["run", day, part, args @ ..] => {
+      let Some((source, options)) = InputSource::from_args(args) else {
+        exit_with_usage()
+      };
+      run(SOLVERS, day, part, source, read_options(options))
+    }
+    ["check", days @ ..] => check_days(SOLVERS, days),
+    ["bench", options @ ..] => bench_days(SOLVERS, options),
+    _ => exit_with_usage(),
+  }
+}
+
+fn list(solvers: &[Solver]) {
+  println!("day part  name");
+  for solver in solvers {
+    println!("{:>3} {:>4}  {}", solver.day, solver.part, solver.name);
+  }
+}
+
+fn read_options(args: &[&str]) -> Options {
+  let mut options = Options {
+    format: Format::Text,
+    runs: None,
+    trace: false,
+    params: Params::new(),
+  };
+  let mut args = args.iter();
+  while let Some(&arg) = args.next() {
+    if arg == "--trace" {
+      options.trace = true;
+      continue;
+    }
+    match (arg, args.next()) {
+      ("--format", Some(&"text")) => options.format = Format::Text,
+      ("--format", Some(&"json")) => options.format = Format::Json,
+      ("--bench", Some(runs)) => options.runs = Some(read_runs(runs)),
+      ("--format", _) => exit_with_usage(),
+      (flag, Some(value)) if flag.starts_with("--") => {
+        options.params.insert(&flag[2..], value)
+      }
+      _ => exit_with_usage(),
+    }
+  }
+
+  options
+}
+
+fn run(
+  solvers: &[Solver],
+  day: &str,
+  part: &str,
+  source: InputSource,
+  options: Options,
+) {
+  let (Ok(day), Ok(part)) = (day.parse::<u8>(), part.parse::<u8>()) else {
+    exit_with_usage()
+  };
+  trace::init(if options.trace {
+    LevelFilter::TRACE
+  } else {
+    LevelFilter::WARN
+  });
+  let Some(solver) = find(solvers, day, part) else {
+    eprintln!("no solver registered for day {day} part {part} (see aoc list)");
+    process::exit(1);
+  };
+  let origin = source.origin().to_owned();
+  let lines: Vec<String> = match source.lines() {
+    Ok(lines) => lines.collect(),
+    Err(err) => {
+      eprintln!("Failed to read input file: {}", err);
+      process::exit(1);
+    }
+  };
+  let input_hash = input_hash(&lines);
+
+  let runs = options.runs.unwrap_or(1);
+  let solved = match (solver.run)(&lines, &options.params, options.format, runs)
+  {
+    Ok(solved) => solved,
+    Err(err) => {
+      eprintln!("{}", err.render(&origin));
+      process::exit(1);
+    }
+  };
+
+  if options.format == Format::Json {
+    let elapsed = Spread::of(solved.phases.iter().map(|p| p.total())).median;
+    let record = Record::new(day, part, solved.answer, elapsed, input_hash);
+    println!("{}", serde_json::to_string(&record).unwrap());
+  }
+  if options.runs.is_some() {
+    eprintln!("{}", report(&solved.phases));
+  }
+}
+
+fn check_days(solvers: &[Solver], days: &[&str]) {
+  let days: Vec<u8> = if days.is_empty() {
+    manifest_days(solvers)
+  } else {
+    match days.iter().map(|day| day.parse::<u8>()).collect() {
+      Ok(days) => days,
+      Err(_) => exit_with_usage(),
+    }
+  };
+
+  if !check(solvers, &days) {
+    process::exit(1);
+  }
+}
+
+fn bench_days(solvers: &[Solver], args: &[&str]) {
+  let mut runs = BENCH_RUNS;
+  let mut output = None;
+  let mut args = args.iter();
+  while let Some(&arg) = args.next() {
+    match (arg, args.next()) {
+      ("--runs", Some(value)) => runs = read_runs(value),
+      ("--output", Some(&path)) => output = Some(path),
+      _ => exit_with_usage(),
+    }
+  }
+
+  let table = bench(solvers, &manifest_days(solvers), runs);
+  match output {
+    Some(path) => {
+      if let Err(err) = fs::write(path, table + "\n") {
+        eprintln!("Failed to write {path}: {err}");
+        process::exit(1);
+      }
+    }
+    None => println!("{table}"),
+  }
+}
+
+/* registered days that list expected answers */
+fn manifest_days(solvers: &[Solver]) -> Vec<u8> {
+  let mut days: Vec<u8> = solvers.iter().map(|s| s.day).collect();
+  days.dedup();
+  days.retain(|&day| workspace_path(manifest_path(day)).exists());
+  days
+}
+
+fn read_runs(runs: &str) -> usize {
+  match runs.parse::<usize>() {
+    Ok(runs) if runs > 0 => runs,
+    _ => exit_with_usage(),
+  }
+}
+
+fn exit_with_usage() -> ! {
+  eprintln!("{}", USAGE);
+  process::exit(2);
+}
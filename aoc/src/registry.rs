@@ -0,0 +1,253 @@
+use aoc_core::registry::{file_runner, service_runner, Solver};
+
+
+/* days 6, 12, 15 and 19 depend on platform specific stacks and are run from
+their own crates, as is the curses replay of day 14 */
+pub const SOLVERS: &[Solver] = &[
+  Solver {
+    day: 1,
+    part: 1,
+    name: "part_1",
+    run: file_runner::<day_1::top_module::ProblemSolverPattern<1>>,
+  },
+  Solver {
+    day: 1,
+    part: 2,
+    name: "part_2",
+    run: file_runner::<day_1::top_module::ProblemSolverPattern<3>>,
+  },
+  Solver {
+    day: 2,
+    part: 1,
+    name: "rps_guess",
+    run: file_runner::<day_2::guide_module::ProblemSolverPattern<false>>,
+  },
+  Solver {
+    day: 2,
+    part: 2,
+    name: "rps_strategy",
+    run: file_runner::<day_2::guide_module::ProblemSolverPattern<true>>,
+  },
+  Solver {
+    day: 3,
+    part: 1,
+    name: "rucksack_priorities",
+    run: file_runner::<day_3::rucksack_priorities_module::ProblemSolverPattern>,
+  },
+  Solver {
+    day: 3,
+    part: 2,
+    name: "safety_badges",
+    run: file_runner::<day_3::safety_badges_module::ProblemSolverPattern>,
+  },
+  Solver {
+    day: 4,
+    part: 1,
+    name: "containment",
+    run: file_runner::<day_4::containment_module::ProblemSolverPattern>,
+  },
+  Solver {
+    day: 4,
+    part: 2,
+    name: "overlap",
+    run: file_runner::<day_4::overlap_module::ProblemSolverPattern>,
+  },
+  Solver {
+    day: 5,
+    part: 1,
+    name: "top",
+    run: file_runner::<day_5::top_module::ProblemSolverPattern>,
+  },
+  Solver {
+    day: 5,
+    part: 2,
+    name: "in_order",
+    run: file_runner::<day_5::in_order_module::ProblemSolverPattern>,
+  },
+  Solver {
+    day: 7,
+    part: 1,
+    name: "total_smalls",
+    run: file_runner::<day_7::total_smalls_module::ProblemSolverPattern>,
+  },
+  Solver {
+    day: 7,
+    part: 2,
+    name: "smallest_large",
+    run: file_runner::<day_7::smallest_large_module::ProblemSolverPattern>,
+  },
+  Solver {
+    day: 8,
+    part: 1,
+    name: "visible",
+    run: service_runner::<day_8::visible::Part1Solver>,
+  },
+  Solver {
+    day: 8,
+    part: 2,
+    name: "scenic_score",
+    run: service_runner::<day_8::scenic_score::Part2Solver>,
+  },
+  Solver {
+    day: 9,
+    part: 1,
+    name: "part_1",
+    run: service_runner::<day_9::part_1_module::CloudRunSolver>,
+  },
+  Solver {
+    day: 9,
+    part: 2,
+    name: "part_2",
+    run: service_runner::<day_9::part_2_module::CloudRunSolver>,
+  },
+  Solver {
+    day: 10,
+    part: 1,
+    name: "part1",
+    run: file_runner::<day_10::part1_mod::ProblemSolverPattern>,
+  },
+  Solver {
+    day: 10,
+    part: 2,
+    name: "part2",
+    run: file_runner::<day_10::part2_mod::ProblemSolverPattern>,
+  },
+  Solver {
+    day: 11,
+    part: 1,
+    name: "part1",
+    run: file_runner::<day_11::part1_module::ProblemSolverPattern>,
+  },
+  Solver {
+    day: 11,
+    part: 2,
+    name: "part2",
+    run: file_runner::<day_11::part2_module::ProblemSolverPattern>,
+  },
+  Solver {
+    day: 13,
+    part: 1,
+    name: "part1",
+    run: file_runner::<day_13::part1_module::ProblemSolverPattern>,
+  },
+  Solver {
+    day: 13,
+    part: 2,
+    name: "part2",
+    run: file_runner::<day_13::part2_module::ProblemSolverPattern>,
+  },
+  Solver {
+    day: 14,
+    part: 1,
+    name: "part1",
+    run: file_runner::<day_14::fill_module::ProblemSolverPattern<false>>,
+  },
+  Solver {
+    day: 14,
+    part: 2,
+    name: "part2",
+    run: file_runner::<day_14::fill_module::ProblemSolverPattern<true>>,
+  },
+  Solver {
+    day: 16,
+    part: 1,
+    name: "part1",
+    run: file_runner::<day_16::part1_solver::ProblemSolverPattern>,
+  },
+  Solver {
+    day: 16,
+    part: 2,
+    name: "part2",
+    run: file_runner::<day_16::part2_solver::ProblemSolverPattern>,
+  },
+  Solver {
+    day: 17,
+    part: 1,
+    name: "part1",
+    run: file_runner::<day_17::part1_solver::ProblemSolverPattern>,
+  },
+  Solver {
+    day: 17,
+    part: 2,
+    name: "part2",
+    run: file_runner::<day_17::part2_solver::ProblemSolverPattern>,
+  },
+  Solver {
+    day: 18,
+    part: 1,
+    name: "part1",
+    run: file_runner::<day_18::part1_solver::ProblemSolverPattern>,
+  },
+  Solver {
+    day: 18,
+    part: 2,
+    name: "part2",
+    run: file_runner::<day_18::part2_solver::ProblemSolverPattern>,
+  },
+  Solver {
+    day: 20,
+    part: 1,
+    name: "part1",
+    run: file_runner::<day_20::part1_solver::ProblemSolverPattern>,
+  },
+  Solver {
+    day: 20,
+    part: 2,
+    name: "part2",
+    run: file_runner::<day_20::part2_solver::ProblemSolverPattern>,
+  },
+  Solver {
+    day: 21,
+    part: 1,
+    name: "part1",
+    run: file_runner::<day_21::part1_solver::ProblemSolverPattern>,
+  },
+  Solver {
+    day: 21,
+    part: 2,
+    name: "part2",
+    run: file_runner::<day_21::part2_solver::ProblemSolverPattern>,
+  },
+  Solver {
+    day: 22,
+    part: 1,
+    name: "part1",
+    run: file_runner::<day_22::part1_solver::ProblemSolverPattern>,
+  },
+  Solver {
+    day: 22,
+    part: 2,
+    name: "part2",
+    run: file_runner::<day_22::part2_solver::ProblemSolverPattern>,
+  },
+  Solver {
+    day: 23,
+    part: 1,
+    name: "part1",
+    run: file_runner::<day_23::part1_solver::ProblemSolverPattern>,
+  },
+  Solver {
+    day: 23,
+    part: 2,
+    name: "part2",
+    run: file_runner::<day_23::part2_solver::ProblemSolverPattern>,
+  },
+  Solver {
+    day: 24,
+    part: 1,
+    name: "part1",
+    run: file_runner::<day_24::part1_solver::ProblemSolverPattern>,
+  },
+  Solver {
+    day: 24,
+    part: 2,
+    name: "part2",
+    run: file_runner::<day_24::part2_solver::ProblemSolverPattern>,
+  },
+  Solver {
+    day: 25,
+    part: 1,
+    name: "part1",
+    run: file_runner::<day_25::part1_solver::ProblemSolverPattern>,
+  },
+];
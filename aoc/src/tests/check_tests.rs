@@ -0,0 +1,86 @@
+use std::path::Path;
+
+use super::*;
+
+
+fn parse(source: &str) -> Result<Vec<Expectation>, ParseError> {
+  let lines = source.lines().map(String::from);
+  parse_manifest(Path::new("day_1/expected_answers.txt"), lines)
+}
+
+#[test]
+fn it_should_skip_comments_and_blank_lines() {
+  // Act
+  let expectations =
+    parse("# part input answer\n\n1 input.txt top 1 calories: 69177\n")
+      .unwrap();
+
+  // Assert
+  assert_eq!(expectations.len(), 1);
+  assert_eq!(expectations[0].part, 1);
+  assert_eq!(expectations[0].input, Path::new("day_1/input.txt"));
+  assert_eq!(expectations[0].answer, "top 1 calories: 69177");
+  assert!(expectations[0].input_path().is_file());
+}
+
+#[test]
+fn it_should_reject_a_malformed_line() {
+  // Act
+  let missing_answer = parse("# part input answer\n1 input.txt").unwrap_err();
+  let bad_part = parse("one input.txt 69177").unwrap_err();
+
+  // Assert
+  assert_eq!(
+    missing_answer.render("manifest"),
+    "error: expected `<part> <input file> <answer>`\n \
+     --> manifest:2:1\n  \
+      |\n\
+     2 | 1 input.txt\n  \
+      | ^^^^^^^^^^^"
+  );
+  assert_eq!(
+    bad_part.render("manifest"),
+    "error: part is not a number\n \
+     --> manifest:1:1\n  \
+      |\n\
+     1 | one input.txt 69177\n  \
+      | ^^^"
+  );
+}
+
+#[test]
+fn it_should_reject_an_input_that_does_not_exist() {
+  // Act
+  let err = parse("1 missing.txt 69177").unwrap_err();
+
+  // Assert
+  assert_eq!(
+    err.render("manifest"),
+    "error: no such input file\n \
+     --> manifest:1:3\n  \
+      |\n\
+     1 | 1 missing.txt 69177\n  \
+      |   ^^^^^^^^^^^"
+  );
+}
+
+#[test]
+fn it_should_compare_the_last_line_printed() {
+  // Act & Assert
+  assert_eq!(
+    last_line("parsing\n  top 3 calories: 42  \n\n"),
+    "top 3 calories: 42"
+  );
+  assert_eq!(last_line("one\ntwo"), "two");
+  assert_eq!(last_line(""), "");
+}
+
+#[test]
+fn it_should_find_manifests_from_any_directory() {
+  // Act
+  let manifest = workspace_path(manifest_path(1));
+
+  // Assert
+  assert!(manifest.is_absolute());
+  assert!(manifest.is_file());
+}
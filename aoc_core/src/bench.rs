@@ -0,0 +1,98 @@
+use std::{
+  fmt,
+  iter::Cloned,
+  slice::Iter,
+  time::{Duration, Instant},
+};
+
+use super::parse_error::ParseError;
+
+
+/* time spent in each phase of a single run */
+#[derive(Clone, Copy, Debug)]
+pub struct Phases {
+  pub parse: Duration,
+  pub solve: Duration,
+}
+
+impl Phases {
+  pub fn total(&self) -> Duration {
+    self.parse + self.solve
+  }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct Spread {
+  pub min: Duration,
+  pub median: Duration,
+  pub max: Duration,
+}
+
+impl Spread {
+  pub fn of(samples: impl Iterator<Item = Duration>) -> Self {
+    let mut samples: Vec<Duration> = samples.collect();
+    samples.sort();
+    let (Some(&min), Some(&max)) = (samples.first(), samples.last()) else {
+      return Spread {
+        min: Duration::ZERO,
+        median: Duration::ZERO,
+        max: Duration::ZERO,
+      };
+    };
+
+    Spread { min, median: samples[samples.len() / 2], max }
+  }
+}
+
+impl fmt::Display for Spread {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    write!(
+      f,
+      "min {:.3?}  median {:.3?}  max {:.3?}",
+      self.min, self.median, self.max
+    )
+  }
+}
+
+/* initializes and solves `runs` times from the same lines, handing back the
+last solution along with the timings of every run */
+pub fn repeat<'a, I, S>(
+  lines: &'a [String],
+  runs: usize,
+  initialize: impl Fn(Cloned<Iter<'a, String>>) -> Result<I, ParseError>,
+  solve: impl Fn(I) -> S,
+) -> Result<(S, Vec<Phases>), ParseError> {
+  let mut phases = Vec::with_capacity(runs);
+
+  let mut run = || {
+    let start = Instant::now();
+    let input = initialize(lines.iter().cloned())?;
+    let parse = start.elapsed();
+
+    let start = Instant::now();
+    let solution = solve(input);
+    phases.push(Phases { parse, solve: start.elapsed() });
+
+    Ok(solution)
+  };
+
+  let mut solution = run()?;
+  for _ in 1..runs {
+    solution = run()?;
+  }
+
+  Ok((solution, phases))
+}
+
+pub fn report(phases: &[Phases]) -> String {
+  format!(
+    "{} runs\n  parse  {}\n  solve  {}",
+    phases.len(),
+    Spread::of(phases.iter().map(|p| p.parse)),
+    Spread::of(phases.iter().map(|p| p.solve)),
+  )
+}
+
+#[cfg(test)]
+#[path = "./tests/bench_tests.rs"]
+mod bench_tests;
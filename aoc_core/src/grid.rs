@@ -0,0 +1,422 @@
+use serde::Serialize;
+use std::{
+  fmt,
+  ops::{Index, IndexMut},
+};
+
+use super::parse_error::ParseError;
+
+
+pub const ORTHOGONAL: [(isize, isize); 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+pub const ADJACENT: [(isize, isize); 8] = [
+  (-1, -1),
+  (0, -1),
+  (1, -1),
+  (1, 0),
+  (1, 1),
+  (0, 1),
+  (-1, 1),
+  (-1, 0),
+];
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Edge {
+  Top,
+  Bottom,
+  Left,
+  Right,
+}
+
+/* a rectangular grid stored row by row. cells are addressed by `(x, y)` with
+the origin at the top left, so `y` counts rows down and `x` columns across */
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash, Serialize)]
+pub struct Grid<T> {
+  width: usize,
+  height: usize,
+  cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+  pub fn new(width: usize, height: usize, fill: T) -> Self
+  where
+    T: Clone,
+  {
+    Grid { width, height, cells: vec![fill; width * height] }
+  }
+
+  /* every row must be as wide as the first one, otherwise the index of the
+  first offending row is handed back */
+  pub fn from_rows(
+    rows: impl IntoIterator<Item = Vec<T>>,
+  ) -> Result<Self, usize> {
+    let mut grid = Grid { width: 0, height: 0, cells: Vec::new() };
+    for (index, row) in rows.into_iter().enumerate() {
+      if index == 0 {
+        grid.width = row.len();
+      } else if row.len() != grid.width {
+        return Err(index);
+      }
+      grid.cells.extend(row);
+      grid.height += 1;
+    }
+
+    Ok(grid)
+  }
+
+  /* reads a character map, `cell` rejects a character by returning `None`
+  and `expected` describes what it accepts */
+  pub fn parse(
+    lines: impl Iterator<Item = String>,
+    expected: &str,
+    cell: impl Fn(char) -> Option<T>,
+  ) -> Result<Self, ParseError> {
+    let mut grid = Grid { width: 0, height: 0, cells: Vec::new() };
+    for (index, line) in lines.enumerate() {
+      let start = grid.cells.len();
+      for (column, c) in line.chars().enumerate() {
+        let Some(value) = cell(c) else {
+          return Err(
+            ParseError::new(index, &line, expected)
+              .at_column(column + 1, &c.to_string()),
+          );
+        };
+        grid.cells.push(value);
+      }
+
+      let width = grid.cells.len() - start;
+      if index == 0 {
+        grid.width = width;
+      }
+      if width == 0 || width != grid.width {
+        return Err(ParseError::new(
+          index,
+          &line,
+          format!("expected a row of {} cells", grid.width.max(1)),
+        ));
+      }
+      grid.height += 1;
+    }
+
+    match grid.height {
+      0 => Err(ParseError::whole_input("empty grid")),
+      _ => Ok(grid),
+    }
+  }
+
+  /* like `parse`, but short rows are padded with `fill` up to the width of
+  the longest one */
+  pub fn parse_padded(
+    lines: impl Iterator<Item = String>,
+    expected: &str,
+    fill: T,
+    cell: impl Fn(char) -> Option<T>,
+  ) -> Result<Self, ParseError>
+  where
+    T: Clone,
+  {
+    let rows = lines
+      .enumerate()
+      .map(|(index, line)| {
+        line
+          .chars()
+          .enumerate()
+          .map(|(column, c)| {
+            cell(c).ok_or_else(|| {
+              ParseError::new(index, &line, expected)
+                .at_column(column + 1, &c.to_string())
+            })
+          })
+          .collect::<Result<Vec<T>, _>>()
+      })
+      .collect::<Result<Vec<_>, _>>()?;
+    let width = rows.iter().map(Vec::len).max().unwrap_or(0);
+    if width == 0 {
+      return Err(ParseError::whole_input("empty grid"));
+    }
+
+    let height = rows.len();
+    let cells = rows
+      .into_iter()
+      .flat_map(|mut row| {
+        row.resize(width, fill.clone());
+        row
+      })
+      .collect();
+
+    Ok(Grid { width, height, cells })
+  }
+
+  pub fn width(&self) -> usize {
+    self.width
+  }
+
+  pub fn height(&self) -> usize {
+    self.height
+  }
+
+  /* the cells row by row */
+  pub fn cells(&self) -> &[T] {
+    &self.cells
+  }
+
+  pub fn into_cells(self) -> Vec<T> {
+    self.cells
+  }
+
+  /* position of `(x, y)` within `cells` */
+  pub fn index_of(&self, (x, y): (usize, usize)) -> usize {
+    y * self.width + x
+  }
+
+  pub fn contains(&self, (x, y): (isize, isize)) -> bool {
+    x >= 0 && y >= 0 && (x as usize) < self.width && (y as usize) < self.height
+  }
+
+  pub fn get(&self, (x, y): (usize, usize)) -> Option<&T> {
+    if x < self.width && y < self.height {
+      self.cells.get(y * self.width + x)
+    } else {
+      None
+    }
+  }
+
+  pub fn get_mut(&mut self, (x, y): (usize, usize)) -> Option<&mut T> {
+    if x < self.width && y < self.height {
+      self.cells.get_mut(y * self.width + x)
+    } else {
+      None
+    }
+  }
+
+  pub fn row(&self, y: usize) -> &[T] {
+    &self.cells[y * self.width..(y + 1) * self.width]
+  }
+
+  pub fn row_mut(&mut self, y: usize) -> &mut [T] {
+    &mut self.cells[y * self.width..(y + 1) * self.width]
+  }
+
+  pub fn rows(&self) -> impl DoubleEndedIterator<Item = &[T]> {
+    self.cells.chunks(self.width.max(1))
+  }
+
+  /* the cells of column `x` top to bottom, `None` past the last column */
+  pub fn column(
+    &self,
+    x: usize,
+  ) -> Option<impl DoubleEndedIterator<Item = &T>> {
+    (x < self.width).then(|| self.column_cells(x))
+  }
+
+  fn column_cells(&self, x: usize) -> impl DoubleEndedIterator<Item = &T> {
+    self.cells.iter().skip(x).step_by(self.width.max(1))
+  }
+
+  /* every cell along with its `(x, y)`, row by row */
+  pub fn iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+    let width = self.width;
+    self
+      .cells
+      .iter()
+      .enumerate()
+      .map(move |(i, cell)| ((i % width, i / width), cell))
+  }
+
+  /* first cell matching `predicate`, row by row */
+  pub fn position(
+    &self,
+    predicate: impl Fn(&T) -> bool,
+  ) -> Option<(usize, usize)> {
+    let index = self.cells.iter().position(predicate)?;
+    Some((index % self.width, index / self.width))
+  }
+
+  /* the cells at `offsets` from `(x, y)` that lie within the grid */
+  pub fn neighbors<'a>(
+    &self,
+    (x, y): (usize, usize),
+    offsets: &'a [(isize, isize)],
+  ) -> impl Iterator<Item = (usize, usize)> + 'a {
+    let (width, height) = (self.width, self.height);
+    offsets.iter().filter_map(move |&(dx, dy)| {
+      let x = x.checked_add_signed(dx).filter(|&x| x < width)?;
+      let y = y.checked_add_signed(dy).filter(|&y| y < height)?;
+      Some((x, y))
+    })
+  }
+
+  pub fn neighbors4(
+    &self,
+    position: (usize, usize),
+  ) -> impl Iterator<Item = (usize, usize)> {
+    self.neighbors(position, &ORTHOGONAL)
+  }
+
+  pub fn neighbors8(
+    &self,
+    position: (usize, usize),
+  ) -> impl Iterator<Item = (usize, usize)> {
+    self.neighbors(position, &ADJACENT)
+  }
+
+  pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+    Grid {
+      width: self.width,
+      height: self.height,
+      cells: self.cells.iter().map(f).collect(),
+    }
+  }
+
+  /* rows become columns */
+  pub fn transpose(&self) -> Self
+  where
+    T: Clone,
+  {
+    let cells = (0..self.width)
+      .flat_map(|x| self.column_cells(x).cloned())
+      .collect();
+
+    Grid { width: self.height, height: self.width, cells }
+  }
+
+  pub fn rotate_clockwise(&self) -> Self
+  where
+    T: Clone,
+  {
+    let cells = (0..self.width)
+      .flat_map(|x| self.column_cells(x).rev().cloned())
+      .collect();
+
+    Grid { width: self.height, height: self.width, cells }
+  }
+
+  pub fn rotate_counter_clockwise(&self) -> Self
+  where
+    T: Clone,
+  {
+    let cells = (0..self.width)
+      .rev()
+      .flat_map(|x| self.column_cells(x).cloned())
+      .collect();
+
+    Grid { width: self.height, height: self.width, cells }
+  }
+
+  /* appends a row below the last one. a row of another width than the
+  grid's leaves the grid as it was and its width is handed back */
+  pub fn push_row(
+    &mut self,
+    row: impl IntoIterator<Item = T>,
+  ) -> Result<(), usize> {
+    let row: Vec<T> = row.into_iter().collect();
+    if self.height == 0 {
+      self.width = row.len();
+    } else if row.len() != self.width {
+      return Err(row.len());
+    }
+    self.cells.extend(row);
+    self.height += 1;
+
+    Ok(())
+  }
+
+  /* grows the grid by `count` rows or columns of `fill` at `edge`, cells
+  keep their content but move when growing at the top or left */
+  pub fn extend(&mut self, edge: Edge, count: usize, fill: T)
+  where
+    T: Clone,
+  {
+    let width = self.width;
+    match edge {
+      Edge::Top => {
+        self.cells.splice(0..0, vec![fill; width * count]);
+        self.height += count;
+      }
+      Edge::Bottom => {
+        self.cells.resize(self.cells.len() + width * count, fill);
+        self.height += count;
+      }
+      Edge::Left | Edge::Right => {
+        let new_width = width + count;
+        let mut cells = Vec::with_capacity(new_width * self.height);
+        for row in self.cells.chunks(width.max(1)).take(self.height) {
+          if edge == Edge::Left {
+            cells.resize(cells.len() + count, fill.clone());
+          }
+          cells.extend_from_slice(row);
+          if edge == Edge::Right {
+            cells.resize(cells.len() + count, fill.clone());
+          }
+        }
+        self.cells = cells;
+        self.width = new_width;
+      }
+    }
+  }
+
+  /* grows whichever edges are needed for `(x, y)` to fall within the grid,
+  returning how far existing cells moved right and down */
+  pub fn grow_to_include(
+    &mut self,
+    (x, y): (isize, isize),
+    fill: T,
+  ) -> (usize, usize)
+  where
+    T: Clone,
+  {
+    let left = (-x).max(0) as usize;
+    let top = (-y).max(0) as usize;
+    let right = (x + 1 - self.width as isize).max(0) as usize;
+    let bottom = (y + 1 - self.height as isize).max(0) as usize;
+
+    for (edge, count) in [
+      (Edge::Left, left),
+      (Edge::Top, top),
+      (Edge::Right, right),
+      (Edge::Bottom, bottom),
+    ] {
+      if count > 0 {
+        self.extend(edge, count, fill.clone());
+      }
+    }
+
+    (left, top)
+  }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+  type Output = T;
+
+  fn index(&self, (x, y): (usize, usize)) -> &T {
+    assert!(x < self.width, "x {x} out of bounds {}", self.width);
+    &self.cells[y * self.width + x]
+  }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+  fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut T {
+    assert!(x < self.width, "x {x} out of bounds {}", self.width);
+    &mut self.cells[y * self.width + x]
+  }
+}
+
+/* one line per row, cells printed back to back */
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    for (y, row) in self.rows().enumerate() {
+      if y > 0 {
+        writeln!(f)?;
+      }
+      for cell in row {
+        write!(f, "{cell}")?;
+      }
+    }
+
+    Ok(())
+  }
+}
+
+
+#[cfg(test)]
+#[path = "./tests/grid_tests.rs"]
+mod grid_tests;
@@ -0,0 +1,241 @@
+use serde::Serialize;
+use std::fmt;
+
+
+/* the integers from `start` to `end`, both included. an interval is never
+empty, operations that could produce an empty one return an `Option` */
+#[derive(
+  Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize,
+)]
+pub struct Interval {
+  start: i64,
+  end: i64,
+}
+
+impl Interval {
+  /* `None` when `start` is past `end` */
+  pub fn new(start: i64, end: i64) -> Option<Self> {
+    (start <= end).then_some(Interval { start, end })
+  }
+
+  pub fn point(at: i64) -> Self {
+    Interval { start: at, end: at }
+  }
+
+  pub fn start(&self) -> i64 {
+    self.start
+  }
+
+  pub fn end(&self) -> i64 {
+    self.end
+  }
+
+  /* how many integers the interval holds, `[3, 5]` holds 3. saturates at
+  `u64::MAX`, one short of the 2^64 integers of `[i64::MIN, i64::MAX]` */
+  pub fn length(&self) -> u64 {
+    self.end.abs_diff(self.start).saturating_add(1)
+  }
+
+  pub fn contains(&self, other: &Interval) -> bool {
+    self.start <= other.start && other.end <= self.end
+  }
+
+  pub fn contains_point(&self, at: i64) -> bool {
+    self.start <= at && at <= self.end
+  }
+
+  /* whether the intervals share at least one integer */
+  pub fn overlaps(&self, other: &Interval) -> bool {
+    self.start <= other.end && other.start <= self.end
+  }
+
+  /* whether the intervals overlap or one ends right before the other
+  starts, so their union is a single interval */
+  pub fn touches(&self, other: &Interval) -> bool {
+    self.start <= other.end.saturating_add(1)
+      && other.start <= self.end.saturating_add(1)
+  }
+
+  pub fn intersection(&self, other: &Interval) -> Option<Interval> {
+    Interval::new(self.start.max(other.start), self.end.min(other.end))
+  }
+
+  /* the smallest interval holding both */
+  pub fn hull(&self, other: &Interval) -> Interval {
+    Interval {
+      start: self.start.min(other.start),
+      end: self.end.max(other.end),
+    }
+  }
+
+  /* what is left of the interval without `other`, up to one piece on
+  either side of it */
+  pub fn difference(&self, other: &Interval) -> [Option<Interval>; 2] {
+    if !self.overlaps(other) {
+      return [Some(*self), None];
+    }
+    [
+      Interval::new(self.start, other.start.saturating_sub(1))
+        .filter(|_| other.start > self.start),
+      Interval::new(other.end.saturating_add(1), self.end)
+        .filter(|_| other.end < self.end),
+    ]
+  }
+}
+
+impl fmt::Display for Interval {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    write!(f, "[{}, {}]", self.start, self.end)
+  }
+}
+
+/* a set of integers kept as sorted intervals, any two of which are
+separated by at least one integer outside the set */
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash, Serialize)]
+pub struct IntervalSet {
+  intervals: Vec<Interval>,
+}
+
+impl IntervalSet {
+  pub fn new() -> Self {
+    IntervalSet::default()
+  }
+
+  pub fn is_empty(&self) -> bool {
+    self.intervals.is_empty()
+  }
+
+  /* the disjoint intervals of the set, lowest first */
+  pub fn intervals(&self) -> &[Interval] {
+    &self.intervals
+  }
+
+  /* adds the interval, merging it with every interval it touches */
+  pub fn insert(&mut self, interval: Interval) {
+    let first = self
+      .intervals
+      .partition_point(|other| other.end.saturating_add(1) < interval.start);
+    let last = first
+      + self.intervals[first..]
+        .iter()
+        .take_while(|other| other.touches(&interval))
+        .count();
+    let merged = self.intervals[first..last]
+      .iter()
+      .fold(interval, |merged, other| merged.hull(other));
+    self.intervals.splice(first..last, [merged]);
+  }
+
+  /* takes the interval's integers out of the set */
+  pub fn remove(&mut self, interval: Interval) {
+    let first = self
+      .intervals
+      .partition_point(|other| other.end < interval.start);
+    let last = first
+      + self.intervals[first..]
+        .iter()
+        .take_while(|other| other.overlaps(&interval))
+        .count();
+    let remaining: Vec<Interval> = self.intervals[first..last]
+      .iter()
+      .flat_map(|other| other.difference(&interval))
+      .flatten()
+      .collect();
+    self.intervals.splice(first..last, remaining);
+  }
+
+  pub fn union(&self, other: &IntervalSet) -> IntervalSet {
+    let mut union = self.clone();
+    other
+      .intervals
+      .iter()
+      .for_each(|&interval| union.insert(interval));
+    union
+  }
+
+  pub fn intersection(&self, other: &IntervalSet) -> IntervalSet {
+    let mut intersection = IntervalSet::new();
+    let (mut left, mut right) = (0, 0);
+    while let (Some(a), Some(b)) =
+      (self.intervals.get(left), other.intervals.get(right))
+    {
+      if let Some(common) = a.intersection(b) {
+        intersection.intervals.push(common);
+      }
+      match a.end < b.end {
+        true => left += 1,
+        false => right += 1,
+      }
+    }
+    intersection
+  }
+
+  pub fn difference(&self, other: &IntervalSet) -> IntervalSet {
+    let mut difference = self.clone();
+    other
+      .intervals
+      .iter()
+      .for_each(|&interval| difference.remove(interval));
+    difference
+  }
+
+  pub fn contains(&self, interval: &Interval) -> bool {
+    let index = self
+      .intervals
+      .partition_point(|other| other.end < interval.start);
+    self
+      .intervals
+      .get(index)
+      .is_some_and(|other| other.contains(interval))
+  }
+
+  pub fn contains_point(&self, at: i64) -> bool {
+    self.contains(&Interval::point(at))
+  }
+
+  /* how many integers the set holds, saturating at `u64::MAX` like
+  `Interval::length` */
+  pub fn coverage(&self) -> u64 {
+    self
+      .intervals
+      .iter()
+      .map(Interval::length)
+      .fold(0, u64::saturating_add)
+  }
+
+  /* the runs of integers missing between the lowest and highest ones in the
+  set */
+  pub fn gaps(&self) -> impl Iterator<Item = Interval> + '_ {
+    self
+      .intervals
+      .windows(2)
+      .map(|pair| Interval { start: pair[0].end + 1, end: pair[1].start - 1 })
+  }
+
+  /* the runs of integers in `bounds` that the set is missing */
+  pub fn gaps_within(&self, bounds: Interval) -> IntervalSet {
+    IntervalSet::from_iter([bounds]).difference(self)
+  }
+}
+
+impl FromIterator<Interval> for IntervalSet {
+  fn from_iter<I: IntoIterator<Item = Interval>>(intervals: I) -> Self {
+    let mut set = IntervalSet::new();
+    intervals
+      .into_iter()
+      .for_each(|interval| set.insert(interval));
+    set
+  }
+}
+
+impl fmt::Display for IntervalSet {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    let intervals: Vec<String> =
+      self.intervals.iter().map(Interval::to_string).collect();
+    write!(f, "{{{}}}", intervals.join(", "))
+  }
+}
+
+#[cfg(test)]
+#[path = "./tests/interval_tests.rs"]
+mod interval_tests;
@@ -0,0 +1,38 @@
+//! Shared runners for the advent of code solvers.
+//!
+//! Each day implements one of the `ProblemSolver` traits and hands it to the
+//! matching `solve_problem` runner:
+//! - `problem_solver`: reads the input file named on the command line
+//! - `problem_solver_service`: solves a string payload (http services)
+//! - `problem_solver_contexts`: string payload with per-phase contexts
+//! - `problem_solver_async`: async solve and output (feature `async`)
+//!
+//! `initialize` reports malformed input as a `ParseError`, which the runners
+//! turn into a diagnostic instead of a panic. Solvers taking parameters read
+//! them in `initialize_with` from the `Params` their runner parsed. `bench` times the parse and
+//! solve phases over repeated runs (`--bench N` on the command line).
+//!
+//! `registry` describes solvers by day and part, so a single binary can pick
+//! one at runtime. Solutions are serializable, and `record` wraps them in a
+//! uniform report for machine readable output.
+//!
+//! `grid` holds the two dimensional map most puzzles are drawn on, and
+//! `interval` the integer ranges and their unions others reason about.
+//!
+//! Solvers log their intermediate steps with `tracing`; `trace` installs the
+//! subscriber, showing warnings only unless `--trace` or RUST_LOG is given.
+
+pub mod bench;
+pub mod grid;
+pub mod interval;
+pub mod params;
+pub mod parse_error;
+pub mod problem_solver;
+pub mod problem_solver_contexts;
+pub mod problem_solver_service;
+pub mod record;
+pub mod registry;
+pub mod trace;
+
+#[cfg(feature = "async")]
+pub mod problem_solver_async;
@@ -0,0 +1,73 @@
+use std::str::FromStr;
+
+use super::parse_error::ParseError;
+
+
+/* the `--<name> <value>` options following the input that are meant for the
+solver rather than its runner, like day 1's `--top 3`. names are kept without
+their dashes and a later value overrides an earlier one */
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Params(Vec<(String, String)>);
+
+impl Params {
+  pub fn new() -> Self {
+    Params::default()
+  }
+
+  /* every `--<name> <value>` pair among the arguments, flags standing alone
+  such as `--trace` are left out */
+  pub fn from_args<S: AsRef<str>>(args: &[S]) -> Self {
+    let mut params = Params::new();
+    let mut args = args.iter().map(AsRef::as_ref).peekable();
+    while let Some(arg) = args.next() {
+      let Some(name) = arg.strip_prefix("--") else {
+        continue;
+      };
+      if let Some(value) = args.next_if(|value| !value.starts_with("--")) {
+        params.insert(name, value);
+      }
+    }
+    params
+  }
+
+  pub fn with(mut self, name: &str, value: &str) -> Self {
+    self.insert(name, value);
+    self
+  }
+
+  pub fn insert(&mut self, name: &str, value: &str) {
+    self.0.push((name.to_owned(), value.to_owned()));
+  }
+
+  /* `Err` names the first parameter that is not one of `names` */
+  pub fn check(&self, names: &[&str]) -> Result<(), ParseError> {
+    match self
+      .0
+      .iter()
+      .find(|(name, _)| !names.contains(&name.as_str()))
+    {
+      Some((name, _)) if names.is_empty() => Err(ParseError::whole_input(
+        format!("--{name} is not a parameter, the solver takes none"),
+      )),
+      Some((name, _)) => Err(ParseError::whole_input(format!(
+        "--{name} is not a parameter, the solver takes --{}",
+        names.join(", --")
+      ))),
+      None => Ok(()),
+    }
+  }
+
+  /* the value given for `name`, `Err` when it does not parse as a `T` */
+  pub fn get<T: FromStr>(&self, name: &str) -> Result<Option<T>, ParseError> {
+    let Some((_, value)) = self.0.iter().rev().find(|(n, _)| n == name) else {
+      return Ok(None);
+    };
+    value.parse().map(Some).map_err(|_| {
+      ParseError::whole_input(format!("invalid value `{value}` for --{name}"))
+    })
+  }
+}
+
+#[cfg(test)]
+#[path = "./tests/params_tests.rs"]
+mod params_tests;
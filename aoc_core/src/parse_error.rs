@@ -0,0 +1,107 @@
+use std::fmt;
+
+
+/* describes input that a solver could not interpret. `line` and `column` are
+1-based; a `line` of 0 marks a problem with the input as a whole rather than
+with any single line */
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+  pub line: usize,
+  pub column: usize,
+  pub text: String,
+  pub source_line: String,
+  pub message: String,
+}
+
+impl ParseError {
+  /* flags the whole line at `index` (0-based, as from `enumerate`) */
+  pub fn new(
+    index: usize,
+    source_line: &str,
+    message: impl Into<String>,
+  ) -> Self {
+    ParseError {
+      line: index + 1,
+      column: 1,
+      text: source_line.to_string(),
+      source_line: source_line.to_string(),
+      message: message.into(),
+    }
+  }
+
+  pub fn whole_input(message: impl Into<String>) -> Self {
+    ParseError {
+      line: 0,
+      column: 0,
+      text: String::new(),
+      source_line: String::new(),
+      message: message.into(),
+    }
+  }
+
+  /* narrows the error to the first occurrence of `fragment` in the line, or
+  to the end of the line when the fragment is absent */
+  pub fn at(self, fragment: &str) -> Self {
+    let byte_offset = match fragment.is_empty() {
+      true => None,
+      false => self.source_line.find(fragment),
+    };
+    match byte_offset {
+      Some(offset) => {
+        let column = self.source_line[..offset].chars().count() + 1;
+        self.at_column(column, fragment)
+      }
+      None => {
+        let column = self.source_line.chars().count() + 1;
+        self.at_column(column, "")
+      }
+    }
+  }
+
+  pub fn at_column(mut self, column: usize, text: &str) -> Self {
+    self.column = column;
+    self.text = text.to_string();
+    self
+  }
+
+  /* compiler style diagnostic, `origin` names where the input came from */
+  pub fn render(&self, origin: &str) -> String {
+    if self.line == 0 {
+      return format!("error: {}\n --> {}", self.message, origin);
+    }
+    let gutter = " ".repeat(self.line.to_string().len());
+    let marker = format!(
+      "{}{}",
+      " ".repeat(self.column - 1),
+      "^".repeat(self.text.chars().count().max(1))
+    );
+
+    format!(
+      "error: {message}\n{gutter}--> {origin}:{line}:{column}\n{gutter} |\n\
+       {line} | {source}\n{gutter} | {marker}",
+      message = self.message,
+      line = self.line,
+      column = self.column,
+      source = self.source_line,
+    )
+  }
+}
+
+impl fmt::Display for ParseError {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    if self.line == 0 {
+      return write!(f, "{}", self.message);
+    }
+    write!(
+      f,
+      "{} (line {}, column {}: '{}')",
+      self.message, self.line, self.column, self.text
+    )
+  }
+}
+
+impl std::error::Error for ParseError {}
+
+#[cfg(test)]
+#[path = "./tests/parse_error_tests.rs"]
+mod parse_error_tests;
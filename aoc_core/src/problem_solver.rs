@@ -0,0 +1,172 @@
+use serde::Serialize;
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, Lines, Read};
+use std::path::Path;
+use std::process;
+
+use super::bench::{repeat, report};
+pub use super::params::Params;
+pub use super::parse_error::ParseError;
+use super::problem_solver_service::payload_lines;
+use super::trace;
+
+
+pub trait ProblemSolver {
+  type Input;
+  type Solution: Serialize;
+  /* the names of the `Params` that `initialize_with` reads */
+  const PARAMS: &'static [&'static str] = &[];
+
+  fn initialize(
+    lines: impl Iterator<Item = String>,
+  ) -> Result<Self::Input, ParseError>;
+  /* `initialize` given the parameters the runner was handed, which solvers
+  without `PARAMS` ignore */
+  fn initialize_with(
+    lines: impl Iterator<Item = String>,
+    _params: &Params,
+  ) -> Result<Self::Input, ParseError> {
+    Self::initialize(lines)
+  }
+  fn solve(input: Self::Input) -> Self::Solution;
+  fn output(solution: Self::Solution);
+}
+
+/* the `--<name> <value>` arguments after the input are the solver's
+parameters, others are left to the binary */
+pub fn solve_problem<T: ProblemSolver>() {
+  trace::init(trace::level_from_args());
+  let (origin, lines) = lines_from_args();
+  let params = params_from_args();
+  let Some(runs) = bench_runs_from_args() else {
+    let input = T::initialize_with(lines, &params)
+      .unwrap_or_else(|err| exit_with_diagnostic(&err, &origin));
+    let solution = T::solve(input);
+    T::output(solution);
+    return;
+  };
+
+  let lines: Vec<String> = lines.collect();
+  let initialize = |lines| T::initialize_with(lines, &params);
+  let (solution, phases) = repeat(&lines, runs, initialize, T::solve)
+    .unwrap_or_else(|err| exit_with_diagnostic(&err, &origin));
+  T::output(solution);
+  eprintln!("{}", report(&phases));
+}
+
+pub(crate) fn exit_with_diagnostic(err: &ParseError, origin: &str) -> ! {
+  eprintln!("{}", err.render(origin));
+  process::exit(1);
+}
+
+/* where a command line runner reads the puzzle input from */
+pub enum InputSource {
+  File(String),
+  Stdin,
+  Text(String),
+}
+
+impl InputSource {
+  /* `-` reads stdin, `--input-text <text>` the text itself and anything else
+  names a file, the remaining arguments are handed back */
+  pub fn from_args<S: AsRef<str>>(args: &[S]) -> Option<(Self, &[S])> {
+    match args {
+      [flag, text, rest @ ..] if flag.as_ref() == "--input-text" => {
+        Some((InputSource::Text(text.as_ref().to_owned()), rest))
+      }
+      [dash, rest @ ..] if dash.as_ref() == "-" => {
+        Some((InputSource::Stdin, rest))
+      }
+      [file, rest @ ..] if !file.as_ref().starts_with("--") => {
+        Some((InputSource::File(file.as_ref().to_owned()), rest))
+      }
+      _ => None,
+    }
+  }
+
+  /* names the input in diagnostics */
+  pub fn origin(&self) -> &str {
+    match self {
+      InputSource::File(filename) => filename,
+      InputSource::Stdin => "stdin",
+      InputSource::Text(_) => "input-text",
+    }
+  }
+
+  /* the whole input is read up front, so a read error or invalid utf-8 is
+  reported here rather than part way through parsing */
+  pub fn lines(self) -> io::Result<Box<dyn Iterator<Item = String>>> {
+    match self {
+      InputSource::File(filename) => {
+        let lines = read_lines(filename)?.collect::<io::Result<Vec<_>>>()?;
+        Ok(Box::new(lines.into_iter()))
+      }
+      InputSource::Stdin => {
+        let mut payload = String::new();
+        io::stdin().read_to_string(&mut payload)?;
+        Ok(owned_payload_lines(payload))
+      }
+      InputSource::Text(payload) => Ok(owned_payload_lines(payload)),
+    }
+  }
+}
+
+/* splits a payload the same way the service runners do */
+fn owned_payload_lines(payload: String) -> Box<dyn Iterator<Item = String>> {
+  Box::new(payload_lines(&payload).collect::<Vec<_>>().into_iter())
+}
+
+/* reads the input given by the first command line arguments, exiting with a
+message when it is missing or cannot be read */
+pub(crate) fn lines_from_args() -> (String, impl Iterator<Item = String>) {
+  let args: Vec<String> = std::env::args().skip(1).collect();
+  let Some((source, _)) = InputSource::from_args(&args) else {
+    eprintln!(
+      "must provide an input file (from context of current working \
+       directory), `-` for stdin or `--input-text <text>`"
+    );
+    process::exit(1);
+  };
+  let origin = source.origin().to_owned();
+  match source.lines() {
+    Ok(lines) => (origin, lines),
+    Err(err) => {
+      eprintln!("Failed to read input file: {}", err);
+      process::exit(1);
+    }
+  }
+}
+
+pub(crate) fn params_from_args() -> Params {
+  let args: Vec<String> = std::env::args().skip(1).collect();
+  InputSource::from_args(&args)
+    .map(|(_, args)| Params::from_args(args))
+    .unwrap_or_default()
+}
+
+/* `--bench N` after the input repeats the parse and solve phases N times and
+reports their timings */
+pub(crate) fn bench_runs_from_args() -> Option<usize> {
+  let args: Vec<String> = std::env::args().skip(1).collect();
+  let (_, args) = InputSource::from_args(&args)?;
+  let position = args.iter().position(|arg| arg == "--bench")?;
+  match args.get(position + 1).map(|runs| runs.parse::<usize>()) {
+    Some(Ok(runs)) if runs > 0 => Some(runs),
+    _ => {
+      eprintln!("--bench expects a number of runs greater than zero");
+      process::exit(1);
+    }
+  }
+}
+
+pub fn read_lines<P>(filename: P) -> std::io::Result<Lines<BufReader<File>>>
+where
+  P: AsRef<Path>,
+{
+  let file = File::open(filename)?;
+  Ok(BufReader::new(file).lines())
+}
+
+#[cfg(test)]
+#[path = "./tests/problem_solver_tests.rs"]
+mod problem_solver_tests;
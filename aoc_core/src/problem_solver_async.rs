@@ -0,0 +1,28 @@
+use async_trait::async_trait;
+use serde::Serialize;
+
+pub use super::parse_error::ParseError;
+use super::problem_solver::{exit_with_diagnostic, lines_from_args};
+use super::trace;
+
+
+#[async_trait]
+pub trait ProblemSolver {
+  type Input;
+  type Solution: Serialize;
+
+  fn initialize(
+    lines: impl Iterator<Item = String>,
+  ) -> Result<Self::Input, ParseError>;
+  async fn solve_async(input: Self::Input) -> Self::Solution;
+  async fn output_async(solution: Self::Solution);
+}
+
+pub async fn solve_problem<T: ProblemSolver>() {
+  trace::init(trace::level_from_args());
+  let (origin, lines) = lines_from_args();
+  let input = T::initialize(lines)
+    .unwrap_or_else(|err| exit_with_diagnostic(&err, &origin));
+  let solution = T::solve_async(input).await;
+  T::output_async(solution).await;
+}
@@ -1,3 +1,9 @@
+use serde::Serialize;
+
+pub use super::parse_error::ParseError;
+use super::problem_solver_service::{payload_lines, PAYLOAD_ORIGIN};
+
+
 pub struct ProblemContexts<Ctx> {
   pub initialize: Option<Ctx>,
   pub solve: Option<Ctx>,
@@ -12,13 +18,13 @@ impl<Ctx> Default for ProblemContexts<Ctx> {
 
 pub trait ProblemSolver {
   type Input;
-  type Solution;
+  type Solution: Serialize;
   type Context;
 
   fn initialize(
     lines: impl Iterator<Item = String>,
     context: Option<Self::Context>,
-  ) -> Self::Input;
+  ) -> Result<Self::Input, ParseError>;
   fn solve(
     input: Self::Input,
     context: Option<Self::Context>,
@@ -31,11 +37,14 @@ pub fn solve_problem<T: ProblemSolver>(
   payload: String,
   problem_contexts: Option<ProblemContexts<T::Context>>,
 ) -> String {
-  let lines = payload.lines().map(|line| line.to_owned());
+  let lines = payload_lines(&payload);
   let default_contexts = ProblemContexts::default();
   let contexts = problem_contexts.unwrap_or(default_contexts);
 
-  let input = T::initialize(lines, contexts.initialize);
+  let input = match T::initialize(lines, contexts.initialize) {
+    Ok(input) => input,
+    Err(err) => return err.render(PAYLOAD_ORIGIN),
+  };
   let solution = T::solve(input, contexts.solve);
 
 
@@ -0,0 +1,35 @@
+use serde::Serialize;
+
+pub use super::parse_error::ParseError;
+
+
+pub const PAYLOAD_ORIGIN: &str = "payload";
+
+pub trait ProblemSolver {
+  type Input;
+  type Solution: Serialize;
+
+  fn initialize(
+    lines: impl Iterator<Item = String>,
+  ) -> Result<Self::Input, ParseError>;
+  fn solve(input: Self::Input) -> Self::Solution;
+  fn output(solution: Self::Solution) -> String;
+}
+
+pub fn solve_problem<T: ProblemSolver>(payload: String) -> String {
+  let lines = payload_lines(&payload);
+
+  let input = match T::initialize(lines) {
+    Ok(input) => input,
+    Err(err) => return err.render(PAYLOAD_ORIGIN),
+  };
+  let solution = T::solve(input);
+
+  T::output(solution)
+}
+
+pub(crate) fn payload_lines(
+  payload: &str,
+) -> impl Iterator<Item = String> + '_ {
+  payload.lines().map(|line| line.to_owned())
+}
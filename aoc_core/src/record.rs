@@ -0,0 +1,47 @@
+use serde::Serialize;
+use serde_json::Value;
+use std::time::Duration;
+
+
+const FNV_OFFSET: u64 = 0xcbf29ce484222325;
+const FNV_PRIME: u64 = 0x100000001b3;
+
+/* uniform machine readable report of one solved part */
+#[derive(Debug, Serialize)]
+pub struct Record {
+  pub day: u8,
+  pub part: u8,
+  pub answer: Value,
+  pub elapsed_ms: f64,
+  pub input_hash: String,
+}
+
+impl Record {
+  pub fn new(
+    day: u8,
+    part: u8,
+    answer: Value,
+    elapsed: Duration,
+    input_hash: String,
+  ) -> Self {
+    let elapsed_ms = elapsed.as_secs_f64() * 1000.0;
+
+    Record { day, part, answer, elapsed_ms, input_hash }
+  }
+}
+
+/* FNV-1a over the input lines, stable between runs and toolchains so records
+of the same input can be matched up */
+pub fn input_hash<'a>(lines: impl IntoIterator<Item = &'a String>) -> String {
+  let hash = lines.into_iter().fold(FNV_OFFSET, |hash, line| {
+    line.bytes().chain(Some(b'\n')).fold(hash, |hash, byte| {
+      (hash ^ byte as u64).wrapping_mul(FNV_PRIME)
+    })
+  });
+
+  format!("{hash:016x}")
+}
+
+#[cfg(test)]
+#[path = "./tests/record_tests.rs"]
+mod record_tests;
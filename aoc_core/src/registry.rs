@@ -0,0 +1,90 @@
+use serde::Serialize;
+use serde_json::Value;
+use std::fmt;
+
+use super::bench::{repeat, Phases};
+use super::params::Params;
+use super::parse_error::ParseError;
+use super::{problem_solver, problem_solver_service};
+
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Format {
+  Text,
+  Json,
+  /* neither the solver's output nor a record, when only timings matter */
+  Quiet,
+}
+
+/* the serialized solution and the timings of every run */
+pub struct Solved {
+  pub answer: Value,
+  pub phases: Vec<Phases>,
+}
+
+/* initializes and solves a single part `runs` times from the given input
+lines and parameters, printing the solver's own output of the last run in the
+text format */
+pub type Runner =
+  fn(&[String], &Params, Format, usize) -> Result<Solved, ParseError>;
+
+#[derive(Clone, Copy)]
+pub struct Solver {
+  pub day: u8,
+  pub part: u8,
+  pub name: &'static str,
+  pub run: Runner,
+}
+
+impl fmt::Debug for Solver {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    f.debug_struct("Solver")
+      .field("day", &self.day)
+      .field("part", &self.part)
+      .field("name", &self.name)
+      .finish()
+  }
+}
+
+pub fn find(solvers: &[Solver], day: u8, part: u8) -> Option<&Solver> {
+  solvers.iter().find(|s| s.day == day && s.part == part)
+}
+
+pub fn file_runner<T: problem_solver::ProblemSolver>(
+  lines: &[String],
+  params: &Params,
+  format: Format,
+  runs: usize,
+) -> Result<Solved, ParseError> {
+  params.check(T::PARAMS)?;
+  let initialize = |lines| T::initialize_with(lines, params);
+  let (solution, phases) = repeat(lines, runs, initialize, T::solve)?;
+
+  let answer = to_answer(&solution);
+  if format == Format::Text {
+    T::output(solution);
+  }
+
+  Ok(Solved { answer, phases })
+}
+
+pub fn service_runner<T: problem_solver_service::ProblemSolver>(
+  lines: &[String],
+  params: &Params,
+  format: Format,
+  runs: usize,
+) -> Result<Solved, ParseError> {
+  params.check(&[])?;
+  let (solution, phases) = repeat(lines, runs, T::initialize, T::solve)?;
+
+  let answer = to_answer(&solution);
+  if format == Format::Text {
+    println!("{}", T::output(solution));
+  }
+
+  Ok(Solved { answer, phases })
+}
+
+fn to_answer(solution: &impl Serialize) -> Value {
+  serde_json::to_value(solution).expect("solutions serialize to json")
+}
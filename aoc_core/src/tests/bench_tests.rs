@@ -0,0 +1,38 @@
+use crate::bench::*;
+use std::time::Duration;
+
+
+#[test]
+fn it_should_spread_unsorted_samples() {
+  // Arrange
+  let samples = [5, 1, 4, 2, 3].map(Duration::from_millis);
+
+  // Act
+  let spread = Spread::of(samples.into_iter());
+
+  // Assert
+  assert_eq!(spread.min, Duration::from_millis(1));
+  assert_eq!(spread.median, Duration::from_millis(3));
+  assert_eq!(spread.max, Duration::from_millis(5));
+}
+
+#[test]
+fn it_should_take_the_upper_median_of_an_even_count() {
+  // Act
+  let spread = Spread::of([1, 2, 3, 4].map(Duration::from_millis).into_iter());
+
+  // Assert
+  assert_eq!(spread.median, Duration::from_millis(3));
+}
+
+#[test]
+fn it_should_spread_no_samples_as_zero() {
+  // Act
+  let spread = Spread::of(std::iter::empty());
+
+  // Assert
+  assert_eq!(
+    (spread.min, spread.median, spread.max),
+    (Duration::ZERO, Duration::ZERO, Duration::ZERO)
+  );
+}
@@ -0,0 +1,110 @@
+use crate::grid::*;
+
+
+fn sample() -> Grid<char> {
+  let lines = ["ab", "cd", "ef"].into_iter().map(String::from);
+  Grid::parse(lines, "expected a letter", Some).unwrap()
+}
+
+#[test]
+fn it_should_parse_a_character_map() {
+  // Act
+  let grid = sample();
+
+  // Assert
+  assert_eq!((grid.width(), grid.height()), (2, 3));
+  assert_eq!(grid[(1, 2)], 'f');
+  assert_eq!(grid.to_string(), "ab\ncd\nef");
+}
+
+#[test]
+fn it_should_point_at_the_rejected_character() {
+  // Arrange
+  let lines = ["..", ".x"].into_iter().map(String::from);
+
+  // Act
+  let err = Grid::parse(lines, "expected '.'", |c| (c == '.').then_some(c))
+    .unwrap_err();
+
+  // Assert
+  assert_eq!((err.line, err.column, err.text.as_str()), (2, 2, "x"));
+}
+
+#[test]
+fn it_should_reject_ragged_rows() {
+  // Arrange
+  let lines = ["...", "."].into_iter().map(String::from);
+
+  // Act
+  let err = Grid::parse(lines, "expected '.'", Some).unwrap_err();
+
+  // Assert
+  assert_eq!(err.line, 2);
+}
+
+#[test]
+fn it_should_keep_neighbors_within_bounds() {
+  // Arrange
+  let grid = sample();
+
+  // Act
+  let corner: Vec<_> = grid.neighbors4((0, 0)).collect();
+  let middle = grid.neighbors8((1, 1)).count();
+
+  // Assert
+  assert_eq!(corner, vec![(1, 0), (0, 1)]);
+  assert_eq!(middle, 5);
+}
+
+#[test]
+fn it_should_rotate_and_transpose() {
+  // Arrange
+  let grid = sample();
+
+  // Assert
+  assert_eq!(grid.transpose().to_string(), "ace\nbdf");
+  assert_eq!(grid.rotate_clockwise().to_string(), "eca\nfdb");
+  assert_eq!(grid.rotate_counter_clockwise().to_string(), "bdf\nace");
+}
+
+#[test]
+fn it_should_grow_to_include_outside_cells() {
+  // Arrange
+  let mut grid = sample();
+
+  // Act
+  let shift = grid.grow_to_include((-1, 3), '.');
+
+  // Assert
+  assert_eq!(shift, (1, 0));
+  assert_eq!(grid.to_string(), ".ab\n.cd\n.ef\n...");
+}
+
+#[test]
+fn it_should_read_columns_within_bounds() {
+  // Arrange
+  let grid = sample();
+
+  // Act
+  let column: Option<String> = grid.column(1).map(|cells| cells.collect());
+  let past_the_end = grid.column(2).is_none();
+
+  // Assert
+  assert_eq!(column.as_deref(), Some("bdf"));
+  assert!(past_the_end);
+}
+
+#[test]
+fn it_should_refuse_a_row_of_another_width() {
+  // Arrange
+  let mut grid = sample();
+
+  // Act
+  let pushed = grid.push_row(['g', 'h']);
+  let refused = grid.push_row(['i', 'j', 'k']);
+
+  // Assert
+  assert_eq!(pushed, Ok(()));
+  assert_eq!(refused, Err(3));
+  assert_eq!(grid.to_string(), "ab\ncd\nef\ngh");
+}
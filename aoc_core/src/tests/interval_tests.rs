@@ -0,0 +1,107 @@
+use crate::interval::*;
+
+
+fn interval(start: i64, end: i64) -> Interval {
+  Interval::new(start, end).unwrap()
+}
+
+fn set(intervals: &[(i64, i64)]) -> IntervalSet {
+  intervals
+    .iter()
+    .map(|&(start, end)| interval(start, end))
+    .collect()
+}
+
+#[test]
+fn it_should_merge_touching_intervals_but_not_separated_ones() {
+  // Act
+  let merged = set(&[(1, 3), (4, 6), (8, 9)]);
+
+  // Assert
+  assert_eq!(merged.intervals(), [interval(1, 6), interval(8, 9)]);
+  assert_eq!(merged.coverage(), 8);
+  assert_eq!(merged.gaps().collect::<Vec<_>>(), [interval(7, 7)]);
+}
+
+#[test]
+fn it_should_merge_an_interval_spanning_several() {
+  // Arrange
+  let mut intervals = set(&[(0, 1), (4, 5), (8, 9), (20, 21)]);
+
+  // Act
+  intervals.insert(interval(2, 10));
+
+  // Assert
+  assert_eq!(intervals, set(&[(0, 10), (20, 21)]));
+}
+
+#[test]
+fn it_should_split_an_interval_when_removing_its_middle() {
+  // Arrange
+  let intervals = set(&[(0, 10)]);
+
+  // Act
+  let difference = intervals.difference(&set(&[(3, 4), (10, 12)]));
+
+  // Assert
+  assert_eq!(difference, set(&[(0, 2), (5, 9)]));
+  assert!(!difference.contains_point(10));
+}
+
+#[test]
+fn it_should_intersect_sets() {
+  // Arrange
+  let left = set(&[(0, 5), (10, 15)]);
+  let right = set(&[(5, 10), (15, 20)]);
+
+  // Act
+  let intersection = left.intersection(&right);
+
+  // Assert
+  assert_eq!(intersection, set(&[(5, 5), (10, 10), (15, 15)]));
+}
+
+#[test]
+fn it_should_unite_sets() {
+  // Arrange
+  let left = set(&[(0, 5), (10, 15), (30, 32)]);
+  let right = set(&[(5, 10), (15, 20), (34, 35)]);
+
+  // Act
+  let union = left.union(&right);
+
+  // Assert
+  assert_eq!(union, set(&[(0, 20), (30, 32), (34, 35)]));
+  assert_eq!(union.coverage(), 26);
+}
+
+#[test]
+fn it_should_saturate_the_length_of_the_widest_intervals() {
+  // Arrange
+  let everything = interval(i64::MIN, i64::MAX);
+  let halves = set(&[(i64::MIN, -1), (0, i64::MAX)]);
+  let all_but_zero = set(&[(i64::MIN, -1), (1, i64::MAX)]);
+
+  // Act
+  let length = everything.length();
+
+  // Assert
+  assert_eq!(length, u64::MAX);
+  assert_eq!(halves.coverage(), u64::MAX);
+  assert_eq!(all_but_zero.coverage(), u64::MAX);
+  assert_eq!(interval(i64::MIN, -1).length(), 1 << 63);
+}
+
+#[test]
+fn it_should_find_the_gaps_within_bounds() {
+  // Arrange
+  let intervals = set(&[(-2, 3), (6, 8)]);
+
+  // Act
+  let gaps = intervals.gaps_within(interval(0, 10));
+
+  // Assert
+  assert_eq!(gaps, set(&[(4, 5), (9, 10)]));
+  assert!(intervals.contains(&interval(0, 3)));
+  assert!(!intervals.contains(&interval(3, 6)));
+}
@@ -0,0 +1,67 @@
+use crate::params::*;
+
+
+#[test]
+fn it_should_pair_names_with_values_and_skip_lone_flags() {
+  // Arrange
+  let args = [
+    "--trace", "--top", "5", "--render", "--group", "-2", "stray",
+  ];
+
+  // Act
+  let params = Params::from_args(&args);
+
+  // Assert
+  assert_eq!(params, Params::new().with("top", "5").with("group", "-2"));
+}
+
+#[test]
+fn it_should_read_the_last_value_given() {
+  // Arrange
+  let params = Params::new().with("top", "5").with("top", "7");
+
+  // Act
+  let top = params.get::<usize>("top");
+  let group = params.get::<usize>("group");
+
+  // Assert
+  assert_eq!(top, Ok(Some(7)));
+  assert_eq!(group, Ok(None));
+}
+
+#[test]
+fn it_should_reject_a_value_of_the_wrong_type() {
+  // Arrange
+  let params = Params::new().with("top", "five");
+
+  // Act
+  let err = params.get::<usize>("top").unwrap_err();
+
+  // Assert
+  assert_eq!(
+    err.render("input"),
+    "error: invalid value `five` for --top\n --> input"
+  );
+}
+
+#[test]
+fn it_should_reject_parameters_the_solver_does_not_take() {
+  // Arrange
+  let params = Params::new().with("top", "3").with("tpo", "4");
+
+  // Act
+  let known = params.check(&["top", "tpo"]);
+  let typo = params.check(&["top"]).unwrap_err();
+  let none = params.check(&[]).unwrap_err();
+
+  // Assert
+  assert!(known.is_ok());
+  assert_eq!(
+    typo.message,
+    "--tpo is not a parameter, the solver takes --top"
+  );
+  assert_eq!(
+    none.message,
+    "--top is not a parameter, the solver takes none"
+  );
+}
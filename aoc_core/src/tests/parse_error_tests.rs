@@ -0,0 +1,38 @@
+use crate::parse_error::*;
+
+
+#[test]
+fn it_should_point_at_a_fragment_of_the_line() {
+  // Act
+  let err =
+    ParseError::new(2, "move 1 from x to 2", "expected a stack").at("x");
+
+  // Assert
+  assert_eq!((err.line, err.column, err.text.as_str()), (3, 13, "x"));
+  assert_eq!(
+    err.render("input.txt"),
+    "error: expected a stack\n --> input.txt:3:13\n  |\n3 | move 1 from x \
+     to 2\n  |             ^"
+  );
+}
+
+#[test]
+fn it_should_point_past_the_end_for_a_missing_fragment() {
+  // Act
+  let err = ParseError::new(0, "abc", "expected more").at("z");
+
+  // Assert
+  assert_eq!((err.column, err.text.as_str()), (4, ""));
+  assert!(err.render("-").ends_with("1 | abc\n  |    ^"));
+}
+
+#[test]
+fn it_should_render_a_whole_input_error_without_a_line() {
+  // Act
+  let err = ParseError::whole_input("empty input");
+
+  // Assert
+  assert_eq!(err.line, 0);
+  assert_eq!(err.render("stdin"), "error: empty input\n --> stdin");
+  assert_eq!(err.to_string(), "empty input");
+}
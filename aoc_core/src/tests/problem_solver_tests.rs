@@ -0,0 +1,64 @@
+use crate::problem_solver::*;
+use std::{env, fs, io, process};
+
+
+#[test]
+fn it_should_read_the_input_source_from_the_arguments() {
+  // Arrange
+  let file = ["input.txt", "--trace"];
+  let text = ["--input-text", "1\n2", "--bench", "3"];
+
+  // Act
+  let (file_source, file_rest) = InputSource::from_args(&file).unwrap();
+  let (text_source, text_rest) = InputSource::from_args(&text).unwrap();
+  let (stdin_source, _) = InputSource::from_args(&["-"]).unwrap();
+
+  // Assert
+  assert_eq!((file_source.origin(), file_rest), ("input.txt", &file[1..]));
+  assert_eq!(
+    (text_source.origin(), text_rest),
+    ("input-text", &text[2..])
+  );
+  assert_eq!(stdin_source.origin(), "stdin");
+  assert!(InputSource::from_args(&["--trace"]).is_none());
+  assert!(InputSource::from_args::<&str>(&[]).is_none());
+}
+
+#[test]
+fn it_should_split_input_text_into_lines() {
+  // Arrange
+  let source = InputSource::Text("a\nb\n".to_owned());
+
+  // Act
+  let lines: Vec<String> = source.lines().unwrap().collect();
+
+  // Assert
+  assert_eq!(lines, ["a", "b"]);
+}
+
+#[test]
+fn it_should_report_invalid_utf8_in_a_file_as_an_error() {
+  // Arrange
+  let path = env::temp_dir().join(format!("aoc-invalid-{}", process::id()));
+  fs::write(&path, b"valid\n\xff\xfe\n").unwrap();
+  let source = InputSource::File(path.to_string_lossy().into_owned());
+
+  // Act
+  let err = source.lines().err();
+  fs::remove_file(&path).unwrap();
+
+  // Assert
+  assert_eq!(err.map(|err| err.kind()), Some(io::ErrorKind::InvalidData));
+}
+
+#[test]
+fn it_should_report_a_missing_file_as_an_error() {
+  // Arrange
+  let source = InputSource::File("no/such/input.txt".to_owned());
+
+  // Act
+  let err = source.lines().err();
+
+  // Assert
+  assert_eq!(err.map(|err| err.kind()), Some(io::ErrorKind::NotFound));
+}
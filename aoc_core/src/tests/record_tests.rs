@@ -0,0 +1,25 @@
+use crate::record::*;
+
+
+#[test]
+fn it_should_hash_the_input_with_fnv_1a() {
+  // Arrange
+  let lines = ["ab".to_owned(), "c".to_owned()];
+
+  // Act
+  let hash = input_hash(&lines);
+
+  // Assert
+  assert_eq!(hash, "55b7cb2f21b10c89");
+  assert_eq!(input_hash(&[]), "cbf29ce484222325");
+}
+
+#[test]
+fn it_should_hash_line_breaks() {
+  // Arrange
+  let split = ["ab".to_owned(), "c".to_owned()];
+  let joined = ["abc".to_owned()];
+
+  // Act & Assert
+  assert_ne!(input_hash(&split), input_hash(&joined));
+}
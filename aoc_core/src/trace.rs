@@ -0,0 +1,33 @@
+use std::{
+  env,
+  io::{self, IsTerminal},
+};
+pub use tracing_subscriber::filter::LevelFilter;
+use tracing_subscriber::EnvFilter;
+
+
+/* solvers report their intermediate steps through `tracing` on stderr, only
+warnings show unless `--trace` is given or RUST_LOG selects what to show, so
+stdout carries nothing but the answers */
+pub fn init(default: LevelFilter) {
+  let filter = EnvFilter::builder()
+    .with_default_directive(default.into())
+    .from_env_lossy();
+
+  /* a subscriber installed earlier (tests, services) is kept */
+  let _ = tracing_subscriber::fmt()
+    .with_env_filter(filter)
+    .with_writer(io::stderr)
+    .with_ansi(io::stderr().is_terminal())
+    .without_time()
+    .try_init();
+}
+
+/* `--trace` anywhere on the command line shows every event */
+pub fn level_from_args() -> LevelFilter {
+  if env::args().skip(1).any(|arg| arg == "--trace") {
+    LevelFilter::TRACE
+  } else {
+    LevelFilter::WARN
+  }
+}
@@ -0,0 +1,114 @@
+use aoc_core::problem_solver::ParseError;
+use serde::Serialize;
+use std::{cmp::Reverse, collections::BinaryHeap, convert::Infallible, iter};
+
+
+/* the calories one elf carries, `index` counts elves from 1 in input order */
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+pub struct Elf {
+  pub index: usize,
+  pub total: u64,
+  pub items: usize,
+}
+
+impl Elf {
+  pub fn mean(&self) -> f64 {
+    self.total as f64 / self.items as f64
+  }
+}
+
+/* groups of calorie lines separated by blank lines, the last group does not
+need a blank line after it */
+pub fn read_elves(
+  lines: impl Iterator<Item = String>,
+) -> Result<Vec<Elf>, ParseError> {
+  elves(lines).collect()
+}
+
+/* the elves as their groups of lines are read, the first malformed line
+ends them with an error */
+pub fn elves(
+  lines: impl Iterator<Item = String>,
+) -> impl Iterator<Item = Result<Elf, ParseError>> {
+  let mut lines = lines.enumerate();
+  let mut count = 0;
+  iter::from_fn(move || {
+    let mut carried: Option<Elf> = None;
+    for (index, line) in lines.by_ref() {
+      let calories = line.trim();
+      if calories.is_empty() {
+        match carried {
+          Some(_) => break,
+          None => continue,
+        }
+      }
+      let calories = match calories.parse::<u64>() {
+        Ok(calories) => calories,
+        Err(_) => {
+          return Some(Err(
+            ParseError::new(index, &line, "expected a number of calories")
+              .at(calories),
+          ))
+        }
+      };
+      let elf =
+        carried.get_or_insert(Elf { index: count + 1, total: 0, items: 0 });
+      elf.total += calories;
+      elf.items += 1;
+    }
+    count += usize::from(carried.is_some());
+    carried.map(Ok)
+  })
+}
+
+/* the `n` elves carrying the most, most first. a min-heap of at most `n`
+elves is kept so the whole list is never sorted */
+pub fn top_elves(elves: impl IntoIterator<Item = Elf>, n: usize) -> Vec<Elf> {
+  match read_top_elves(elves.into_iter().map(Ok::<_, Infallible>), n) {
+    Ok(top) => top,
+    Err(never) => match never {},
+  }
+}
+
+/* like `top_elves`, folding the elves into the heap as they are read and
+stopping at the first error */
+pub fn read_top_elves<E>(
+  elves: impl Iterator<Item = Result<Elf, E>>,
+  n: usize,
+) -> Result<Vec<Elf>, E> {
+  let mut heap: BinaryHeap<Reverse<(u64, Reverse<usize>, usize)>> =
+    BinaryHeap::with_capacity(n + 1);
+  for elf in elves {
+    let elf = elf?;
+    heap.push(Reverse((elf.total, Reverse(elf.index), elf.items)));
+    if heap.len() > n {
+      heap.pop();
+    }
+  }
+
+  Ok(
+    heap
+      .into_sorted_vec()
+      .into_iter()
+      .map(|Reverse((total, Reverse(index), items))| Elf {
+        index,
+        total,
+        items,
+      })
+      .collect(),
+  )
+}
+
+/* every elf with its rank, 1 for the one carrying the most, in rank order.
+ties are ranked by input order */
+pub fn ranked(elves: &[Elf]) -> Vec<(usize, Elf)> {
+  top_elves(elves.iter().copied(), elves.len())
+    .into_iter()
+    .enumerate()
+    .map(|(rank, elf)| (rank + 1, elf))
+    .collect()
+}
+
+#[cfg(test)]
+#[path = "./tests/calories_tests.rs"]
+mod calories_tests;
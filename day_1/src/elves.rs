@@ -0,0 +1,49 @@
+use aoc_core::problem_solver::InputSource;
+use day_1::calories::{ranked, read_elves};
+use std::{env, process};
+
+
+/* `<input> [--top N]` prints every elf, or the `N` carrying the most, in
+rank order with the calories they carry */
+fn main() {
+  let args: Vec<String> = env::args().skip(1).collect();
+  let Some((source, rest)) = InputSource::from_args(&args) else {
+    exit_with(
+      "usage: elves <input file | - | --input-text <text>> [--top N]"
+        .to_owned(),
+    );
+  };
+  let top = match rest {
+    [] => None,
+    [flag, n] if flag == "--top" => {
+      Some(n.parse::<usize>().unwrap_or_else(|_| {
+        exit_with(format!("--top expects a number of elves, not {n}"))
+      }))
+    }
+    _ => exit_with(format!("unexpected arguments: {}", rest.join(" "))),
+  };
+
+  let origin = source.origin().to_owned();
+  let lines = source
+    .lines()
+    .unwrap_or_else(|err| exit_with(format!("Failed to open input: {err}")));
+  let elves =
+    read_elves(lines).unwrap_or_else(|err| exit_with(err.render(&origin)));
+
+  println!("rank  elf  calories  items     mean");
+  let ranked = ranked(&elves);
+  for (rank, elf) in ranked.iter().take(top.unwrap_or(elves.len())) {
+    println!(
+      "{rank:>4} {:>4} {:>9} {:>6} {:>8.1}",
+      elf.index,
+      elf.total,
+      elf.items,
+      elf.mean()
+    );
+  }
+}
+
+fn exit_with(message: String) -> ! {
+  eprintln!("{message}");
+  process::exit(1);
+}
@@ -0,0 +1,2 @@
+pub mod calories;
+pub mod top_module;
@@ -0,0 +1,85 @@
+use crate::calories::*;
+
+
+fn lines(input: &str) -> impl Iterator<Item = String> + '_ {
+  input.lines().map(String::from)
+}
+
+fn totals(elves: &[Elf]) -> Vec<(usize, u64)> {
+  elves.iter().map(|elf| (elf.index, elf.total)).collect()
+}
+
+#[test]
+fn it_should_count_the_last_elf_without_a_trailing_blank_line() {
+  // Act
+  let elves = read_elves(lines("1000\n2000\n\n4000\n\n5000\n6000")).unwrap();
+
+  // Assert
+  assert_eq!(totals(&elves), [(1, 3000), (2, 4000), (3, 11000)]);
+  assert_eq!(elves[2].items, 2);
+}
+
+#[test]
+fn it_should_skip_repeated_blank_lines() {
+  // Act
+  let elves = read_elves(lines("\n1\n\n\n\n2\n\n")).unwrap();
+
+  // Assert
+  assert_eq!(totals(&elves), [(1, 1), (2, 2)]);
+}
+
+#[test]
+fn it_should_reject_a_line_that_is_not_a_number() {
+  // Act
+  let err = read_elves(lines("1000\n\n20x0\n")).unwrap_err();
+
+  // Assert
+  assert_eq!(
+    err.render("input"),
+    "error: expected a number of calories\n \
+     --> input:3:1\n  \
+      |\n\
+     3 | 20x0\n  \
+      | ^^^^"
+  );
+  assert!(read_elves(lines("-5")).is_err());
+}
+
+#[test]
+fn it_should_keep_the_elves_carrying_the_most() {
+  // Arrange
+  let input = "1\n\n5\n\n3\n\n5\n\n4";
+
+  // Act
+  let top = read_top_elves(elves(lines(input)), 3).unwrap();
+
+  // Assert
+  assert_eq!(totals(&top), [(2, 5), (4, 5), (5, 4)]);
+  assert_eq!(read_top_elves(elves(lines(input)), 0).unwrap(), []);
+  assert_eq!(read_top_elves(elves(lines(input)), 9).unwrap().len(), 5);
+}
+
+#[test]
+fn it_should_stop_folding_at_the_first_malformed_line() {
+  // Act
+  let top = read_top_elves(elves(lines("1\n\nx\n\n2")), 2);
+
+  // Assert
+  assert!(top.is_err());
+}
+
+#[test]
+fn it_should_rank_every_elf() {
+  // Arrange
+  let elves = read_elves(lines("3\n\n7\n\n3")).unwrap();
+
+  // Act
+  let ranked = ranked(&elves);
+
+  // Assert
+  let ranks: Vec<(usize, usize)> = ranked
+    .iter()
+    .map(|(rank, elf)| (*rank, elf.index))
+    .collect();
+  assert_eq!(ranks, [(1, 2), (2, 1), (3, 3)]);
+}
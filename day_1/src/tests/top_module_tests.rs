@@ -0,0 +1,49 @@
+use aoc_core::problem_solver::{Params, ProblemSolver};
+
+use super::*;
+
+
+fn lines() -> impl Iterator<Item = String> {
+  "1000\n2000\n\n4000\n\n5000\n6000\n\n7000\n8000\n9000\n\n10000"
+    .lines()
+    .map(String::from)
+}
+
+#[test]
+fn it_should_sum_the_top_n_elves_by_default() {
+  // Act
+  let input = ProblemSolverPattern::<3>::initialize(lines()).unwrap();
+  let solution = ProblemSolverPattern::<3>::solve(input);
+
+  // Assert
+  assert_eq!((solution.n, solution.calories), (3, 45000));
+}
+
+#[test]
+fn it_should_take_the_top_parameter_over_n() {
+  // Arrange
+  let params = Params::new().with("top", "2");
+
+  // Act
+  let input =
+    ProblemSolverPattern::<3>::initialize_with(lines(), &params).unwrap();
+  let solution = ProblemSolverPattern::<3>::solve(input);
+
+  // Assert
+  assert_eq!((solution.n, solution.calories), (2, 35000));
+}
+
+#[test]
+fn it_should_reject_a_top_parameter_that_is_not_a_number() {
+  // Arrange
+  let params = Params::new().with("top", "three");
+
+  // Act
+  let result = ProblemSolverPattern::<3>::initialize_with(lines(), &params);
+
+  // Assert
+  assert_eq!(
+    result.err().map(|err| err.message),
+    Some("invalid value `three` for --top".to_owned())
+  );
+}
@@ -0,0 +1,59 @@
+use aoc_core::problem_solver::{Params, ParseError, ProblemSolver};
+use serde::Serialize;
+
+use super::calories::{elves, read_top_elves, Elf};
+
+
+pub struct PSInput {
+  top: Vec<Elf>,
+  n: usize,
+}
+
+#[derive(Serialize)]
+pub struct PSSolution {
+  top: Vec<Elf>,
+  n: usize,
+  calories: u64,
+}
+
+/* the calories carried by the `N` elves carrying the most, or by as many as
+the `top` parameter asks for */
+pub struct ProblemSolverPattern<const N: usize>;
+
+impl<const N: usize> ProblemSolver for ProblemSolverPattern<N> {
+  type Input = PSInput;
+  type Solution = PSSolution;
+  const PARAMS: &'static [&'static str] = &["top"];
+
+  fn initialize(
+    lines: impl Iterator<Item = String>,
+  ) -> Result<Self::Input, ParseError> {
+    Self::initialize_with(lines, &Params::new())
+  }
+
+  fn initialize_with(
+    lines: impl Iterator<Item = String>,
+    params: &Params,
+  ) -> Result<Self::Input, ParseError> {
+    let n = params.get("top")?.unwrap_or(N);
+
+    Ok(PSInput { top: read_top_elves(elves(lines), n)?, n })
+  }
+
+  fn solve(input: Self::Input) -> Self::Solution {
+    let calories = input.top.iter().map(|elf| elf.total).sum();
+
+    PSSolution { top: input.top, n: input.n, calories }
+  }
+
+  fn output(solution: Self::Solution) {
+    for elf in &solution.top {
+      tracing::debug!(index = elf.index, total = elf.total, "top elf");
+    }
+    println!("top {} calories: {}", solution.n, solution.calories);
+  }
+}
+
+#[cfg(test)]
+#[path = "./tests/top_module_tests.rs"]
+mod top_module_tests;
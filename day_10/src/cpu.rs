@@ -0,0 +1,316 @@
+use aoc_core::problem_solver::ParseError;
+use serde::{Serialize, Serializer};
+use std::{collections::BTreeMap, fmt};
+
+
+/* a mnemonic, how many integer arguments it takes, how many cycles it runs
+for, at least one, and the registers it leaves once its last cycle is over,
+`None` when they would overflow */
+#[derive(Clone, Copy, Debug)]
+pub struct Opcode {
+  pub mnemonic: &'static str,
+  pub arity: usize,
+  pub cycles: usize,
+  pub execute: fn(Registers, &[i32]) -> Option<Registers>,
+}
+
+pub const NOOP: Opcode = Opcode {
+  mnemonic: "noop",
+  arity: 0,
+  cycles: 1,
+  execute: |registers, _| Some(registers),
+};
+
+pub const ADDX: Opcode = Opcode {
+  mnemonic: "addx",
+  arity: 1,
+  cycles: 2,
+  execute: |registers, arguments| {
+    Some(Registers { x: registers.x.checked_add(arguments[0])? })
+  },
+};
+
+/* an opcode with its arguments, as many as its arity */
+#[derive(Clone, Debug)]
+pub struct Instruction {
+  pub opcode: Opcode,
+  pub arguments: Vec<i32>,
+}
+
+impl Instruction {
+  pub fn cycles(&self) -> usize {
+    self.opcode.cycles
+  }
+
+  /* applied once the instruction's last cycle is over */
+  pub fn execute(&self, registers: Registers) -> Option<Registers> {
+    (self.opcode.execute)(registers, &self.arguments)
+  }
+}
+
+impl fmt::Display for Instruction {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    write!(f, "{}", self.opcode.mnemonic)?;
+    self
+      .arguments
+      .iter()
+      .try_for_each(|argument| write!(f, " {argument}"))
+  }
+}
+
+impl Serialize for Instruction {
+  fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.collect_str(self)
+  }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+pub struct Registers {
+  pub x: i32,
+}
+
+impl Default for Registers {
+  fn default() -> Self {
+    Registers { x: 1 }
+  }
+}
+
+/* the opcodes the assembler understands, register one to extend it */
+pub struct InstructionTable(BTreeMap<&'static str, Opcode>);
+
+impl Default for InstructionTable {
+  fn default() -> Self {
+    InstructionTable(BTreeMap::from([
+      (NOOP.mnemonic, NOOP),
+      (ADDX.mnemonic, ADDX),
+    ]))
+  }
+}
+
+impl InstructionTable {
+  /* `Err` for an opcode running for no cycles, it would never complete */
+  pub fn with(mut self, opcode: Opcode) -> Result<Self, String> {
+    if opcode.cycles == 0 {
+      return Err(format!(
+        "'{}' must run for at least one cycle",
+        opcode.mnemonic
+      ));
+    }
+    self.0.insert(opcode.mnemonic, opcode);
+    Ok(self)
+  }
+
+  pub fn decode(
+    &self,
+    index: usize,
+    line: &str,
+  ) -> Result<Instruction, ParseError> {
+    let mut tokens = tokens(line);
+    let (column, mnemonic) = tokens
+      .next()
+      .ok_or_else(|| ParseError::new(index, line, "missing command"))?;
+    let Some(&opcode) = self.0.get(mnemonic) else {
+      let known: Vec<String> = self
+        .0
+        .keys()
+        .map(|mnemonic| format!("'{mnemonic}'"))
+        .collect();
+      return Err(
+        ParseError::new(
+          index,
+          line,
+          format!("expected one of {}", known.join(", ")),
+        )
+        .at_column(column, mnemonic),
+      );
+    };
+    let arguments = tokens
+      .map(|(column, argument)| {
+        argument.parse::<i32>().map_err(|_| {
+          ParseError::new(index, line, "expected an integer argument")
+            .at_column(column, argument)
+        })
+      })
+      .collect::<Result<Vec<_>, _>>()?;
+    if arguments.len() != opcode.arity {
+      return Err(
+        ParseError::new(
+          index,
+          line,
+          format!("'{mnemonic}' takes {} argument(s)", opcode.arity),
+        )
+        .at_column(column, mnemonic),
+      );
+    }
+
+    Ok(Instruction { opcode, arguments })
+  }
+
+  pub fn assemble(
+    &self,
+    lines: impl Iterator<Item = String>,
+  ) -> Result<Vec<Instruction>, ParseError> {
+    lines
+      .enumerate()
+      .map(|(index, line)| self.decode(index, &line))
+      .collect()
+  }
+
+  /* the assembled program, `Err` also when running it would fault, pointing
+  at the instruction that does */
+  pub fn load(
+    &self,
+    lines: impl Iterator<Item = String>,
+  ) -> Result<Vec<Instruction>, ParseError> {
+    let lines: Vec<String> = lines.collect();
+    let program = self.assemble(lines.iter().cloned())?;
+    Cpu::new(program.clone()).run().map_err(|fault| {
+      ParseError::new(fault.pc, &lines[fault.pc], fault.to_string())
+    })?;
+
+    Ok(program)
+  }
+}
+
+/* the whitespace separated tokens of `line`, each with its 1-based column */
+fn tokens(line: &str) -> impl Iterator<Item = (usize, &str)> {
+  let mut column = 1;
+  line.split(char::is_whitespace).filter_map(move |token| {
+    let start = column;
+    column += token.chars().count() + 1;
+    (!token.is_empty()).then_some((start, token))
+  })
+}
+
+/* what the cpu looks like *during* a cycle, before the instruction in flight
+completes */
+#[derive(Clone, Debug)]
+pub struct Tick {
+  pub cycle: usize,
+  pub pc: usize,
+  pub instruction: Instruction,
+  pub registers: Registers,
+}
+
+/* an instruction whose registers would overflow, the cpu halts on it */
+#[derive(Clone, Debug)]
+pub struct Fault {
+  pub cycle: usize,
+  pub pc: usize,
+  pub instruction: Instruction,
+}
+
+impl fmt::Display for Fault {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    write!(
+      f,
+      "'{}' overflows x on cycle {}",
+      self.instruction, self.cycle
+    )
+  }
+}
+
+type Hook<'a> = Box<dyn FnMut(&Tick) + 'a>;
+
+/* runs a program one cycle at a time, calling every hook on each cycle */
+pub struct Cpu<'a> {
+  program: Vec<Instruction>,
+  registers: Registers,
+  pc: usize,
+  cycle: usize,
+  remaining: usize,
+  fault: Option<Fault>,
+  hooks: Vec<Hook<'a>>,
+}
+
+impl<'a> Cpu<'a> {
+  pub fn new(program: Vec<Instruction>) -> Self {
+    Cpu {
+      program,
+      registers: Registers::default(),
+      pc: 0,
+      cycle: 0,
+      remaining: 0,
+      fault: None,
+      hooks: Vec::new(),
+    }
+  }
+
+  pub fn on_cycle(&mut self, hook: impl FnMut(&Tick) + 'a) {
+    self.hooks.push(Box::new(hook));
+  }
+
+  pub fn registers(&self) -> Registers {
+    self.registers
+  }
+
+  pub fn pc(&self) -> usize {
+    self.pc
+  }
+
+  /* cycles completed so far */
+  pub fn cycle(&self) -> usize {
+    self.cycle
+  }
+
+  pub fn program(&self) -> &[Instruction] {
+    &self.program
+  }
+
+  pub fn fault(&self) -> Option<&Fault> {
+    self.fault.as_ref()
+  }
+
+  pub fn is_halted(&self) -> bool {
+    self.pc >= self.program.len() || self.fault.is_some()
+  }
+
+  /* advances a single cycle, `None` once the program has run out or
+  faulted */
+  pub fn step(&mut self) -> Option<Tick> {
+    if self.fault.is_some() {
+      return None;
+    }
+    let instruction = self.program.get(self.pc)?.clone();
+    if self.remaining == 0 {
+      self.remaining = instruction.cycles();
+    }
+    self.cycle += 1;
+
+    let tick = Tick {
+      cycle: self.cycle,
+      pc: self.pc,
+      instruction,
+      registers: self.registers,
+    };
+    self.hooks.iter_mut().for_each(|hook| hook(&tick));
+
+    self.remaining -= 1;
+    if self.remaining == 0 {
+      match tick.instruction.execute(self.registers) {
+        Some(registers) => self.registers = registers,
+        None => {
+          self.fault = Some(Fault {
+            cycle: tick.cycle,
+            pc: tick.pc,
+            instruction: tick.instruction.clone(),
+          });
+          return Some(tick);
+        }
+      }
+      self.pc += 1;
+    }
+
+    Some(tick)
+  }
+
+  /* steps until the program runs out, `Err` when it faults first */
+  pub fn run(&mut self) -> Result<(), Fault> {
+    while self.step().is_some() {}
+    self.fault.clone().map_or(Ok(()), Err)
+  }
+}
+
+#[cfg(test)]
+#[path = "./tests/cpu_tests.rs"]
+mod cpu_tests;
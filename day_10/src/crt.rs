@@ -0,0 +1,83 @@
+use super::cpu::Tick;
+
+
+pub const SCREEN_WIDTH: usize = 40;
+pub const SCREEN_HEIGHT: usize = 6;
+/* letters are 4 pixels wide with a blank column after each, the rare 5 pixel
+wide letters fill the blank column too */
+pub const CELL_WIDTH: usize = 5;
+pub const CELLS: usize = SCREEN_WIDTH / CELL_WIDTH;
+pub const UNRECOGNIZED: char = '?';
+
+/* the capital letters the puzzle font is known to draw */
+const GLYPHS: [(char, [&str; SCREEN_HEIGHT]); 18] = [
+  ('A', [".##.", "#..#", "#..#", "####", "#..#", "#..#"]),
+  ('B', ["###.", "#..#", "###.", "#..#", "#..#", "###."]),
+  ('C', [".##.", "#..#", "#...", "#...", "#..#", ".##."]),
+  ('E', ["####", "#...", "###.", "#...", "#...", "####"]),
+  ('F', ["####", "#...", "###.", "#...", "#...", "#..."]),
+  ('G', [".##.", "#..#", "#...", "#.##", "#..#", ".###"]),
+  ('H', ["#..#", "#..#", "####", "#..#", "#..#", "#..#"]),
+  ('I', [".###", "..#.", "..#.", "..#.", "..#.", ".###"]),
+  ('J', ["..##", "...#", "...#", "...#", "#..#", ".##."]),
+  ('K', ["#..#", "#.#.", "##..", "#.#.", "#.#.", "#..#"]),
+  ('L', ["#...", "#...", "#...", "#...", "#...", "####"]),
+  ('O', [".##.", "#..#", "#..#", "#..#", "#..#", ".##."]),
+  ('P', ["###.", "#..#", "#..#", "###.", "#...", "#..."]),
+  ('R', ["###.", "#..#", "#..#", "###.", "#.#.", "#..#"]),
+  ('S', [".###", "#...", "#...", ".##.", "...#", "###."]),
+  ('U', ["#..#", "#..#", "#..#", "#..#", "#..#", ".##."]),
+  ('Y', ["#...#", "#...#", ".#.#.", "..#..", "..#..", "..#.."]),
+  ('Z', ["####", "...#", "..#.", ".#..", "#...", "####"]),
+];
+
+/* lights the pixel the beam is on during `tick` when the 3 pixel wide sprite,
+centred on x, covers it */
+pub fn draw(screen: &mut [char], tick: &Tick) {
+  let Some(pixel) = screen.get_mut(tick.cycle - 1) else {
+    return;
+  };
+  let column = ((tick.cycle - 1) % SCREEN_WIDTH) as i32;
+  *pixel = if (tick.registers.x - column).abs() < 2 {
+    '#'
+  } else {
+    '.'
+  };
+}
+
+/* the pixels of the `index`th letter cell, one string per screen row */
+pub fn cell(screen: &[String], index: usize) -> Vec<&str> {
+  let start = index * CELL_WIDTH;
+  screen
+    .iter()
+    .map(|row| row.get(start..start + CELL_WIDTH).unwrap_or_default())
+    .collect()
+}
+
+fn matches(glyph: &[&str], cell: &[&str]) -> bool {
+  glyph.len() == cell.len()
+    && glyph.iter().zip(cell).all(|(glyph_row, cell_row)| {
+      let padding = CELL_WIDTH - glyph_row.len();
+      cell_row.len() == CELL_WIDTH
+        && cell_row.starts_with(glyph_row)
+        && cell_row.ends_with(&".".repeat(padding))
+    })
+}
+
+/* reads the letter in every cell of the screen, `None` marks a cell that
+matches no known glyph */
+pub fn read_letters(screen: &[String]) -> Vec<Option<char>> {
+  (0..CELLS)
+    .map(|index| {
+      let cell = cell(screen, index);
+      GLYPHS
+        .iter()
+        .find(|(_, glyph)| matches(glyph, &cell))
+        .map(|(letter, _)| *letter)
+    })
+    .collect()
+}
+
+#[cfg(test)]
+#[path = "./tests/crt_tests.rs"]
+mod crt_tests;
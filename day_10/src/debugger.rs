@@ -0,0 +1,257 @@
+use crossterm::{
+  cursor,
+  event::{self, Event, KeyCode, KeyEventKind},
+  execute, queue,
+  style::Print,
+  terminal::{self, ClearType},
+};
+use std::{
+  fmt,
+  io::{self, Write},
+  str::FromStr,
+};
+
+use super::cpu::{Cpu, Instruction, Registers, Tick};
+use super::crt::{draw, SCREEN_HEIGHT, SCREEN_WIDTH};
+
+
+/* instructions listed above and below the one in flight */
+const LISTING_CONTEXT: usize = 3;
+const UNDRAWN: char = ' ';
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Breakpoint {
+  Cycle(usize),
+  /* stops on the cycle where x becomes this value */
+  X(i32),
+}
+
+impl Breakpoint {
+  pub fn matches(&self, before: &Registers, tick: &Tick) -> bool {
+    match self {
+      Breakpoint::Cycle(cycle) => tick.cycle == *cycle,
+      Breakpoint::X(x) => tick.registers.x == *x && before.x != *x,
+    }
+  }
+}
+
+/* `cycle=N`, `x=V` or a bare cycle number */
+impl FromStr for Breakpoint {
+  type Err = String;
+
+  fn from_str(spec: &str) -> Result<Self, Self::Err> {
+    let invalid =
+      || format!("invalid breakpoint '{spec}', expected cycle=N or x=V");
+    match spec.split_once('=') {
+      Some(("cycle", cycle)) => {
+        cycle.parse().map(Breakpoint::Cycle).map_err(|_| invalid())
+      }
+      Some(("x", x)) => x.parse().map(Breakpoint::X).map_err(|_| invalid()),
+      Some(_) => Err(invalid()),
+      None => spec.parse().map(Breakpoint::Cycle).map_err(|_| invalid()),
+    }
+  }
+}
+
+/* the breakpoints in the `--step` and `--break <cycle=N|x=V>` arguments
+following the input, anything else is an error */
+pub fn breakpoints_from_args(
+  args: &[String],
+) -> Result<Vec<Breakpoint>, String> {
+  let mut args = args.iter();
+  let mut breakpoints = Vec::new();
+  while let Some(arg) = args.next() {
+    match arg.as_str() {
+      "--step" => {}
+      "--break" => {
+        let spec = args
+          .next()
+          .ok_or("--break expects a breakpoint, cycle=N or x=V")?;
+        breakpoints.push(spec.parse()?);
+      }
+      _ => return Err(format!("unexpected argument '{arg}'")),
+    }
+  }
+
+  Ok(breakpoints)
+}
+
+impl fmt::Display for Breakpoint {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    match self {
+      Breakpoint::Cycle(cycle) => write!(f, "cycle={cycle}"),
+      Breakpoint::X(x) => write!(f, "x={x}"),
+    }
+  }
+}
+
+/* drives the cpu a cycle at a time, drawing the crt as it goes */
+pub struct Debugger {
+  cpu: Cpu<'static>,
+  screen: Vec<char>,
+  breakpoints: Vec<Breakpoint>,
+  last: Option<Tick>,
+}
+
+impl Debugger {
+  pub fn new(program: Vec<Instruction>, breakpoints: Vec<Breakpoint>) -> Self {
+    Debugger {
+      cpu: Cpu::new(program),
+      screen: vec![UNDRAWN; SCREEN_WIDTH * SCREEN_HEIGHT],
+      breakpoints,
+      last: None,
+    }
+  }
+
+  pub fn is_halted(&self) -> bool {
+    self.cpu.is_halted()
+  }
+
+  pub fn step(&mut self) -> Option<Tick> {
+    let tick = self.cpu.step()?;
+    draw(&mut self.screen, &tick);
+    self.last = Some(tick.clone());
+    Some(tick)
+  }
+
+  /* steps until a breakpoint is hit or the program runs out, returning the
+  breakpoint that stopped it */
+  pub fn resume(&mut self) -> Option<Breakpoint> {
+    loop {
+      let before = self
+        .last
+        .as_ref()
+        .map_or_else(Registers::default, |t| t.registers);
+      let tick = self.step()?;
+      let hit = self
+        .breakpoints
+        .iter()
+        .find(|breakpoint| breakpoint.matches(&before, &tick));
+      if let Some(breakpoint) = hit {
+        return Some(*breakpoint);
+      }
+    }
+  }
+
+  /* the whole debugger view, one string per terminal line */
+  pub fn view(&self) -> Vec<String> {
+    let mut lines = Vec::new();
+    let (pc, x) = match &self.last {
+      Some(tick) => {
+        lines.push(format!(
+          "cycle {:>3}  pc {:>3}  x {:>3}  {}",
+          tick.cycle, tick.pc, tick.registers.x, tick.instruction
+        ));
+        (tick.pc, tick.registers.x)
+      }
+      None => {
+        lines.push("cycle   0  not started".to_owned());
+        (self.cpu.pc(), self.cpu.registers().x)
+      }
+    };
+    if self.cpu.is_halted() {
+      lines.push(format!("halted after {} cycles", self.cpu.cycle()));
+    } else {
+      lines.push(String::new());
+    }
+    lines.push(String::new());
+
+    let program = self.cpu.program();
+    let first = pc.saturating_sub(LISTING_CONTEXT);
+    let last = (pc + LISTING_CONTEXT + 1).min(program.len());
+    for (index, instruction) in
+      program[first.min(last)..last].iter().enumerate()
+    {
+      let index = first + index;
+      let marker = if index == pc && self.last.is_some() {
+        '>'
+      } else {
+        ' '
+      };
+      lines.push(format!("{marker} {index:>4}  {instruction}"));
+    }
+    lines.push(String::new());
+
+    let sprite: String = (0..SCREEN_WIDTH as i32)
+      .map(|column| if (x - column).abs() < 2 { '#' } else { '.' })
+      .collect();
+    lines.push(format!("sprite  {sprite}"));
+    let beam = self
+      .last
+      .as_ref()
+      .map(|tick| (tick.cycle - 1) % SCREEN_WIDTH)
+      .filter(|_| self.cpu.cycle() < SCREEN_WIDTH * SCREEN_HEIGHT);
+    lines.push(match beam {
+      Some(column) => format!("beam    {}^", " ".repeat(column)),
+      None => String::new(),
+    });
+    for row in self.screen.chunks(SCREEN_WIDTH) {
+      lines.push(format!("crt     {}", row.iter().collect::<String>()));
+    }
+    lines.push(String::new());
+
+    let breakpoints: Vec<String> =
+      self.breakpoints.iter().map(Breakpoint::to_string).collect();
+    lines.push(format!("breakpoints: {}", breakpoints.join(", ")));
+    lines.push("[s]tep  [c]ontinue  [q]uit".to_owned());
+
+    lines
+  }
+
+  /* takes over the terminal until the user quits */
+  pub fn run(mut self) -> io::Result<()> {
+    let mut out = io::stdout();
+    let _terminal = RawTerminal::enter(&mut out)?;
+    let mut status = String::new();
+    loop {
+      queue!(out, terminal::Clear(ClearType::All))?;
+      for (row, line) in self.view().iter().chain([&status]).enumerate() {
+        queue!(out, cursor::MoveTo(0, row as u16), Print(line))?;
+      }
+      out.flush()?;
+
+      let Event::Key(key) = event::read()? else {
+        continue;
+      };
+      if key.kind != KeyEventKind::Press {
+        continue;
+      }
+      status.clear();
+      match key.code {
+        KeyCode::Char('s') | KeyCode::Char(' ') | KeyCode::Enter => {
+          self.step();
+        }
+        KeyCode::Char('c') => {
+          if let Some(breakpoint) = self.resume() {
+            status = format!("stopped at {breakpoint}");
+          }
+        }
+        KeyCode::Char('q') | KeyCode::Esc => return Ok(()),
+        _ => {}
+      }
+    }
+  }
+}
+
+/* raw mode on the alternate screen, restored even when the loop bails out */
+struct RawTerminal;
+
+impl RawTerminal {
+  fn enter(out: &mut impl Write) -> io::Result<Self> {
+    terminal::enable_raw_mode()?;
+    execute!(out, terminal::EnterAlternateScreen, cursor::Hide)?;
+    Ok(RawTerminal)
+  }
+}
+
+impl Drop for RawTerminal {
+  fn drop(&mut self) {
+    let _ =
+      execute!(io::stdout(), cursor::Show, terminal::LeaveAlternateScreen);
+    let _ = terminal::disable_raw_mode();
+  }
+}
+
+#[cfg(test)]
+#[path = "./tests/debugger_tests.rs"]
+mod debugger_tests;
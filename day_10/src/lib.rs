@@ -0,0 +1,5 @@
+pub mod cpu;
+pub mod crt;
+pub mod debugger;
+pub mod part1_mod;
+pub mod part2_mod;
@@ -1,8 +1,5 @@
-mod part1_mod;
-use part1_mod::ProblemSolverPattern;
-mod problem_solver;
-use problem_solver::solve_problem;
-mod common;
+use aoc_core::problem_solver::solve_problem;
+use day_10::part1_mod::ProblemSolverPattern;
 
 
 fn main() {
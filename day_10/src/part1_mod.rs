@@ -1,12 +1,16 @@
-use std::collections::BTreeMap;
+use aoc_core::problem_solver::{ParseError, ProblemSolver};
+use serde::Serialize;
 
-use super::common::interpret_command;
-use super::problem_solver::ProblemSolver;
+use super::cpu::{Cpu, Instruction, InstructionTable};
+
+/* cycles during which the signal strength is sampled */
+const SAMPLE_CYCLES: [usize; 6] = [20, 60, 100, 140, 180, 220];
 
 pub struct PSInput {
-  commands: Vec<(String, Option<i32>)>,
+  program: Vec<Instruction>,
 }
 
+#[derive(Serialize)]
 pub struct PSSolution {
   total_signal_strength: i32,
 }
@@ -17,51 +21,28 @@ impl ProblemSolver for ProblemSolverPattern {
   type Input = PSInput;
   type Solution = PSSolution;
 
-  fn initialize(lines: impl Iterator<Item = String>) -> Self::Input {
-    let commands: Vec<(String, Option<i32>)> = lines
-      .enumerate()
-      .map(|(h, l)| interpret_command(h, l))
-      .collect();
+  fn initialize(
+    lines: impl Iterator<Item = String>,
+  ) -> Result<Self::Input, ParseError> {
+    let program = InstructionTable::default().load(lines)?;
 
-    Self::Input { commands }
+    Ok(Self::Input { program })
   }
 
   fn solve(input: Self::Input) -> Self::Solution {
-    let mut x = 1;
-    let mut actions: BTreeMap<usize, i32> = BTreeMap::new();
-    let mut step: usize = 0;
-    input.commands.iter().for_each(|(cmd, v)| {
-      step += 1;
-      if let Some(value) = v {
-        let i = match cmd.as_str() {
-          "addx" => 1,
-          "noop" => 0,
-          _ => unreachable!(),
-        };
-        step += i;
-        actions.insert(step, *value);
-      }
-    });
-
-    // let key_steps = [1, 2, 3, 4, 5, 6];
-    let key_steps = [20, 60, 100, 140, 180, 220];
-    for measurement in key_steps {
-      actions.entry(measurement).or_insert(0);
-      actions.entry(measurement + 1).or_insert(0);
-    }
-
-    let signal_strengths: Vec<i32> = actions
-      .iter()
-      .filter_map(|(k, v)| {
-        x += v;
-        if key_steps.contains(k) && *k <= *key_steps.last().unwrap() {
-          Some((x - v) * *k as i32)
-        } else {
-          None
+    let mut signal_strengths: Vec<i32> = Vec::new();
+    {
+      let mut cpu = Cpu::new(input.program);
+      cpu.on_cycle(|tick| {
+        if SAMPLE_CYCLES.contains(&tick.cycle) {
+          signal_strengths.push(tick.cycle as i32 * tick.registers.x);
         }
-      })
-      .collect();
-    dbg!(signal_strengths.clone());
+      });
+      cpu
+        .run()
+        .expect("faults are rejected when the program is loaded");
+    }
+    tracing::debug!(?signal_strengths);
 
     Self::Solution {
       total_signal_strength: signal_strengths.iter().sum(),
@@ -1,10 +1,44 @@
-mod part2_mod;
-use part2_mod::ProblemSolverPattern;
-mod problem_solver;
-use problem_solver::solve_problem;
-mod common;
+use aoc_core::problem_solver::{solve_problem, InputSource};
+use day_10::{
+  cpu::InstructionTable,
+  debugger::{breakpoints_from_args, Debugger},
+  part2_mod::ProblemSolverPattern,
+};
+use std::{env, process};
 
 
+/* `<input> --step [--break <cycle=N|x=V>]...` steps through the program in
+the terminal instead of solving it, a `--break` alone implies `--step` */
 fn main() {
-  solve_problem::<ProblemSolverPattern>();
+  let args: Vec<String> = env::args().skip(1).collect();
+  match InputSource::from_args(&args) {
+    Some((source, rest))
+      if rest.iter().any(|arg| arg == "--step" || arg == "--break") =>
+    {
+      step(source, rest)
+    }
+    _ => solve_problem::<ProblemSolverPattern>(),
+  }
+}
+
+fn step(source: InputSource, args: &[String]) {
+  let breakpoints =
+    breakpoints_from_args(args).unwrap_or_else(|err| exit_with(err));
+
+  let origin = source.origin().to_owned();
+  let lines = source
+    .lines()
+    .unwrap_or_else(|err| exit_with(format!("Failed to open input: {err}")));
+  let program = InstructionTable::default()
+    .load(lines)
+    .unwrap_or_else(|err| exit_with(err.render(&origin)));
+
+  if let Err(err) = Debugger::new(program, breakpoints).run() {
+    exit_with(format!("terminal error: {err}"));
+  }
+}
+
+fn exit_with(message: String) -> ! {
+  eprintln!("{message}");
+  process::exit(1);
 }
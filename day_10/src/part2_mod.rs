@@ -1,14 +1,21 @@
-use std::collections::BTreeMap;
+use aoc_core::problem_solver::{ParseError, ProblemSolver};
+use serde::Serialize;
 
-use super::common::interpret_command;
-use super::problem_solver::ProblemSolver;
+use super::cpu::{Cpu, Instruction, InstructionTable};
+use super::crt::{
+  cell, draw, read_letters, SCREEN_HEIGHT, SCREEN_WIDTH, UNRECOGNIZED,
+};
 
 pub struct PSInput {
-  commands: Vec<(String, Option<i32>)>,
+  program: Vec<Instruction>,
 }
 
+#[derive(Serialize)]
 pub struct PSSolution {
-  capital_letters: [char; 8],
+  #[serde(skip)]
+  screen: Vec<String>,
+  capital_letters: String,
+  unrecognized: Vec<usize>,
 }
 
 pub struct ProblemSolverPattern;
@@ -17,62 +24,55 @@ impl ProblemSolver for ProblemSolverPattern {
   type Input = PSInput;
   type Solution = PSSolution;
 
-  fn initialize(lines: impl Iterator<Item = String>) -> Self::Input {
-    let commands: Vec<(String, Option<i32>)> = lines
-      .enumerate()
-      .map(|(h, l)| interpret_command(h, l))
-      .collect();
+  fn initialize(
+    lines: impl Iterator<Item = String>,
+  ) -> Result<Self::Input, ParseError> {
+    let program = InstructionTable::default().load(lines)?;
 
-    Self::Input { commands }
+    Ok(Self::Input { program })
   }
 
   fn solve(input: Self::Input) -> Self::Solution {
-    let mut step: usize = 0;
-    let mut actions: BTreeMap<usize, i32> = BTreeMap::new();
-    input.commands.iter().for_each(|(cmd, v)| {
-      step += 1;
-      if let Some(value) = v {
-        let i = match cmd.as_str() {
-          "addx" => 1,
-          "noop" => 0,
-          _ => unreachable!(),
-        };
-        step += i;
-        actions.insert(step, *value);
-      }
-    });
-    for measurement in 1..=240 {
-      actions.entry(measurement).or_insert(0);
+    let mut screen_buffer = vec!['.'; SCREEN_WIDTH * SCREEN_HEIGHT];
+    {
+      let mut cpu = Cpu::new(input.program);
+      cpu.on_cycle(|tick| {
+        tracing::trace!(cycle = tick.cycle, sprite = tick.registers.x, "beam");
+        draw(&mut screen_buffer, tick);
+      });
+      cpu
+        .run()
+        .expect("faults are rejected when the program is loaded");
     }
-    let mut cursor: i32 = 1;
-    let screen_buffer: Vec<char> = (0..240)
-      .map(|i| {
-        if i % 40 == 0 && i > 2 {
-          cursor += 40;
-        }
-        println!("{} c{}, c_after{}", i, cursor, cursor + actions[&(i + 1)]);
-        let pixel = if (cursor - i as i32).abs() < 2 {
-          '#'
-        } else {
-          '.'
-        };
-        cursor += actions[&(i + 1)];
 
-        pixel
-      })
+    let screen: Vec<String> = screen_buffer
+      .chunks(SCREEN_WIDTH)
+      .map(|line| line.iter().collect())
+      .collect();
+    let letters = read_letters(&screen);
+    let unrecognized = letters
+      .iter()
+      .enumerate()
+      .filter(|(_, letter)| letter.is_none())
+      .map(|(index, _)| index)
+      .collect();
+    let capital_letters = letters
+      .iter()
+      .map(|letter| letter.unwrap_or(UNRECOGNIZED))
       .collect();
 
-    for line in screen_buffer.chunks(40) {
-      println!("{}", line.iter().collect::<String>());
-    }
-
-    Self::Solution { capital_letters: ['.'; 8] }
+    Self::Solution { screen, capital_letters, unrecognized }
   }
 
   fn output(solution: Self::Solution) {
-    println!(
-      "capital letters: {}",
-      solution.capital_letters.iter().collect::<String>()
-    )
+    tracing::debug!("screen\n{}", solution.screen.join("\n"));
+    for &index in &solution.unrecognized {
+      tracing::warn!(
+        "letter {} matches no known glyph:\n{}",
+        index + 1,
+        cell(&solution.screen, index).join("\n")
+      );
+    }
+    println!("capital letters: {}", solution.capital_letters)
   }
 }
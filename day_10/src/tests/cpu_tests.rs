@@ -0,0 +1,140 @@
+use crate::cpu::*;
+
+
+fn program(table: &InstructionTable, source: &[&str]) -> Vec<Instruction> {
+  table
+    .assemble(source.iter().map(|line| line.to_string()))
+    .unwrap()
+}
+
+#[test]
+fn it_should_run_each_instruction_for_its_cycles() {
+  // Arrange
+  let mut xs = vec![];
+  let program =
+    program(&InstructionTable::default(), &["noop", "addx 3", "addx -5"]);
+  let mut cpu = Cpu::new(program);
+  cpu.on_cycle(|tick| xs.push(tick.registers.x));
+
+  // Act
+  cpu.run().unwrap();
+
+  // Assert
+  assert_eq!(cpu.cycle(), 5);
+  assert_eq!(cpu.registers().x, -1);
+  drop(cpu);
+  assert_eq!(xs, [1, 1, 1, 4, 4]);
+}
+
+#[test]
+fn it_should_run_a_registered_opcode() {
+  // Arrange
+  let mulx = Opcode {
+    mnemonic: "mulx",
+    arity: 2,
+    cycles: 3,
+    execute: |registers, arguments| {
+      Some(Registers { x: registers.x * arguments[0] + arguments[1] })
+    },
+  };
+  let table = InstructionTable::default().with(mulx).unwrap();
+  let mut cpu = Cpu::new(program(&table, &["addx 1", "mulx 5 -1"]));
+
+  // Act
+  cpu.run().unwrap();
+
+  // Assert
+  assert_eq!(cpu.cycle(), 5);
+  assert_eq!(cpu.registers().x, 9);
+  assert_eq!(cpu.program()[1].to_string(), "mulx 5 -1");
+}
+
+#[test]
+fn it_should_point_at_the_bad_argument() {
+  // Arrange
+  let line = "addx \t 1x";
+
+  // Act
+  let err = InstructionTable::default().decode(3, line).unwrap_err();
+
+  // Assert
+  assert_eq!(err.render("input"), {
+    "error: expected an integer argument\n \
+     --> input:4:8\n  \
+      |\n\
+     4 | addx \t 1x\n  \
+      |        ^^"
+  });
+}
+
+#[test]
+fn it_should_reject_a_wrong_argument_count() {
+  // Arrange
+  let table = InstructionTable::default();
+
+  // Act
+  let missing = table.decode(0, "addx");
+  let extra = table.decode(0, "noop 1");
+
+  // Assert
+  assert!(missing.is_err());
+  assert!(extra.is_err());
+}
+
+#[test]
+fn it_should_refuse_an_opcode_running_for_no_cycles() {
+  // Arrange
+  let jump = Opcode {
+    mnemonic: "jump",
+    arity: 0,
+    cycles: 0,
+    execute: |registers, _| Some(registers),
+  };
+
+  // Act
+  let result = InstructionTable::default().with(jump);
+
+  // Assert
+  assert_eq!(
+    result.err(),
+    Some("'jump' must run for at least one cycle".to_owned())
+  );
+}
+
+#[test]
+fn it_should_halt_on_an_overflowing_addx() {
+  // Arrange
+  let max = i32::MAX.to_string();
+  let source = ["noop", &format!("addx {max}"), "noop"];
+  let mut cpu = Cpu::new(program(&InstructionTable::default(), &source));
+
+  // Act
+  let fault = cpu.run().unwrap_err();
+
+  // Assert
+  assert_eq!((fault.cycle, fault.pc), (3, 1));
+  assert_eq!(cpu.registers().x, 1);
+  assert!(cpu.is_halted());
+  assert!(cpu.step().is_none());
+}
+
+#[test]
+fn it_should_point_at_the_instruction_that_overflows_when_loading() {
+  // Arrange
+  let lines = ["addx 2147483646", "addx -3", "addx 4"].map(String::from);
+
+  // Act
+  let err = InstructionTable::default()
+    .load(lines.into_iter())
+    .unwrap_err();
+
+  // Assert
+  assert_eq!(
+    err.render("input"),
+    "error: 'addx 4' overflows x on cycle 6\n \
+     --> input:3:1\n  \
+      |\n\
+     3 | addx 4\n  \
+      | ^^^^^^"
+  );
+}
@@ -0,0 +1,89 @@
+use crate::{
+  cpu::{Instruction, Registers, Tick, NOOP},
+  crt::*,
+};
+
+
+const SCREEN: [&str; SCREEN_HEIGHT] = [
+  "####.#..#.###..#..#.####.###..#..#.####.",
+  "#....#.#..#..#.#..#.#....#..#.#..#....#.",
+  "###..##...#..#.####.###..#..#.#..#...#..",
+  "#....#.#..###..#..#.#....###..#..#..#...",
+  "#....#.#..#.#..#..#.#....#....#..#.#....",
+  "####.#..#.#..#.#..#.####.#.....##..####.",
+];
+
+fn screen() -> Vec<String> {
+  SCREEN.iter().map(|row| row.to_string()).collect()
+}
+
+#[test]
+fn it_should_read_the_letters_on_the_screen() {
+  // Arrange
+  let screen = screen();
+
+  // Act
+  let letters: Option<String> = read_letters(&screen).into_iter().collect();
+
+  // Assert
+  assert_eq!(letters.as_deref(), Some("EKRHEPUZ"));
+}
+
+#[test]
+fn it_should_read_five_pixel_wide_letters() {
+  // Arrange
+  let y = ["#...#", "#...#", ".#.#.", "..#..", "..#..", "..#.."];
+  let screen: Vec<String> = y
+    .iter()
+    .map(|row| format!("{row}{}", ".".repeat(35)))
+    .collect();
+
+  // Act
+  let letters = read_letters(&screen);
+
+  // Assert
+  assert_eq!(letters[0], Some('Y'));
+}
+
+#[test]
+fn it_should_mark_a_cell_matching_no_glyph() {
+  // Arrange
+  let mut screen = screen();
+  screen[0].replace_range(5..10, "#####");
+
+  // Act
+  let letters = read_letters(&screen);
+
+  // Assert
+  assert_eq!(letters[1], None);
+  assert_eq!(letters[0], Some('E'));
+  assert_eq!(
+    letters[2..],
+    [
+      Some('R'),
+      Some('H'),
+      Some('E'),
+      Some('P'),
+      Some('U'),
+      Some('Z')
+    ]
+  );
+}
+
+#[test]
+fn it_should_light_the_pixels_the_sprite_covers() {
+  // Arrange
+  let mut screen = ['?'; SCREEN_WIDTH];
+  let tick = |cycle: usize| Tick {
+    cycle,
+    pc: 0,
+    instruction: Instruction { opcode: NOOP, arguments: vec![] },
+    registers: Registers { x: 1 },
+  };
+
+  // Act
+  (1..=4).for_each(|cycle| draw(&mut screen, &tick(cycle)));
+
+  // Assert
+  assert_eq!(screen[..5], ['#', '#', '#', '.', '?']);
+}
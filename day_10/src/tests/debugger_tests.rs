@@ -0,0 +1,77 @@
+use crate::{
+  cpu::{Instruction, Registers, Tick, NOOP},
+  debugger::*,
+};
+
+
+fn args(args: &[&str]) -> Vec<String> {
+  args.iter().map(|arg| arg.to_string()).collect()
+}
+
+fn tick(cycle: usize, x: i32) -> Tick {
+  Tick {
+    cycle,
+    pc: 0,
+    instruction: Instruction { opcode: NOOP, arguments: vec![] },
+    registers: Registers { x },
+  }
+}
+
+#[test]
+fn it_should_parse_breakpoints() {
+  // Act & Assert
+  assert_eq!("cycle=20".parse(), Ok(Breakpoint::Cycle(20)));
+  assert_eq!("x=-3".parse(), Ok(Breakpoint::X(-3)));
+  assert_eq!("140".parse(), Ok(Breakpoint::Cycle(140)));
+}
+
+#[test]
+fn it_should_reject_malformed_breakpoints() {
+  // Act & Assert
+  for spec in ["", "cycle=", "cycle=-1", "x=one", "pc=3", "cycle:20", "x"] {
+    assert!(spec.parse::<Breakpoint>().is_err(), "'{spec}' was accepted");
+  }
+}
+
+#[test]
+fn it_should_print_breakpoints_as_they_parse() {
+  // Arrange
+  let breakpoints = [Breakpoint::Cycle(7), Breakpoint::X(-1)];
+
+  // Act & Assert
+  for breakpoint in breakpoints {
+    assert_eq!(breakpoint.to_string().parse(), Ok(breakpoint));
+  }
+}
+
+#[test]
+fn it_should_stop_when_x_becomes_the_value() {
+  // Arrange
+  let breakpoint = Breakpoint::X(4);
+
+  // Act & Assert
+  assert!(breakpoint.matches(&Registers { x: 1 }, &tick(4, 4)));
+  assert!(!breakpoint.matches(&Registers { x: 4 }, &tick(5, 4)));
+  assert!(Breakpoint::Cycle(5).matches(&Registers { x: 4 }, &tick(5, 4)));
+}
+
+#[test]
+fn it_should_read_breakpoints_from_the_arguments() {
+  // Act
+  let breakpoints =
+    breakpoints_from_args(&args(&["--break", "x=3", "--step", "--break", "9"]));
+
+  // Assert
+  assert_eq!(
+    breakpoints,
+    Ok(vec![Breakpoint::X(3), Breakpoint::Cycle(9)])
+  );
+}
+
+#[test]
+fn it_should_reject_a_break_flag_without_a_breakpoint() {
+  // Act & Assert
+  assert!(breakpoints_from_args(&args(&["--step", "--break"])).is_err());
+  assert!(breakpoints_from_args(&args(&["--break", "x=y"])).is_err());
+  assert!(breakpoints_from_args(&args(&["--step", "--brake"])).is_err());
+}
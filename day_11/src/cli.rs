@@ -0,0 +1,82 @@
+use aoc_core::problem_solver::InputSource;
+use std::{env, path::Path, process};
+
+use super::common::{read_troup, simulate_with, verify_reduction, Relief};
+use super::throws;
+
+
+/* the extra modes of the part runners, given after the input:
+  `--verify <rounds>` replays the first rounds with big integers to check the
+    lcm reduction
+  `--record <file>` writes every throw to a csv or `.json` trace
+  `--replay <file> --round <n>` rebuilds the inventories after round n
+returns false when none was asked for so the runner solves as usual */
+pub fn run_from_args(rounds: usize, relief: Relief) -> bool {
+  let args: Vec<String> = env::args().skip(1).collect();
+  let Some((source, rest)) = InputSource::from_args(&args) else {
+    return false;
+  };
+  let value = |flag: &str| {
+    let position = rest.iter().position(|arg| arg == flag)?;
+    match rest.get(position + 1) {
+      Some(value) => Some(value.as_str()),
+      None => exit_with(format!("{flag} expects a value")),
+    }
+  };
+  let number = |flag: &str| {
+    value(flag).map(|value| {
+      value
+        .parse::<usize>()
+        .unwrap_or_else(|_| exit_with(format!("{flag} expects a number")))
+    })
+  };
+  let (verify, record, replay) =
+    (number("--verify"), value("--record"), value("--replay"));
+  if verify.is_none() && record.is_none() && replay.is_none() {
+    return false;
+  }
+
+  let origin = source.origin().to_owned();
+  let lines = source
+    .lines()
+    .unwrap_or_else(|err| exit_with(format!("Failed to open input: {err}")));
+  let (mut items, monkeys) =
+    read_troup(lines).unwrap_or_else(|err| exit_with(err.render(&origin)));
+
+  if let Some(rounds) = verify {
+    match verify_reduction(&monkeys, &items, rounds) {
+      Ok(()) => {
+        println!("reduction agrees with big integers over {rounds} rounds")
+      }
+      Err(divergence) => exit_with(divergence.to_string()),
+    }
+  } else if let Some(path) = record {
+    let mut recorded = Vec::new();
+    simulate_with(&monkeys, &mut items, rounds, relief, |throw| {
+      recorded.push(throw)
+    })
+    .unwrap_or_else(|err| exit_with(err.to_string()));
+    throws::write(Path::new(path), &recorded).unwrap_or_else(|err| {
+      exit_with(format!("Failed to write {path}: {err}"))
+    });
+    println!("recorded {} throws over {rounds} rounds", recorded.len());
+  } else if let Some(path) = replay {
+    let round = number("--round").unwrap_or(rounds);
+    let recorded =
+      throws::read(Path::new(path)).unwrap_or_else(|err| exit_with(err));
+    match throws::replay(&items, &recorded, round) {
+      Ok(items) => println!("{}", throws::snapshot(&items)),
+      Err(index) => exit_with(format!(
+        "throw {} of {path} does not match the inventories",
+        index + 1
+      )),
+    }
+  }
+
+  true
+}
+
+fn exit_with(message: String) -> ! {
+  eprintln!("{message}");
+  process::exit(1);
+}
@@ -1,89 +1,137 @@
+use aoc_core::problem_solver::ParseError;
+use num_bigint::BigInt;
+use num_integer::Integer;
 use sscanf::sscanf;
-use std::collections::{BinaryHeap, VecDeque};
+use std::{
+  collections::{BinaryHeap, VecDeque},
+  fmt,
+};
+
+use super::operation::Expr;
+use super::throws::Throw;
+use super::worry::Worry;
 
 
 #[derive(Clone)]
 pub struct Monkey {
   pub test: i64,
   pub result: (usize, usize),
-  pub operation: (char, i64),
+  pub operation: Expr,
 }
 
 impl Monkey {
-  pub fn input_props_from(record: Vec<String>) -> (VecDeque<i64>, Monkey) {
-    let invalid_format_error =
-      &format!("invalid record format for monkey:\n{}", record.join("\n"));
-    assert!(record.len() > 5, "{}", invalid_format_error);
+  pub fn input_props_from(
+    first_index: usize,
+    record: Vec<String>,
+  ) -> Result<(VecDeque<i64>, Monkey), ParseError> {
+    if record.len() < 6 {
+      let last = record.len().saturating_sub(1);
+      return Err(ParseError::new(
+        first_index + last,
+        record.last().map_or("", |l| l),
+        "incomplete monkey record, expected 6 lines",
+      ));
+    }
+    let invalid = |offset: usize, message: &str| {
+      ParseError::new(first_index + offset, &record[offset], message)
+    };
 
     let (_, items_str) = sscanf!(record[1], r"{str:/[^:]+:\s+/}{str}")
-      .expect(invalid_format_error);
+      .map_err(|_| invalid(1, "expected 'Starting items: <worry levels>'"))?;
     let items = items_str
       .trim()
       .split(", ")
-      .map(|v| v.parse::<i64>().unwrap())
-      .collect();
-
-    let o = record[2]
-      .split_whitespace()
-      .collect::<Vec<&str>>()
-      .as_slice()
-      .chunks(2)
-      .last()
-      .unwrap_or(&[])
-      .to_vec();
-    let operation = (
-      o[0].chars().next().unwrap(),
-      o[1].parse::<i64>().unwrap_or(i64::MAX),
-    );
+      .map(|v| {
+        v.parse::<i64>()
+          .map_err(|_| invalid(1, "expected an integer worry level").at(v))
+      })
+      .collect::<Result<_, _>>()?;
+
+    let start = record[2]
+      .find("new =")
+      .ok_or_else(|| invalid(2, "expected 'Operation: new = <expression>'"))?;
+    let operation =
+      Expr::parse(first_index + 2, &record[2], start + "new =".len())?;
     let test = sscanf!(record[3], r"{str:/.*\s/}{i64:/\d+$/}")
-      .expect(invalid_format_error)
+      .map_err(|_| invalid(3, "expected 'Test: divisible by <integer>'"))?
       .1;
-    let result = (
-      sscanf!(record[5], r"{str:/.*\s/}{usize:/\d+$/}")
-        .expect(invalid_format_error)
-        .1,
-      sscanf!(record[4], r"{str:/.*\s/}{usize:/\d+$/}")
-        .expect(invalid_format_error)
-        .1,
-    );
-
-    (items, Monkey { test, result, operation })
+    if test == 0 {
+      let (column, divisor) = trailing_number(&record[3]);
+      return Err(
+        invalid(3, "no worry level is divisible by 0")
+          .at_column(column, divisor),
+      );
+    }
+    let throw_target = |offset: usize| {
+      sscanf!(record[offset], r"{str:/.*\s/}{usize:/\d+$/}")
+        .map(|(_, target)| target)
+        .map_err(|_| invalid(offset, "expected 'throw to monkey <index>'"))
+    };
+    let result = (throw_target(5)?, throw_target(4)?);
+
+    Ok((items, Monkey { test, result, operation }))
   }
 
-  pub fn inspect_items<R>(
-    &mut self,
-    items: &VecDeque<i64>,
-    relief: R,
-  ) -> Vec<(usize, i64)>
-  where
-    R: Fn(i64) -> i64,
-  {
-    let mut actions: Vec<(usize, i64)> = Vec::new();
-    for item in items.iter() {
-      let updated_item = match self.operation.0 {
-        '+' => item + self.operation.1,
-        '*' => {
-          let r = match self.operation.1 {
-            i64::MAX => *item,
-            x => x,
-          };
-          item * r
-        }
-        _ => unimplemented!(),
-      };
-      let updated_item = relief(updated_item);
-      let to = match inv_heaviside(updated_item % self.test) {
-        0 => self.result.0,
-        1 => self.result.1,
-        _ => unreachable!(),
-      };
-      actions.push((to, updated_item));
-    }
+  /* `Err` holds the first worry level the operation overflows or divides
+  by zero on */
+  pub fn inspect_items<W: Worry>(
+    &self,
+    items: &VecDeque<W>,
+    relieve: impl Fn(W) -> W,
+  ) -> Result<Vec<(usize, W)>, W> {
+    items
+      .iter()
+      .map(|item| {
+        let updated_item = self.operation.eval(item).ok_or(item.clone())?;
+        let updated_item = relieve(updated_item);
+        let to = match updated_item.is_divisible_by(self.test) {
+          true => self.result.1,
+          false => self.result.0,
+        };
+        Ok((to, updated_item))
+      })
+      .collect()
+  }
+}
+
+/* the 1-based column and text of the number ending `line` */
+fn trailing_number(line: &str) -> (usize, &str) {
+  let line = line.trim_end();
+  let start = line.trim_end_matches(|c: char| c.is_ascii_digit()).len();
+  (line[..start].chars().count() + 1, &line[start..])
+}
+
+/* a monkey's operation overflowing the worry level type or dividing by
+zero */
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct InspectionError {
+  pub round: usize,
+  pub monkey: usize,
+  pub worry: String,
+  pub operation: String,
+}
 
-    actions
+impl fmt::Display for InspectionError {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    write!(
+      f,
+      "monkey {} cannot work out 'new = {}' for worry level {} in round {}",
+      self.monkey, self.operation, self.worry, self.round
+    )
   }
 }
 
+/* what keeps worry levels from growing without bound between inspections */
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Relief {
+  /* worry is divided once the monkey gets bored with the item */
+  Divide(i64),
+  /* no relief, worry is taken modulo the lcm of every monkey's test */
+  Reduce,
+  /* no relief and no reduction, only sensible with big integers */
+  Unbounded,
+}
+
 pub fn convert_vec_to_vec_and_vec<T, U>(vec: Vec<(T, U)>) -> (Vec<T>, Vec<U>) {
   let mut vec_deques = Vec::new();
   let mut monkeys = Vec::new();
@@ -96,36 +144,235 @@ pub fn convert_vec_to_vec_and_vec<T, U>(vec: Vec<(T, U)>) -> (Vec<T>, Vec<U>) {
   (vec_deques, monkeys)
 }
 
-pub fn factory_ordered_troup_tallies<R>(
-  mut monkeys: Vec<Monkey>,
-  mut items: Vec<VecDeque<i64>>,
+/* monkey records are 6 lines separated by a blank one. there must be at
+least two monkeys and every throw must go to one of them */
+pub fn read_troup(
+  lines: impl Iterator<Item = String>,
+) -> Result<(Vec<VecDeque<i64>>, Vec<Monkey>), ParseError> {
+  let lines: Vec<String> = lines.collect();
+  let (items, monkeys) = convert_vec_to_vec_and_vec(
+    lines
+      .chunks(7)
+      .enumerate()
+      .map(|(index, record)| {
+        Monkey::input_props_from(index * 7, record.to_vec())
+      })
+      .collect::<Result<_, _>>()?,
+  );
+  if monkeys.len() < 2 {
+    return Err(ParseError::whole_input(format!(
+      "monkey business needs at least two monkeys, found {}",
+      monkeys.len()
+    )));
+  }
+  for (index, monkey) in monkeys.iter().enumerate() {
+    for (offset, target) in [(5, monkey.result.0), (4, monkey.result.1)] {
+      if target >= monkeys.len() {
+        let line = &lines[index * 7 + offset];
+        let (column, text) = trailing_number(line);
+        return Err(
+          ParseError::new(
+            index * 7 + offset,
+            line,
+            format!(
+              "there is no monkey {target}, only 0 to {}",
+              monkeys.len() - 1
+            ),
+          )
+          .at_column(column, text),
+        );
+      }
+    }
+  }
+
+  Ok((items, monkeys))
+}
+
+/* `Err` when the lcm does not fit a 64 bit integer */
+pub fn tests_lcm(monkeys: &[Monkey]) -> Result<i64, ParseError> {
+  monkeys.iter().try_fold(1_i64, |lcm, monkey| {
+    (lcm / lcm.gcd(&monkey.test))
+      .checked_mul(monkey.test)
+      .ok_or_else(|| {
+        ParseError::whole_input(
+          "the lcm of the monkeys' tests overflows a 64 bit integer",
+        )
+      })
+  })
+}
+
+/* plays `rounds` rounds, leaving the items where the last one put them, and
+counts the inspections of every monkey */
+pub fn simulate<W: Worry>(
+  monkeys: &[Monkey],
+  items: &mut [VecDeque<W>],
+  rounds: usize,
+  relief: Relief,
+) -> Result<Vec<usize>, InspectionError> {
+  simulate_with(monkeys, items, rounds, relief, |_| {})
+}
+
+/* like `simulate`, handing every throw to `on_throw` as it happens */
+pub fn simulate_with<W: Worry>(
+  monkeys: &[Monkey],
+  items: &mut [VecDeque<W>],
   rounds: usize,
-  relief: R,
-) -> BinaryHeap<usize>
-where
-  R: Fn(i64) -> i64 + Copy,
-{
-  let rates = (0..rounds).fold(vec![0; monkeys.len()], |mut acc, _| {
-    for (index, monkey) in monkeys.iter_mut().enumerate() {
-      let actions = monkey.inspect_items(&items[index], relief);
-      acc[index] += items[index].len();
-      actions.iter().for_each(|(to, value)| {
-        println!("@{}  {}:{}", index, to, *value);
-        items[*to].push_front(*value);
-      });
-      items[index].clear();
+  relief: Relief,
+  mut on_throw: impl FnMut(Throw<W>),
+) -> Result<Vec<usize>, InspectionError> {
+  let modulus = match relief {
+    Relief::Reduce if monkeys.iter().all(|m| m.operation.is_modular()) => {
+      match tests_lcm(monkeys) {
+        Ok(lcm) => Some(lcm),
+        Err(err) => {
+          tracing::warn!("{}, worry levels are left unreduced", err.message);
+          None
+        }
+      }
+    }
+    Relief::Reduce => {
+      tracing::warn!("an operation divides, worry levels are left unreduced");
+      None
     }
-    dbg!(acc.clone());
-    acc
-  });
+    _ => None,
+  };
+  let relieve = |worry: W| match (relief, modulus) {
+    (Relief::Divide(divisor), _) => worry.relieve(divisor),
+    (_, Some(modulus)) => worry.reduce(modulus),
+    _ => worry,
+  };
 
-  BinaryHeap::from(rates)
+  let mut acc = vec![0; monkeys.len()];
+  for round in 1..=rounds {
+    for (index, monkey) in monkeys.iter().enumerate() {
+      let held = std::mem::take(&mut items[index]);
+      let actions = monkey.inspect_items(&held, relieve).map_err(|worry| {
+        InspectionError {
+          round,
+          monkey: index,
+          worry: worry.to_string(),
+          operation: monkey.operation.to_string(),
+        }
+      })?;
+      acc[index] += held.len();
+      for (before, (to, after)) in held.into_iter().zip(actions) {
+        tracing::trace!(from = index, to, worry = %after, "throw");
+        items[to].push_back(after.clone());
+        on_throw(Throw { round, from: index, to, before, after });
+      }
+    }
+    tracing::debug!(inspections = ?acc);
+  }
+
+  Ok(acc)
 }
 
-pub fn inv_heaviside(x: i64) -> i64 {
-  if x <= 0 {
-    1
-  } else {
-    0
+/* plays with machine integers, starting over with big integers when a worry
+level overflows. `Err` only when an operation divides by zero */
+pub fn factory_ordered_troup_tallies(
+  monkeys: Vec<Monkey>,
+  items: Vec<VecDeque<i64>>,
+  rounds: usize,
+  relief: Relief,
+) -> Result<BinaryHeap<usize>, InspectionError> {
+  let mut machine_items = items.clone();
+  let tallies = simulate(&monkeys, &mut machine_items, rounds, relief)
+    .or_else(|err| {
+      tracing::warn!("{err}, starting over with big integers");
+      let mut big_items: Vec<VecDeque<BigInt>> = items
+        .iter()
+        .map(|queue| queue.iter().map(|&worry| BigInt::from(worry)).collect())
+        .collect();
+      simulate(&monkeys, &mut big_items, rounds, relief)
+    })?;
+
+  Ok(BinaryHeap::from(tallies))
+}
+
+/* the inspection counts of the two most active monkeys multiplied */
+pub fn monkey_business(tallies: BinaryHeap<usize>) -> i64 {
+  tallies
+    .into_sorted_vec()
+    .iter()
+    .rev()
+    .take(2)
+    .product::<usize>() as i64
+}
+
+/* why the reduced simulation cannot be trusted */
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Divergence {
+  /* an operation divides, so worry levels are never reduced */
+  Division,
+  /* the lcm of the tests overflows, so worry levels are never reduced */
+  Overflow,
+  /* either simulation could not go on */
+  Inspection(InspectionError),
+  /* inspection counts or worry levels modulo the lcm first differ after
+  this round */
+  Round(usize),
+}
+
+impl fmt::Display for Divergence {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    match self {
+      Divergence::Division => write!(f, "an operation divides, no reduction"),
+      Divergence::Overflow => write!(f, "the lcm overflows, no reduction"),
+      Divergence::Inspection(err) => write!(f, "{err}"),
+      Divergence::Round(round) => {
+        write!(f, "reduction diverges in round {round}")
+      }
+    }
+  }
+}
+
+/* plays `rounds` rounds with reduced machine integers and with unbounded big
+integers side by side */
+pub fn verify_reduction(
+  monkeys: &[Monkey],
+  items: &[VecDeque<i64>],
+  rounds: usize,
+) -> Result<(), Divergence> {
+  if !monkeys.iter().all(|monkey| monkey.operation.is_modular()) {
+    return Err(Divergence::Division);
   }
+  let lcm = tests_lcm(monkeys).map_err(|_| Divergence::Overflow)?;
+  let mut reduced = items.to_vec();
+  let mut exact: Vec<VecDeque<BigInt>> = items
+    .iter()
+    .map(|queue| queue.iter().map(|&worry| BigInt::from(worry)).collect())
+    .collect();
+  let mut reduced_tallies = vec![0; monkeys.len()];
+  let mut exact_tallies = vec![0; monkeys.len()];
+
+  for round in 1..=rounds {
+    let counts = simulate(monkeys, &mut reduced, 1, Relief::Reduce)
+      .map_err(Divergence::Inspection)?;
+    reduced_tallies
+      .iter_mut()
+      .zip(counts)
+      .for_each(|(t, c)| *t += c);
+    let counts = simulate(monkeys, &mut exact, 1, Relief::Unbounded)
+      .map_err(Divergence::Inspection)?;
+    exact_tallies
+      .iter_mut()
+      .zip(counts)
+      .for_each(|(t, c)| *t += c);
+
+    let same_items = reduced.iter().zip(&exact).all(|(reduced, exact)| {
+      reduced.len() == exact.len()
+        && reduced.iter().zip(exact).all(|(reduced, exact)| {
+          BigInt::from(reduced.reduce(lcm)) == exact.reduce(lcm)
+        })
+    });
+    if reduced_tallies != exact_tallies || !same_items {
+      return Err(Divergence::Round(round));
+    }
+  }
+
+  Ok(())
 }
+
+#[cfg(test)]
+#[path = "./tests/common_tests.rs"]
+mod common_tests;
@@ -0,0 +1,7 @@
+pub mod cli;
+pub mod common;
+pub mod operation;
+pub mod part1_module;
+pub mod part2_module;
+pub mod throws;
+pub mod worry;
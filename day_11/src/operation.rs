@@ -0,0 +1,254 @@
+use aoc_core::problem_solver::ParseError;
+use std::fmt;
+
+use super::worry::Worry;
+
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Operator {
+  Add,
+  Subtract,
+  Multiply,
+  Divide,
+}
+
+impl Operator {
+  fn precedence(self) -> u8 {
+    match self {
+      Operator::Add | Operator::Subtract => 1,
+      Operator::Multiply | Operator::Divide => 2,
+    }
+  }
+
+  fn symbol(self) -> char {
+    match self {
+      Operator::Add => '+',
+      Operator::Subtract => '-',
+      Operator::Multiply => '*',
+      Operator::Divide => '/',
+    }
+  }
+}
+
+/* the right hand side of `Operation: new = ...`, worked out per item */
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Expr {
+  Old,
+  Constant(i64),
+  Negate(Box<Expr>),
+  Binary(Box<Expr>, Operator, Box<Expr>),
+}
+
+impl Expr {
+  /* `None` when the worry level overflows or is divided by zero */
+  pub fn eval<W: Worry>(&self, old: &W) -> Option<W> {
+    match self {
+      Expr::Old => Some(old.clone()),
+      Expr::Constant(value) => Some(W::from_i64(*value)),
+      Expr::Negate(operand) => {
+        W::from_i64(0).combine(Operator::Subtract, &operand.eval(old)?)
+      }
+      Expr::Binary(lhs, operator, rhs) => {
+        lhs.eval(old)?.combine(*operator, &rhs.eval(old)?)
+      }
+    }
+  }
+
+  /* reducing worry levels modulo the tests' lcm leaves every test outcome
+  unchanged through `+ - *`, but not through a division */
+  pub fn is_modular(&self) -> bool {
+    match self {
+      Expr::Binary(_, Operator::Divide, _) => false,
+      Expr::Binary(lhs, _, rhs) => lhs.is_modular() && rhs.is_modular(),
+      Expr::Negate(operand) => operand.is_modular(),
+      _ => true,
+    }
+  }
+
+  /* parses the expression starting at byte `start` of `line`, so errors
+  point into the line */
+  pub fn parse(
+    index: usize,
+    line: &str,
+    start: usize,
+  ) -> Result<Expr, ParseError> {
+    let located = |(offset, fragment, message): Failure| {
+      ParseError::new(index, line, message)
+        .at_column(line[..offset].chars().count() + 1, fragment)
+    };
+    let tokens = tokenize(line, start).map_err(located)?;
+    let mut parser = Parser { line, tokens, position: 0 };
+    let expr = parser.expression(0).map_err(located)?;
+    match parser.tokens.get(parser.position) {
+      Some(&(offset, fragment, _)) => Err(located((
+        offset,
+        fragment,
+        "expected an operator or ')'".to_owned(),
+      ))),
+      None => Ok(expr),
+    }
+  }
+}
+
+/* parenthesised only where precedence needs it */
+impl fmt::Display for Expr {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    match self {
+      Expr::Old => write!(f, "old"),
+      Expr::Constant(value) => write!(f, "{value}"),
+      Expr::Negate(operand) => match **operand {
+        Expr::Binary(..) => write!(f, "-({operand})"),
+        _ => write!(f, "-{operand}"),
+      },
+      Expr::Binary(lhs, operator, rhs) => {
+        let operand = |expr: &Expr, right: bool| match expr {
+          Expr::Binary(_, inner, _)
+            if inner.precedence() < operator.precedence()
+              || (right && inner.precedence() == operator.precedence()) =>
+          {
+            format!("({expr})")
+          }
+          _ => expr.to_string(),
+        };
+        write!(
+          f,
+          "{} {} {}",
+          operand(lhs, false),
+          operator.symbol(),
+          operand(rhs, true)
+        )
+      }
+    }
+  }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Token {
+  Old,
+  Number(i64),
+  Operator(Operator),
+  Open,
+  Close,
+}
+
+/* a token with the byte offset and the slice of the line it was read from */
+type Located<'a> = (usize, &'a str, Token);
+
+/* the offending slice of the line, its byte offset and what was expected
+there */
+type Failure<'a> = (usize, &'a str, String);
+
+/* the tokens of `line` from byte `start` on */
+fn tokenize(line: &str, start: usize) -> Result<Vec<Located<'_>>, Failure<'_>> {
+  let mut tokens = Vec::new();
+  let mut offset = start;
+  while let Some(c) = line[offset..].chars().next() {
+    if c.is_whitespace() {
+      offset += c.len_utf8();
+      continue;
+    }
+    let rest = &line[offset..];
+    let length = match c {
+      '0'..='9' => rest.find(|c: char| !c.is_ascii_digit()),
+      'a'..='z' | 'A'..='Z' => rest.find(|c: char| !c.is_alphanumeric()),
+      _ => Some(c.len_utf8()),
+    }
+    .unwrap_or(rest.len());
+    let fragment = &rest[..length];
+    let token = match c {
+      '0'..='9' => Token::Number(fragment.parse().map_err(|_| {
+        (
+          offset,
+          fragment,
+          "constant does not fit a worry level".to_owned(),
+        )
+      })?),
+      _ if fragment == "old" => Token::Old,
+      '+' => Token::Operator(Operator::Add),
+      '-' => Token::Operator(Operator::Subtract),
+      '*' => Token::Operator(Operator::Multiply),
+      '/' => Token::Operator(Operator::Divide),
+      '(' => Token::Open,
+      ')' => Token::Close,
+      _ => {
+        return Err((
+          offset,
+          fragment,
+          "expected 'old', an integer, an operator or a parenthesis".to_owned(),
+        ))
+      }
+    };
+    tokens.push((offset, fragment, token));
+    offset += length;
+  }
+
+  Ok(tokens)
+}
+
+/* precedence climbing over the tokens, operators are left associative and
+a `-` in front of an operand negates it */
+struct Parser<'a> {
+  line: &'a str,
+  tokens: Vec<Located<'a>>,
+  position: usize,
+}
+
+impl<'a> Parser<'a> {
+  fn next(&mut self) -> Result<Located<'a>, Failure<'a>> {
+    let token = self.tokens.get(self.position).copied().ok_or_else(|| {
+      let end = self.line.len();
+      (
+        end,
+        &self.line[end..],
+        "unexpected end of expression".to_owned(),
+      )
+    })?;
+    self.position += 1;
+    Ok(token)
+  }
+
+  fn expression(&mut self, min_precedence: u8) -> Result<Expr, Failure<'a>> {
+    let mut lhs = self.operand()?;
+    while let Some(&(_, _, Token::Operator(operator))) =
+      self.tokens.get(self.position)
+    {
+      if operator.precedence() < min_precedence {
+        break;
+      }
+      self.position += 1;
+      let rhs = self.expression(operator.precedence() + 1)?;
+      lhs = Expr::Binary(Box::new(lhs), operator, Box::new(rhs));
+    }
+
+    Ok(lhs)
+  }
+
+  fn operand(&mut self) -> Result<Expr, Failure<'a>> {
+    match self.next()? {
+      (_, _, Token::Old) => Ok(Expr::Old),
+      (_, _, Token::Number(value)) => Ok(Expr::Constant(value)),
+      (_, _, Token::Operator(Operator::Subtract)) => match self.operand()? {
+        Expr::Constant(value) => Ok(Expr::Constant(-value)),
+        operand => Ok(Expr::Negate(Box::new(operand))),
+      },
+      (_, _, Token::Open) => {
+        let expr = self.expression(0)?;
+        match self.next()? {
+          (_, _, Token::Close) => Ok(expr),
+          (offset, fragment, _) => {
+            Err((offset, fragment, "expected ')'".to_owned()))
+          }
+        }
+      }
+      (offset, fragment, _) => Err((
+        offset,
+        fragment,
+        "expected 'old', an integer, '-' or '('".to_owned(),
+      )),
+    }
+  }
+}
+
+#[cfg(test)]
+#[path = "./tests/operation_tests.rs"]
+mod operation_tests;
@@ -1,19 +1,21 @@
+use aoc_core::problem_solver::{ParseError, ProblemSolver};
+use serde::Serialize;
 use std::collections::VecDeque;
 
 use super::common::*;
-use super::problem_solver::ProblemSolver;
 
 
-const ROUNDS: usize = 20;
-const RELIEF: i64 = 3;
+pub const ROUNDS: usize = 20;
+pub const RELIEF: i64 = 3;
 
 pub struct PSInput {
   monkeys: Vec<Monkey>,
   items: Vec<VecDeque<i64>>, // separate because iterating each monkey we must mutate each other's items. assignment there requires a double borrow if from monkey.items
 }
 
+#[derive(Serialize)]
 pub struct PSSolution {
-  monkey_business: i64,
+  monkey_business: Result<i64, String>,
 }
 
 pub struct ProblemSolverPattern;
@@ -22,31 +24,31 @@ impl ProblemSolver for ProblemSolverPattern {
   type Input = PSInput;
   type Solution = PSSolution;
 
-  fn initialize(lines: impl Iterator<Item = String>) -> Self::Input {
-    let (items, monkeys): (Vec<VecDeque<i64>>, Vec<Monkey>) =
-      convert_vec_to_vec_and_vec(
-        lines
-          .collect::<Vec<String>>()
-          .chunks(7)
-          .map(|record| Monkey::input_props_from(record.to_vec()))
-          .collect(),
-      );
-
-    PSInput { monkeys, items }
+  fn initialize(
+    lines: impl Iterator<Item = String>,
+  ) -> Result<Self::Input, ParseError> {
+    let (items, monkeys) = read_troup(lines)?;
+
+    Ok(PSInput { monkeys, items })
   }
 
   fn solve(input: Self::Input) -> Self::Solution {
-    let mut tallies =
-      factory_ordered_troup_tallies(input.monkeys, input.items, ROUNDS, |x| {
-        x / RELIEF
-      });
+    let tallies = factory_ordered_troup_tallies(
+      input.monkeys,
+      input.items,
+      ROUNDS,
+      Relief::Divide(RELIEF),
+    );
     let monkey_business =
-      (tallies.pop().unwrap() * tallies.pop().unwrap()) as i64;
+      tallies.map(monkey_business).map_err(|err| err.to_string());
 
     PSSolution { monkey_business }
   }
 
   fn output(solution: Self::Solution) {
-    dbg!(solution.monkey_business);
+    match solution.monkey_business {
+      Ok(monkey_business) => println!("monkey business: {monkey_business}"),
+      Err(err) => eprintln!("{err}"),
+    }
   }
 }
@@ -1,10 +1,13 @@
-mod part2_module;
-use part2_module::ProblemSolverPattern;
-mod problem_solver;
-use problem_solver::solve_problem;
-mod common;
+use aoc_core::problem_solver::solve_problem;
+use day_11::{
+  cli,
+  common::Relief,
+  part2_module::{ProblemSolverPattern, ROUNDS},
+};
 
 
 fn main() {
-  solve_problem::<ProblemSolverPattern>();
+  if !cli::run_from_args(ROUNDS, Relief::Reduce) {
+    solve_problem::<ProblemSolverPattern>();
+  }
 }
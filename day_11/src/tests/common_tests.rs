@@ -0,0 +1,140 @@
+use crate::common::*;
+
+
+const SAMPLE: &str = include_str!("../../sample.txt");
+
+fn lines(input: &str) -> impl Iterator<Item = String> + '_ {
+  input.lines().map(String::from)
+}
+
+fn error_line(input: &str) -> String {
+  read_troup(lines(input))
+    .err()
+    .expect("the troup was accepted")
+    .render("input")
+    .lines()
+    .nth(1)
+    .unwrap()
+    .to_owned()
+}
+
+#[test]
+fn it_should_find_the_sample_monkey_business() {
+  // Arrange
+  let (items, monkeys) = read_troup(lines(SAMPLE)).unwrap();
+
+  // Act
+  let part1 = factory_ordered_troup_tallies(
+    monkeys.clone(),
+    items.clone(),
+    20,
+    Relief::Divide(3),
+  );
+  let part2 =
+    factory_ordered_troup_tallies(monkeys, items, 10_000, Relief::Reduce);
+
+  // Assert
+  assert_eq!(part1.map(monkey_business), Ok(10605));
+  assert_eq!(part2.map(monkey_business), Ok(2713310158));
+}
+
+#[test]
+fn it_should_reject_a_zero_divisor() {
+  // Arrange
+  let input = SAMPLE.replacen("divisible by 23", "divisible by 0", 1);
+
+  // Act
+  let location = error_line(&input);
+
+  // Assert
+  assert_eq!(location, " --> input:4:22");
+}
+
+#[test]
+fn it_should_reject_throws_to_missing_monkeys() {
+  // Arrange
+  let input = SAMPLE.replacen(
+    "If false: throw to monkey 3",
+    "If false: throw to monkey 4",
+    1,
+  );
+
+  // Act
+  let location = error_line(&input);
+
+  // Assert
+  assert_eq!(location, " --> input:6:31");
+}
+
+#[test]
+fn it_should_need_two_monkeys() {
+  // Arrange
+  let single: String = SAMPLE.lines().take(6).collect::<Vec<_>>().join("\n");
+
+  // Act & Assert
+  assert!(read_troup(lines(&single)).is_err());
+  assert!(read_troup(lines("")).is_err());
+}
+
+#[test]
+fn it_should_start_over_with_big_integers_on_overflow() {
+  // Arrange
+  let input = SAMPLE.replace("old * 19", "old * 1000000000000");
+  let (items, monkeys) = read_troup(lines(&input)).unwrap();
+  let mut machine_items = items.clone();
+
+  // Act
+  let overflowed = simulate(&monkeys, &mut machine_items, 5, Relief::Unbounded);
+  let tallies =
+    factory_ordered_troup_tallies(monkeys, items, 5, Relief::Unbounded);
+
+  // Assert
+  assert!(matches!(overflowed, Err(InspectionError { monkey: 0, .. })));
+  assert!(tallies.is_ok());
+}
+
+#[test]
+fn it_should_report_a_division_by_zero() {
+  // Arrange
+  let input = SAMPLE.replace("old * old", "old / (old - old)");
+  let (items, monkeys) = read_troup(lines(&input)).unwrap();
+
+  // Act
+  let tallies =
+    factory_ordered_troup_tallies(monkeys, items, 1, Relief::Reduce);
+
+  // Assert
+  assert_eq!(
+    tallies.map(monkey_business).map_err(|err| err.to_string()),
+    Err(
+      "monkey 2 cannot work out 'new = old / (old - old)' for worry level 79 \
+       in round 1"
+        .to_owned()
+    )
+  );
+}
+
+#[test]
+fn it_should_reject_tests_whose_lcm_overflows() {
+  // Arrange
+  let input = SAMPLE
+    .replace("divisible by 23", "divisible by 4294967291")
+    .replace("divisible by 19", "divisible by 4294967311");
+  let (items, monkeys) = read_troup(lines(&input)).unwrap();
+
+  // Act
+  let lcm = tests_lcm(&monkeys);
+  let verified = verify_reduction(&monkeys, &items, 1);
+
+  // Assert
+  assert_eq!(
+    lcm.map_err(|err| err.render("input")),
+    Err(
+      "error: the lcm of the monkeys' tests overflows a 64 bit integer\n \
+       --> input"
+        .to_owned()
+    )
+  );
+  assert_eq!(verified, Err(Divergence::Overflow));
+  assert_eq!(tests_lcm(&read_troup(lines(SAMPLE)).unwrap().1), Ok(96577));
+}
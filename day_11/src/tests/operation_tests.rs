@@ -0,0 +1,100 @@
+use crate::operation::*;
+
+
+const PREFIX: &str = "  Operation: new = ";
+
+fn parse(expression: &str) -> Result<Expr, String> {
+  let line = format!("{PREFIX}{expression}");
+  Expr::parse(0, &line, PREFIX.len()).map_err(|err| err.render("input"))
+}
+
+/* the column the rendered error points at */
+fn error_column(expression: &str) -> usize {
+  let rendered = parse(expression).unwrap_err();
+  let location = rendered.lines().nth(1).unwrap();
+  location.rsplit(':').next().unwrap().parse().unwrap()
+}
+
+#[test]
+fn it_should_give_multiplication_precedence() {
+  // Arrange
+  let expr = parse("old * old + 3").unwrap();
+
+  // Act
+  let new = expr.eval(&5_i64);
+
+  // Assert
+  assert_eq!(new, Some(28));
+  assert_eq!(expr.to_string(), "old * old + 3");
+}
+
+#[test]
+fn it_should_evaluate_parentheses_first() {
+  // Arrange
+  let expr = parse("(old + 1) * 2").unwrap();
+
+  // Act
+  let new = expr.eval(&5_i64);
+
+  // Assert
+  assert_eq!(new, Some(12));
+  assert_eq!(expr.to_string(), "(old + 1) * 2");
+}
+
+#[test]
+fn it_should_associate_to_the_left() {
+  // Arrange
+  let expr = parse("old - 2 - 1").unwrap();
+
+  // Act & Assert
+  assert_eq!(expr.eval(&10_i64), Some(7));
+  assert_eq!(parse("old - (2 - 1)").unwrap().eval(&10_i64), Some(9));
+}
+
+#[test]
+fn it_should_not_reduce_through_a_division() {
+  // Arrange
+  let halved = parse("old / 2").unwrap();
+  let nested = parse("-(old + old / 2)").unwrap();
+
+  // Act & Assert
+  assert_eq!(halved.eval(&7_i64), Some(3));
+  assert!(!halved.is_modular());
+  assert!(!nested.is_modular());
+  assert!(parse("old * old - 3").unwrap().is_modular());
+}
+
+#[test]
+fn it_should_read_negative_constants_and_unary_minus() {
+  // Arrange
+  let negative = parse("old * -3").unwrap();
+  let negated = parse("-(old + 1) - -old").unwrap();
+
+  // Act & Assert
+  assert_eq!(negative, parse("old * (-3)").unwrap());
+  assert_eq!(negative.eval(&5_i64), Some(-15));
+  assert_eq!(negated.eval(&5_i64), Some(-1));
+  assert_eq!(negated.to_string(), "-(old + 1) - -old");
+  assert_eq!(parse(&negated.to_string()), Ok(negated));
+}
+
+#[test]
+fn it_should_report_overflow_and_division_by_zero() {
+  // Act & Assert
+  assert_eq!(parse("old * old").unwrap().eval(&i64::MAX), None);
+  assert_eq!(parse("-old").unwrap().eval(&i64::MIN), None);
+  assert_eq!(parse("old / (old - 1)").unwrap().eval(&1_i64), None);
+}
+
+#[test]
+fn it_should_point_at_the_malformed_part() {
+  // Arrange
+  let column = |offset: usize| PREFIX.len() + offset + 1;
+
+  // Act & Assert
+  assert_eq!(error_column("old + x"), column(6));
+  assert_eq!(error_column("old old"), column(4));
+  assert_eq!(error_column("(old + 1"), column(8));
+  assert_eq!(error_column("old * )"), column(6));
+  assert_eq!(error_column("old + 99999999999999999999"), column(6));
+}
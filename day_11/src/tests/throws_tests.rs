@@ -0,0 +1,131 @@
+use std::{collections::VecDeque, env, fs, path::PathBuf};
+
+use crate::{common::*, throws::*};
+
+
+const SAMPLE: &str = include_str!("../../sample.txt");
+const ROUNDS: usize = 4;
+
+/* the sample's starting items, its monkeys and the throws of its first
+rounds */
+fn recorded() -> (Vec<VecDeque<i64>>, Vec<Monkey>, Vec<Throw>) {
+  let (items, monkeys) = read_troup(SAMPLE.lines().map(String::from)).unwrap();
+  let mut throws = Vec::new();
+  simulate_with(
+    &monkeys,
+    &mut items.clone(),
+    ROUNDS,
+    Relief::Divide(3),
+    |throw| throws.push(throw),
+  )
+  .unwrap();
+  (items, monkeys, throws)
+}
+
+fn temporary(name: &str) -> PathBuf {
+  env::temp_dir().join(format!("day_11_{}_{name}", std::process::id()))
+}
+
+#[test]
+fn it_should_replay_the_recorded_rounds() {
+  // Arrange
+  let (items, monkeys, throws) = recorded();
+  let mut simulated = items.clone();
+  simulate(&monkeys, &mut simulated, ROUNDS, Relief::Divide(3)).unwrap();
+
+  // Act
+  let replayed = replay(&items, &throws, ROUNDS);
+
+  // Assert
+  assert_eq!(replayed, Ok(simulated.clone()));
+  assert_eq!(snapshot(&replayed.unwrap()), snapshot(&simulated));
+}
+
+#[test]
+fn it_should_replay_up_to_a_round() {
+  // Arrange
+  let (items, monkeys, throws) = recorded();
+  let mut simulated = items.clone();
+  simulate(&monkeys, &mut simulated, 1, Relief::Divide(3)).unwrap();
+
+  // Act
+  let replayed = replay(&items, &throws, 1).unwrap();
+
+  // Assert
+  assert_eq!(
+    snapshot(&replayed),
+    "Monkey 0: 20, 23, 27, 26\n\
+     Monkey 1: 2080, 25, 167, 207, 401, 1046\n\
+     Monkey 2:\n\
+     Monkey 3:"
+  );
+  assert_eq!(replayed, simulated);
+}
+
+#[test]
+fn it_should_point_at_the_first_throw_that_does_not_fit() {
+  // Arrange
+  let (items, _, mut throws) = recorded();
+  throws[5].before += 1;
+
+  // Act
+  let replayed = replay(&items, &throws, ROUNDS);
+
+  // Assert
+  assert_eq!(replayed, Err(5));
+}
+
+#[test]
+fn it_should_read_back_the_csv_it_writes() {
+  // Arrange
+  let (_, _, throws) = recorded();
+
+  // Act
+  let csv = to_csv(&throws);
+  let read = from_csv(csv.lines().map(String::from));
+
+  // Assert
+  assert!(csv.starts_with("round,from,to,before,after\n1,0,3,79,500\n"));
+  assert_eq!(read, Ok(throws));
+}
+
+#[test]
+fn it_should_reject_malformed_csv() {
+  // Arrange
+  let traces = [
+    "",
+    "round,from,to\n",
+    "round,from,to,before,after\n1,0,3,79\n",
+    "round,from,to,before,after\n1,-1,3,79,500\n",
+    "round,from,to,before,after\n1,0,three,79,500\n",
+  ];
+
+  // Act & Assert
+  for trace in traces {
+    assert!(
+      from_csv(trace.lines().map(String::from)).is_err(),
+      "{trace}"
+    );
+  }
+}
+
+#[test]
+fn it_should_write_and_read_both_trace_formats() {
+  // Arrange
+  let (_, _, throws) = recorded();
+  let paths = [temporary("trace.csv"), temporary("trace.json")];
+
+  // Act
+  let read: Vec<_> = paths
+    .iter()
+    .map(|path| {
+      write(path, &throws).unwrap();
+      let read = read(path);
+      fs::remove_file(path).unwrap();
+      read
+    })
+    .collect();
+
+  // Assert
+  assert_eq!(read, [Ok(throws.clone()), Ok(throws)]);
+}
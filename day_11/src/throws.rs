@@ -0,0 +1,141 @@
+use aoc_core::problem_solver::ParseError;
+use serde::{Deserialize, Serialize};
+use std::{collections::VecDeque, fmt::Write as _, fs, io, path::Path};
+
+
+const CSV_HEADER: &str = "round,from,to,before,after";
+
+/* one item changing hands, worry levels before inspection and after relief */
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Throw<W = i64> {
+  pub round: usize,
+  pub from: usize,
+  pub to: usize,
+  pub before: W,
+  pub after: W,
+}
+
+pub fn to_csv(throws: &[Throw]) -> String {
+  let mut csv = format!("{CSV_HEADER}\n");
+  for Throw { round, from, to, before, after } in throws {
+    let _ = writeln!(csv, "{round},{from},{to},{before},{after}");
+  }
+  csv
+}
+
+pub fn from_csv(
+  lines: impl Iterator<Item = String>,
+) -> Result<Vec<Throw>, ParseError> {
+  let mut lines = lines.enumerate();
+  match lines.next() {
+    Some((_, header)) if header == CSV_HEADER => {}
+    Some((index, header)) => {
+      return Err(ParseError::new(
+        index,
+        &header,
+        format!("expected the '{CSV_HEADER}' header"),
+      ))
+    }
+    None => return Err(ParseError::whole_input("empty trace")),
+  }
+
+  lines
+    .filter(|(_, line)| !line.is_empty())
+    .map(|(index, line)| {
+      let fields = line
+        .split(',')
+        .map(|field| {
+          field.trim().parse::<i64>().map_err(|_| {
+            ParseError::new(index, &line, "expected an integer").at(field)
+          })
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+      match fields[..] {
+        [round, from, to, before, after]
+          if round >= 0 && from >= 0 && to >= 0 =>
+        {
+          Ok(Throw {
+            round: round as usize,
+            from: from as usize,
+            to: to as usize,
+            before,
+            after,
+          })
+        }
+        _ => Err(ParseError::new(
+          index,
+          &line,
+          format!("expected '{CSV_HEADER}'"),
+        )),
+      }
+    })
+    .collect()
+}
+
+/* `.json` files hold an array of throws, anything else is csv */
+fn is_json(path: &Path) -> bool {
+  path
+    .extension()
+    .is_some_and(|extension| extension == "json")
+}
+
+pub fn write(path: &Path, throws: &[Throw]) -> io::Result<()> {
+  let contents = match is_json(path) {
+    true => serde_json::to_string(throws)?,
+    false => to_csv(throws),
+  };
+  fs::write(path, contents)
+}
+
+/* errors are rendered for the terminal */
+pub fn read(path: &Path) -> Result<Vec<Throw>, String> {
+  let origin = path.display().to_string();
+  let contents = fs::read_to_string(path)
+    .map_err(|err| format!("Failed to open trace {origin}: {err}"))?;
+  match is_json(path) {
+    true => serde_json::from_str(&contents)
+      .map_err(|err| format!("error: {err}\n --> {origin}")),
+    false => from_csv(contents.lines().map(String::from))
+      .map_err(|err| err.render(&origin)),
+  }
+}
+
+/* the inventories after `round` rounds, rebuilt from the starting items by
+replaying the recorded throws. `Err` holds the index of the first throw whose
+item is not the next one its monkey holds */
+pub fn replay(
+  items: &[VecDeque<i64>],
+  throws: &[Throw],
+  round: usize,
+) -> Result<Vec<VecDeque<i64>>, usize> {
+  let mut items = items.to_vec();
+  let replayed = throws.iter().take_while(|throw| throw.round <= round);
+  for (index, throw) in replayed.enumerate() {
+    let held = items.get_mut(throw.from).and_then(VecDeque::pop_front);
+    if held != Some(throw.before) || throw.to >= items.len() {
+      return Err(index);
+    }
+    items[throw.to].push_back(throw.after);
+  }
+
+  Ok(items)
+}
+
+/* the puzzle's own notation, one monkey per line */
+pub fn snapshot(items: &[VecDeque<i64>]) -> String {
+  items
+    .iter()
+    .enumerate()
+    .map(|(monkey, held)| {
+      let held: Vec<String> = held.iter().map(i64::to_string).collect();
+      format!("Monkey {monkey}: {}", held.join(", "))
+        .trim_end()
+        .to_owned()
+    })
+    .collect::<Vec<_>>()
+    .join("\n")
+}
+
+#[cfg(test)]
+#[path = "./tests/throws_tests.rs"]
+mod throws_tests;
@@ -0,0 +1,72 @@
+use num_bigint::BigInt;
+use num_integer::Integer;
+use std::fmt;
+
+use super::operation::Operator;
+
+
+/* a worry level, either a machine integer kept small by reduction or an
+unbounded one used to check that the reduction did not change anything */
+pub trait Worry: Clone + PartialEq + fmt::Display {
+  fn from_i64(value: i64) -> Self;
+  /* `None` on overflow or division by zero */
+  fn combine(&self, operator: Operator, rhs: &Self) -> Option<Self>;
+  fn is_divisible_by(&self, divisor: i64) -> bool;
+  fn reduce(&self, modulus: i64) -> Self;
+  fn relieve(&self, divisor: i64) -> Self;
+}
+
+impl Worry for i64 {
+  fn from_i64(value: i64) -> Self {
+    value
+  }
+
+  fn combine(&self, operator: Operator, rhs: &Self) -> Option<Self> {
+    match operator {
+      Operator::Add => self.checked_add(*rhs),
+      Operator::Subtract => self.checked_sub(*rhs),
+      Operator::Multiply => self.checked_mul(*rhs),
+      Operator::Divide => self.checked_div(*rhs),
+    }
+  }
+
+  fn is_divisible_by(&self, divisor: i64) -> bool {
+    self.rem_euclid(divisor) == 0
+  }
+
+  fn reduce(&self, modulus: i64) -> Self {
+    self.rem_euclid(modulus)
+  }
+
+  fn relieve(&self, divisor: i64) -> Self {
+    self / divisor
+  }
+}
+
+impl Worry for BigInt {
+  fn from_i64(value: i64) -> Self {
+    BigInt::from(value)
+  }
+
+  fn combine(&self, operator: Operator, rhs: &Self) -> Option<Self> {
+    match operator {
+      Operator::Add => Some(self + rhs),
+      Operator::Subtract => Some(self - rhs),
+      Operator::Multiply => Some(self * rhs),
+      Operator::Divide if *rhs == BigInt::from(0) => None,
+      Operator::Divide => Some(self / rhs),
+    }
+  }
+
+  fn is_divisible_by(&self, divisor: i64) -> bool {
+    self.is_multiple_of(&BigInt::from(divisor))
+  }
+
+  fn reduce(&self, modulus: i64) -> Self {
+    self.mod_floor(&BigInt::from(modulus))
+  }
+
+  fn relieve(&self, divisor: i64) -> Self {
+    self / divisor
+  }
+}
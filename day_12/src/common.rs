@@ -1,3 +1,4 @@
+use aoc_core::grid::Grid;
 use petgraph::prelude::*;
 use petgraph::{
   algo::{astar, dijkstra},
@@ -5,62 +6,37 @@ use petgraph::{
 };
 
 pub fn factory_graph_from_map(
-  map: Vec<String>,
+  mut map: Grid<char>,
 ) -> (Option<usize>, Option<usize>, Graph<usize, ()>, Vec<char>) {
-  let height = map.len();
-  let width = map[0].len();
-  let mut flattened: Vec<char> = map
-    .into_iter()
-    .flat_map(|l| l.chars().collect::<Vec<char>>())
-    .collect();
-  let start = flattened.iter().position(|&ch| ch == 'S');
+  let start = map.position(|&ch| ch == 'S');
   if let Some(s) = start {
-    flattened[s] = 'a';
+    map[s] = 'a';
   }
-  let end = flattened.iter().position(|&ch| ch == 'E');
+  let end = map.position(|&ch| ch == 'E');
   if let Some(e) = end {
-    flattened[e] = 'z';
+    map[e] = 'z';
   }
 
-  let edges = factory_edges(&flattened, height, width);
-  let graph = factory_graph(edges, width * height);
+  let edges = factory_edges(&map);
+  let graph = factory_graph(edges, map.cells().len());
+  let start = start.map(|s| map.index_of(s));
+  let end = end.map(|e| map.index_of(e));
 
-  (start, end, graph, flattened)
+  (start, end, graph, map.into_cells())
 }
 
-fn factory_edges(
-  flattened: &[char],
-  height: usize,
-  width: usize,
-) -> Vec<(usize, usize)> {
-  let mut edges = Vec::new();
-  for (index, &ch) in flattened.iter().enumerate() {
-    let row = index / width;
-    let col = index % width;
-    let current_node = row * width + col;
-    let next_char = (ch as u8 + 1) as char;
-    if row > 0 && flattened[index - width] <= next_char {
-      let target_node = (row - 1) * width + col;
-      edges.push((current_node, target_node));
-    }
-
-    if row < height - 1 && flattened[index + width] <= next_char {
-      let target_node = (row + 1) * width + col;
-      edges.push((current_node, target_node));
-    }
-
-    if col > 0 && flattened[index - 1] <= next_char {
-      let target_node = row * width + (col - 1);
-      edges.push((current_node, target_node));
-    }
-
-    if col < width - 1 && flattened[index + 1] <= next_char {
-      let target_node = row * width + (col + 1);
-      edges.push((current_node, target_node));
-    }
-  }
-
-  edges
+/* a step may climb at most one letter, but descend any distance */
+fn factory_edges(map: &Grid<char>) -> Vec<(usize, usize)> {
+  map
+    .iter()
+    .flat_map(|(position, &ch)| {
+      let next_char = (ch as u8 + 1) as char;
+      map
+        .neighbors4(position)
+        .filter(move |&neighbor| map[neighbor] <= next_char)
+        .map(move |neighbor| (map.index_of(position), map.index_of(neighbor)))
+    })
+    .collect()
 }
 
 fn factory_graph(
@@ -93,7 +69,7 @@ pub fn find_path_part1(
     .find(|i| graph[*i] == end_index)
     .unwrap();
 
-  dbg!(start, end);
+  tracing::debug!(?start, ?end);
 
   astar(&graph, start, |curr| curr == end, |_| 1, |_| 0)
 }
@@ -114,7 +90,7 @@ pub fn find_path_part2(
     .find(|i| graph[*i] == end_index)
     .unwrap();
 
-  dbg!(start, end);
+  tracing::debug!(?start, ?end);
 
   graph.reverse();
   let distances = dijkstra(&graph, end, None, |_| 1);
@@ -132,7 +108,8 @@ pub fn find_path_part2(
     })
     .1
     .unwrap_or_default();
-  dbg!(&distances, best);
+  tracing::trace!(?distances);
+  tracing::debug!(?best);
   graph.reverse();
   (
     astar(&graph, best, |curr| curr == end, |_| 1, |_| 1),
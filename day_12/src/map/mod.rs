@@ -86,7 +86,7 @@ fn map_events(
   mut commands: Commands,
 ) {
   for event in event_reader.iter() {
-    dbg!(event);
+    debug!(?event, "map event");
     match event {
       Event::MapStart => {
         local.clear();
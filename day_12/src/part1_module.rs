@@ -1,9 +1,11 @@
 use petgraph::graph::Graph;
 use petgraph::prelude::*;
 //use std::collections::HashMap;
+use aoc_core::grid::Grid;
+use aoc_core::problem_solver::{ParseError, ProblemSolver};
+use serde::Serialize;
 
 use crate::common::{factory_graph_from_map, find_path_part1};
-use crate::problem_solver::ProblemSolver;
 
 
 pub struct PSInput {
@@ -13,11 +15,14 @@ pub struct PSInput {
   flattened: Vec<char>,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Serialize)]
 pub struct PSSolution {
+  #[serde(skip)]
   path: Option<(i32, Vec<NodeIndex>)>,
   //end: NodeIndex,
+  #[serde(skip)]
   flattened: Vec<char>,
+  cost: Option<i32>,
 }
 
 pub struct ProblemSolverPattern;
@@ -26,14 +31,20 @@ impl ProblemSolver for ProblemSolverPattern {
   type Input = PSInput;
   type Solution = PSSolution;
 
-  fn initialize(lines: impl Iterator<Item = String>) -> Self::Input {
-    let map: Vec<String> = lines.collect();
+  fn initialize(
+    lines: impl Iterator<Item = String>,
+  ) -> Result<Self::Input, ParseError> {
+    let map = Grid::parse(lines, "expected a height, 'S' or 'E'", |c| {
+      (c.is_ascii_lowercase() || c == 'S' || c == 'E').then_some(c)
+    })?;
     let (start_opt, end_opt, graph, flattened) = factory_graph_from_map(map);
 
-    let start = start_opt.unwrap();
-    let end = end_opt.unwrap();
+    let start = start_opt
+      .ok_or_else(|| ParseError::whole_input("height map has no start 'S'"))?;
+    let end = end_opt
+      .ok_or_else(|| ParseError::whole_input("height map has no end 'E'"))?;
 
-    Self::Input { graph, start, end, flattened }
+    Ok(Self::Input { graph, start, end, flattened })
   }
 
   fn solve(input: Self::Input) -> Self::Solution {
@@ -44,7 +55,9 @@ impl ProblemSolver for ProblemSolverPattern {
     flattened[input.start] = 'S';
     flattened[input.end] = 'E';
 
-    Self::Solution { path, /* end, */ flattened }
+    let cost = path.as_ref().map(|(cost, _)| *cost);
+
+    Self::Solution { path, /* end, */ flattened, cost }
   }
 
   fn output(solution: Self::Solution) {
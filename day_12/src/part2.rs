@@ -1,7 +1,7 @@
+use aoc_core::problem_solver::solve_problem;
+
 mod part2_module;
 use part2_module::ProblemSolverPattern;
-mod problem_solver;
-use problem_solver::solve_problem;
 mod common;
 
 fn main() {
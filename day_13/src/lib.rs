@@ -0,0 +1,3 @@
+pub mod packet;
+pub mod part1_module;
+pub mod part2_module;
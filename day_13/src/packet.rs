@@ -0,0 +1,205 @@
+use aoc_core::problem_solver::ParseError;
+use serde_json::Value;
+use std::{cmp::Ordering, fmt};
+
+
+pub const DIVIDERS: [&str; 2] = ["[[2]]", "[[6]]"];
+
+/* an integer or a list of packets, ordered by the distress signal rules */
+#[derive(Clone, Debug)]
+pub enum Packet {
+  Integer(i64),
+  List(Vec<Packet>),
+}
+
+impl Packet {
+  pub fn parse(index: usize, line: &str) -> Result<Packet, ParseError> {
+    let mut parser = Parser { line, position: 0 };
+    let located = |(position, message): (usize, &str)| {
+      let text = line[position..].chars().next().map(String::from);
+      ParseError::new(index, line, message).at_column(
+        line[..position].chars().count() + 1,
+        &text.unwrap_or_default(),
+      )
+    };
+    let packet = parser.packet().map_err(located)?;
+    parser.skip_whitespace();
+    match parser.position < line.len() {
+      true => Err(located((parser.position, "expected the end of the packet"))),
+      false => Ok(packet),
+    }
+  }
+}
+
+/* the divider packets the decoder key is built from */
+pub fn dividers() -> [Packet; 2] {
+  DIVIDERS.map(|divider| Packet::parse(0, divider).unwrap())
+}
+
+/* the packets with the dividers, in order. the sort is stable so a packet
+equal to a divider stays in front of it */
+pub fn sort_with_dividers(mut packets: Vec<Packet>) -> Vec<Packet> {
+  packets.extend(dividers());
+  packets.sort();
+  packets
+}
+
+/* one packet per line, blank lines between pairs are skipped */
+pub fn read_packets(
+  lines: impl Iterator<Item = String>,
+) -> Result<Vec<Packet>, ParseError> {
+  lines
+    .enumerate()
+    .filter(|(_, line)| !line.trim().is_empty())
+    .map(|(index, line)| Packet::parse(index, &line))
+    .collect()
+}
+
+/* canonical text, no whitespace, which parses back to the same packet */
+impl fmt::Display for Packet {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    match self {
+      Packet::Integer(value) => write!(f, "{value}"),
+      Packet::List(packets) => {
+        write!(f, "[")?;
+        for (index, packet) in packets.iter().enumerate() {
+          if index > 0 {
+            write!(f, ",")?;
+          }
+          write!(f, "{packet}")?;
+        }
+        write!(f, "]")
+      }
+    }
+  }
+}
+
+/* packets are the integer and array subset of json */
+impl From<&Packet> for Value {
+  fn from(packet: &Packet) -> Self {
+    match packet {
+      Packet::Integer(value) => Value::from(*value),
+      Packet::List(packets) => packets.iter().map(Value::from).collect(),
+    }
+  }
+}
+
+/* anything but integers and arrays is handed back as the error */
+impl TryFrom<Value> for Packet {
+  type Error = Value;
+
+  fn try_from(value: Value) -> Result<Self, Self::Error> {
+    match value {
+      Value::Number(_) => value.as_i64().map(Packet::Integer).ok_or(value),
+      Value::Array(values) => values
+        .into_iter()
+        .map(Packet::try_from)
+        .collect::<Result<_, _>>()
+        .map(Packet::List),
+      _ => Err(value),
+    }
+  }
+}
+
+/* integers compare by value and lists element by element, the shorter list
+first when one runs out. an integer meeting a list is compared as a list
+holding just that integer */
+impl Ord for Packet {
+  fn cmp(&self, other: &Self) -> Ordering {
+    match (self, other) {
+      (Packet::Integer(left), Packet::Integer(right)) => left.cmp(right),
+      (Packet::List(left), Packet::List(right)) => left.cmp(right),
+      (Packet::Integer(_), Packet::List(right)) => {
+        std::slice::from_ref(self).cmp(right)
+      }
+      (Packet::List(left), Packet::Integer(_)) => {
+        left.as_slice().cmp(std::slice::from_ref(other))
+      }
+    }
+  }
+}
+
+impl PartialOrd for Packet {
+  fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+    Some(self.cmp(other))
+  }
+}
+
+/* equality follows the ordering, so `2` and `[[2]]` are equal packets */
+impl PartialEq for Packet {
+  fn eq(&self, other: &Self) -> bool {
+    self.cmp(other) == Ordering::Equal
+  }
+}
+
+impl Eq for Packet {}
+
+/* recursive descent over the line, failures carry the byte position they
+were detected at. whitespace between tokens is ignored */
+struct Parser<'a> {
+  line: &'a str,
+  position: usize,
+}
+
+type Failure = (usize, &'static str);
+
+impl Parser<'_> {
+  fn peek(&self) -> Option<u8> {
+    self.line.as_bytes().get(self.position).copied()
+  }
+
+  fn skip_whitespace(&mut self) {
+    while self.peek().is_some_and(|byte| byte.is_ascii_whitespace()) {
+      self.position += 1;
+    }
+  }
+
+  fn packet(&mut self) -> Result<Packet, Failure> {
+    self.skip_whitespace();
+    match self.peek() {
+      Some(b'[') => self.list(),
+      Some(b'-' | b'0'..=b'9') => self.integer(),
+      _ => Err((self.position, "expected '[' or an integer")),
+    }
+  }
+
+  fn list(&mut self) -> Result<Packet, Failure> {
+    self.position += 1;
+    let mut packets = Vec::new();
+    self.skip_whitespace();
+    if self.peek() == Some(b']') {
+      self.position += 1;
+      return Ok(Packet::List(packets));
+    }
+    loop {
+      packets.push(self.packet()?);
+      self.skip_whitespace();
+      match self.peek() {
+        Some(b',') => self.position += 1,
+        Some(b']') => {
+          self.position += 1;
+          return Ok(Packet::List(packets));
+        }
+        _ => return Err((self.position, "expected ',' or ']'")),
+      }
+    }
+  }
+
+  fn integer(&mut self) -> Result<Packet, Failure> {
+    let start = self.position;
+    if self.peek() == Some(b'-') {
+      self.position += 1;
+    }
+    while let Some(b'0'..=b'9') = self.peek() {
+      self.position += 1;
+    }
+    self.line[start..self.position]
+      .parse()
+      .map(Packet::Integer)
+      .map_err(|_| (start, "expected a 64 bit integer"))
+  }
+}
+
+#[cfg(test)]
+#[path = "./tests/packet_tests.rs"]
+mod packet_tests;
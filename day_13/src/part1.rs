@@ -1,8 +1,5 @@
-mod part1_module;
-use part1_module::ProblemSolverPattern;
-mod problem_solver;
-use problem_solver::solve_problem;
-mod common;
+use aoc_core::problem_solver::solve_problem;
+use day_13::part1_module::ProblemSolverPattern;
 
 
 fn main() {
@@ -1,15 +1,16 @@
-use std::cmp::Ordering;
-
-use super::common::*;
-use super::problem_solver::ProblemSolver;
+use super::packet::{read_packets, Packet};
+use aoc_core::problem_solver::{ParseError, ProblemSolver};
+use serde::Serialize;
 
 
 pub struct PSInput {
-  pairs: Vec<(String, String)>,
+  pairs: Vec<(Packet, Packet)>,
 }
 
+#[derive(Serialize)]
 pub struct PSSolution {
   ordered_indices: Vec<usize>,
+  sum: usize,
 }
 
 pub struct ProblemSolverPattern;
@@ -18,15 +19,19 @@ impl ProblemSolver for ProblemSolverPattern {
   type Input = PSInput;
   type Solution = PSSolution;
 
-  fn initialize(lines: impl Iterator<Item = String>) -> Self::Input {
-    let pairs = lines
-      .filter(|l| !l.is_empty())
-      .collect::<Vec<String>>()
+  fn initialize(
+    lines: impl Iterator<Item = String>,
+  ) -> Result<Self::Input, ParseError> {
+    let packets = read_packets(lines)?;
+    if packets.len() % 2 != 0 {
+      return Err(ParseError::whole_input("the last packet has no pair"));
+    }
+    let pairs = packets
       .chunks(2)
-      .map(|str_slices| (str_slices[0].to_string(), str_slices[1].to_string()))
+      .map(|pair| (pair[0].clone(), pair[1].clone()))
       .collect();
 
-    Self::Input { pairs }
+    Ok(Self::Input { pairs })
   }
 
   fn solve(input: Self::Input) -> Self::Solution {
@@ -34,27 +39,18 @@ impl ProblemSolver for ProblemSolverPattern {
       .pairs
       .iter()
       .enumerate()
-      .filter_map(|(i, (left, right))| {
-        ordering(i + 1, left.into(), right.into())
-      })
-      .collect();
-    Self::Solution { ordered_indices }
+      .filter(|(_, (left, right))| left <= right)
+      .map(|(i, _)| i + 1)
+      .collect::<Vec<_>>();
+    let sum = ordered_indices.iter().sum();
+
+    Self::Solution { ordered_indices, sum }
   }
 
   fn output(solution: Self::Solution) {
     println!(
       "{:?}\nsum of indices: {}",
-      solution.ordered_indices,
-      solution.ordered_indices.iter().sum::<usize>()
+      solution.ordered_indices, solution.sum
     );
   }
 }
-
-fn ordering(i: usize, left: String, right: String) -> Option<usize> {
-  // we're making parentheses into numbers to ease comparison.
-  if compare(left.as_bytes(), right.as_bytes()) != Ordering::Greater {
-    Some(i)
-  } else {
-    None
-  }
-}
@@ -1,8 +1,5 @@
-mod part2_module;
-use part2_module::ProblemSolverPattern;
-mod problem_solver;
-use problem_solver::solve_problem;
-mod common;
+use aoc_core::problem_solver::solve_problem;
+use day_13::part2_module::ProblemSolverPattern;
 
 
 fn main() {
@@ -1,16 +1,16 @@
-use super::common::*;
-use super::problem_solver::ProblemSolver;
+use aoc_core::problem_solver::{ParseError, ProblemSolver};
+use serde::Serialize;
 
+use super::packet::{dividers, read_packets, Packet};
 
-const TWO: &str = "[[2]]";
-const SIX: &str = "[[6]]";
 
 pub struct PSInput {
-  packets: Vec<String>,
+  packets: Vec<Packet>,
 }
 
+#[derive(Serialize)]
 pub struct PSSolution {
-  ordered_packets: Vec<String>,
+  decoder_key: usize,
 }
 
 pub struct ProblemSolverPattern;
@@ -19,33 +19,38 @@ impl ProblemSolver for ProblemSolverPattern {
   type Input = PSInput;
   type Solution = PSSolution;
 
-  fn initialize(lines: impl Iterator<Item = String>) -> Self::Input {
-    let packets: Vec<String> = lines.filter(|l| !l.is_empty()).collect();
+  fn initialize(
+    lines: impl Iterator<Item = String>,
+  ) -> Result<Self::Input, ParseError> {
+    let packets = read_packets(lines)?;
 
-    Self::Input { packets }
+    Ok(Self::Input { packets })
   }
 
+  /* where a divider lands when sorted in after the packets, a packet equal
+  to it like `[2]` to `[[2]]` staying in front. the dividers are in order so
+  each one follows the dividers listed before it */
   fn solve(input: Self::Input) -> Self::Solution {
-    let mut ordered_packets = input.packets;
-    ordered_packets.extend_from_slice(&[TWO.to_string(), SIX.to_string()]);
-    ordered_packets
-      .sort_by(|left, right| compare(left.as_bytes(), right.as_bytes()));
-    Self::Solution { ordered_packets }
+    let dividers = dividers();
+    let [two_packet, six_packet] = [0, 1].map(|index| {
+      let divider = &dividers[index];
+      let packets_before = input
+        .packets
+        .iter()
+        .filter(|packet| *packet <= divider)
+        .count();
+      packets_before + index + 1
+    });
+    let decoder_key = two_packet * six_packet;
+
+    Self::Solution { decoder_key }
   }
 
   fn output(solution: Self::Solution) {
-    let two_packet = solution
-      .ordered_packets
-      .iter()
-      .position(|s| s == TWO)
-      .unwrap()
-      + 1;
-    let six_packet = solution
-      .ordered_packets
-      .iter()
-      .position(|s| s == SIX)
-      .unwrap()
-      + 1;
-    println!("decoder_key: {}", two_packet * six_packet);
+    println!("decoder_key: {}", solution.decoder_key);
   }
 }
+
+#[cfg(test)]
+#[path = "./tests/part2_tests.rs"]
+mod part2_tests;
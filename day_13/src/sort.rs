@@ -0,0 +1,38 @@
+use aoc_core::problem_solver::InputSource;
+use day_13::packet::{read_packets, sort_with_dividers};
+use serde_json::Value;
+use std::{env, process};
+
+
+/* `<input> [--json]` prints every packet and the dividers in order, one
+canonical packet per line or as a single json array */
+fn main() {
+  let args: Vec<String> = env::args().skip(1).collect();
+  let Some((source, rest)) = InputSource::from_args(&args) else {
+    exit_with(
+      "usage: day_13_sort <input file | - | --input-text <text>> [--json]"
+        .to_owned(),
+    );
+  };
+  let json = rest.iter().any(|arg| arg == "--json");
+
+  let origin = source.origin().to_owned();
+  let lines = source
+    .lines()
+    .unwrap_or_else(|err| exit_with(format!("Failed to open input: {err}")));
+  let packets =
+    read_packets(lines).unwrap_or_else(|err| exit_with(err.render(&origin)));
+  let packets = sort_with_dividers(packets);
+
+  if json {
+    let array: Value = packets.iter().map(Value::from).collect();
+    println!("{array}");
+  } else {
+    packets.iter().for_each(|packet| println!("{packet}"));
+  }
+}
+
+fn exit_with(message: String) -> ! {
+  eprintln!("{message}");
+  process::exit(1);
+}
@@ -0,0 +1,138 @@
+use serde_json::{json, Value};
+use std::cmp::Ordering;
+
+use crate::packet::*;
+
+
+fn packet(line: &str) -> Packet {
+  Packet::parse(0, line).unwrap()
+}
+
+fn compare(left: &str, right: &str) -> Ordering {
+  packet(left).cmp(&packet(right))
+}
+
+/* the location line of the rendered error */
+fn error_location(line: &str) -> String {
+  let rendered = Packet::parse(0, line).unwrap_err().render("input");
+  rendered.lines().nth(1).unwrap().to_owned()
+}
+
+#[test]
+fn it_should_compare_integers_by_value() {
+  // Act & Assert
+  assert_eq!(compare("[10]", "[9]"), Ordering::Greater);
+  assert_eq!(compare("[-1]", "[0]"), Ordering::Less);
+  assert_eq!(compare("[3]", "[3]"), Ordering::Equal);
+}
+
+#[test]
+fn it_should_compare_lists_element_by_element() {
+  // Act & Assert
+  assert_eq!(compare("[1,1,3,1,1]", "[1,1,5,1,1]"), Ordering::Less);
+  assert_eq!(compare("[7,7,7,7]", "[7,7,7]"), Ordering::Greater);
+  assert_eq!(compare("[]", "[3]"), Ordering::Less);
+  assert_eq!(compare("[[[]]]", "[[]]"), Ordering::Greater);
+}
+
+#[test]
+fn it_should_promote_an_integer_meeting_a_list() {
+  // Act & Assert
+  assert_eq!(compare("[[1],[2,3,4]]", "[[1],4]"), Ordering::Less);
+  assert_eq!(compare("[9]", "[[8,7,6]]"), Ordering::Greater);
+  assert_eq!(compare("[2]", "[[2]]"), Ordering::Equal);
+  assert_eq!(compare("[[2]]", "[2,0]"), Ordering::Less);
+}
+
+#[test]
+fn it_should_print_packets_the_way_they_parse() {
+  // Arrange
+  let line = " [ 1, [2 ,[]], -3 ] ";
+
+  // Act
+  let printed = packet(line).to_string();
+
+  // Assert
+  assert_eq!(printed, "[1,[2,[]],-3]");
+  assert_eq!(packet(&printed).to_string(), printed);
+}
+
+#[test]
+fn it_should_point_at_the_malformed_part() {
+  // Act & Assert
+  assert_eq!(error_location("[1,2"), " --> input:1:5");
+  assert_eq!(error_location("[1;2]"), " --> input:1:3");
+  assert_eq!(error_location("[1,]"), " --> input:1:4");
+  assert_eq!(error_location("[1] 2"), " --> input:1:5");
+  assert_eq!(error_location("[99999999999999999999]"), " --> input:1:2");
+  assert_eq!(error_location("x"), " --> input:1:1");
+}
+
+#[test]
+fn it_should_skip_blank_lines_between_pairs() {
+  // Arrange
+  let lines = ["[1]", "[2]", "", "[3]", "[4]"].map(String::from);
+
+  // Act
+  let packets = read_packets(lines.into_iter()).unwrap();
+
+  // Assert
+  assert_eq!(packets.len(), 4);
+}
+
+#[test]
+fn it_should_round_trip_canonical_text() {
+  // Arrange
+  let lines = ["[]", "[[]]", "[1,[2,[3,[4,[5,6,7]]]],8,9]", "[-12,[0]]"];
+
+  // Act & Assert
+  for line in lines {
+    assert_eq!(packet(line).to_string(), line);
+  }
+}
+
+#[test]
+fn it_should_round_trip_through_json() {
+  // Arrange
+  let original = packet("[1,[2,[3,[4,[5,6,7]]]],8,[],-9]");
+
+  // Act
+  let value = Value::from(&original);
+  let packet = Packet::try_from(value.clone()).unwrap();
+
+  // Assert
+  assert_eq!(value.to_string(), "[1,[2,[3,[4,[5,6,7]]]],8,[],-9]");
+  assert_eq!(packet.to_string(), original.to_string());
+}
+
+#[test]
+fn it_should_reject_json_that_is_not_integers_and_arrays() {
+  // Arrange
+  let values = [json!(1.5), json!("1"), json!(null), json!([1, [true]])];
+
+  // Act & Assert
+  for value in values {
+    assert!(Packet::try_from(value).is_err());
+  }
+  assert_eq!(Packet::try_from(json!([1, {}])).unwrap_err(), json!({}));
+}
+
+#[test]
+fn it_should_sort_the_dividers_in_with_the_packets() {
+  // Arrange
+  let lines = include_str!("../../sample.txt").lines().map(String::from);
+  let packets = read_packets(lines).unwrap();
+
+  // Act
+  let sorted: Vec<String> = sort_with_dividers(packets)
+    .iter()
+    .map(Packet::to_string)
+    .collect();
+
+  // Assert
+  let position = |divider| sorted.iter().position(|p| p == divider).unwrap();
+  assert_eq!(sorted.len(), 18);
+  assert_eq!(DIVIDERS.map(position), [9, 13]);
+  assert_eq!(sorted[8..=10], ["[[1],4]", "[[2]]", "[3]"]);
+  assert_eq!(sorted[12..=14], ["[[4,4],4,4,4]", "[[6]]", "[7,7,7]"]);
+}
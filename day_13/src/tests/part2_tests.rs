@@ -0,0 +1,25 @@
+use aoc_core::problem_solver::ProblemSolver;
+
+use crate::part2_module::*;
+
+
+fn decoder_key(input: &str) -> usize {
+  let lines = input.lines().map(String::from);
+  let input = ProblemSolverPattern::initialize(lines).unwrap();
+  ProblemSolverPattern::solve(input).decoder_key
+}
+
+#[test]
+fn it_should_find_the_sample_decoder_key() {
+  // Act & Assert
+  assert_eq!(decoder_key(include_str!("../../sample.txt")), 140);
+}
+
+#[test]
+fn it_should_keep_packets_equal_to_a_divider_in_front_of_it() {
+  // Act
+  let key = decoder_key("[1]\n[3]\n\n[2]\n[7]");
+
+  // Assert
+  assert_eq!(key, 15);
+}
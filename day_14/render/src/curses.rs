@@ -1,11 +1,12 @@
+use day_14::common::{
+  get_render_bounds, prelude::*, AIR, ROCK, SAND, SAND_ENTRY,
+};
 use linked_hash_set::LinkedHashSet;
 use pancurses::{
   endwin, init_pair, initscr, COLOR_BLACK, COLOR_PAIR, COLOR_WHITE,
 };
-use rayon::prelude::*;
 use std::{
   cmp::{max, min},
-  collections::HashSet,
   ops::Deref,
   sync::{
     atomic::{AtomicBool, Ordering},
@@ -14,14 +15,8 @@ use std::{
 };
 use tokio::{sync::Mutex, time::Duration};
 
-use super::common::prelude::*;
-
 
 const FPS: u64 = 1000 / 60;
-const AIR: char = '.';
-const SAND_ENTRY: char = '+';
-const SAND: char = 'o';
-const ROCK: char = '#';
 
 #[derive(Clone, Default)]
 pub struct RenderMap {
@@ -29,7 +24,6 @@ pub struct RenderMap {
   bounds: (usize, usize, usize),
   sand_entry: Option<Coord>,
   entities: LinkedHashSet<Entity>,
-  limit: bool,
 }
 
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
@@ -102,12 +96,7 @@ impl Curses {
       tokio::runtime::Handle::current().block_on(render_map_clone.lock())
     });
     let render_map = render_map_guard.deref();
-    let max_y = if render_map.limit {
-      render_map.bounds.2 + 1
-    } else {
-      render_map.bounds.2
-    };
-    for y in 0..=max_y {
+    for y in 0..=render_map.bounds.2 {
       for x in render_map.bounds.0..=render_map.bounds.1 {
         let terminal_x = x - render_map.bounds.0;
         if let Some(entry) = render_map.sand_entry {
@@ -150,7 +139,7 @@ impl Curses {
       let current_last_entity_position = render_map
         .entities
         .iter()
-        .last()
+        .next_back()
         .map(|e| Entity::position_from_entity(e).unwrap());
 
       if render_map.entities.len() != previous_entities_length {
@@ -230,103 +219,21 @@ impl Curses {
     render_map_guard.sand_entry = Some(coord);
   }
 
-  pub async fn set_limit(&self, value: bool) {
+  /* widens the drawn area from the rocks' extent, `(min x, max x, max y)` */
+  pub async fn set_bounds(&self, bounds: (usize, usize, usize)) {
     let mut render_map_guard = self.render_map.lock().await;
-    render_map_guard.limit = value;
+    render_map_guard.bounds = bounds;
   }
 
   pub fn stop_rendering(&self) {
     self.terminate_render.store(true, Ordering::SeqCst);
   }
 
-  pub async fn release_sand(&self, delay: u64) -> Option<Coord> {
-    let render_map_guard = self.render_map.lock().await;
-
-    let Some(original_position) = render_map_guard.sand_entry else {
-      return None;
-    };
-
-    let entities: HashSet<Entity> =
-      HashSet::from_iter(render_map_guard.entities.clone().into_iter());
-    let is_valid_move = |coord| {
-      entities
-        .par_iter()
-        .all(|entity| !Entity::entity_is_at_coord(entity, coord))
-    };
-    if !is_valid_move(original_position) {
-      return None;
-    }
-    let mut current_position = Some(original_position);
-    let bounds = render_map_guard.bounds;
-    let max_y = if render_map_guard.limit {
-      bounds.2 + 1
-    } else {
-      bounds.2
-    };
-    drop(render_map_guard);
-
-    loop {
-      let mut render_map_guard = self.render_map.lock().await;
-      let position = match current_position {
-        Some(c) if is_valid_move((c.0, c.1 + 1)) => (c.0, c.1 + 1),
-        Some(c) if is_valid_move((c.0 - 1, c.1 + 1)) => (c.0 - 1, c.1 + 1),
-        Some(c) if is_valid_move((c.0 + 1, c.1 + 1)) => (c.0 + 1, c.1 + 1),
-        _ => current_position.unwrap(),
-      };
-      if position == current_position.unwrap() {
-        if render_map_guard.limit && position.1 == 0 {
-          current_position = None;
-        }
-        break;
-      }
-      if render_map_guard.limit {
-        if position.1 > max_y {
-          // Sand reached the last valid y position
-          current_position = Some(position);
-          break;
-        }
-      } else if position.0 < bounds.0
-        || position.0 > bounds.1
-        || position.1 > max_y
-      {
-        render_map_guard
-          .entities
-          .remove(&Entity::Sand(current_position.unwrap()));
-        current_position = None;
-        break;
-      }
-
-      // Update RenderMap with the new sand position
-      render_map_guard
-        .entities
-        .remove(&Entity::Sand(current_position.unwrap()));
-      render_map_guard.entities.insert(Entity::Sand(position));
-
-      current_position = Some(position);
-
-      if delay > 0 {
-        tokio::time::sleep(Duration::from_millis(delay)).await;
-      }
-    }
-
-    current_position
-  }
-}
-
-fn get_render_bounds(paths: &[PathSegment]) -> (usize, usize, usize) {
-  let mut min_x = usize::MAX;
-  let mut max_x = usize::MIN;
-  let mut max_y = usize::MIN;
-
-  for path in paths {
-    for (x, y) in [path.0, path.1] {
-      min_x = min(min_x, x);
-      max_x = max(max_x, x);
-      max_y = max(max_y, y);
-    }
+  /* shows a unit of sand the simulation let come to rest */
+  pub async fn settle_sand(&self, at: Coord) {
+    let mut render_map_guard = self.render_map.lock().await;
+    render_map_guard.entities.insert(Entity::Sand(at));
   }
-
-  (min_x, max_x, max_y)
 }
 
 fn compute_entities(paths: &[PathSegment]) -> LinkedHashSet<Entity> {
@@ -0,0 +1,78 @@
+use crossterm::event::{self, poll, Event, KeyCode};
+use day_14::common::prelude::*;
+use day_14::recording::{Recorder, Recording};
+use day_14::sand::{Cave, Outcome};
+use std::{env, process, sync::Arc, time::Duration};
+
+use super::curses::Curses;
+
+
+/* replays the simulation in the curses window, one unit of sand every
+`step` milliseconds until the cave is full or Esc is pressed, recording the
+frames when asked to */
+pub async fn render_solution(
+  rock_structures: Vec<PathSegment>,
+  sand_entry: Coord,
+  step: u64,
+  floor: bool,
+  recording: Option<Recording>,
+) {
+  let mut cave = Cave::new(&rock_structures, sand_entry, floor);
+  let mut recorder = recording.and_then(|recording| {
+    Recorder::create(&recording, &rock_structures, &cave)
+      .map_err(|err| eprintln!("Failed to start recording: {err}"))
+      .ok()
+  });
+  let curses = Arc::new(Curses::new());
+  curses.init();
+  curses.set_paths(rock_structures).await;
+  curses.set_bounds(cave.bounds()).await;
+  curses.set_sand_entry(sand_entry).await;
+
+  let curses_clone = Arc::clone(&curses);
+  tokio::spawn(async move {
+    tokio::time::sleep(Duration::from_millis(step)).await;
+    while let Outcome::Settled(at) = cave.drop_sand() {
+      curses_clone.settle_sand(at).await;
+      if let Some(recorder) = &mut recorder {
+        record_or_report(recorder.settled(&cave));
+      }
+      if poll(Duration::from_millis(step)).ok().unwrap() {
+        if let Ok(Event::Key(key_event)) = event::read() {
+          if key_event.code == KeyCode::Esc {
+            break;
+          }
+        }
+      }
+    }
+    if let Some(recorder) = recorder {
+      record_or_report(recorder.finish(&cave));
+    }
+    tokio::time::sleep(Duration::from_secs(1)).await;
+
+    curses_clone.stop_rendering();
+  });
+
+  curses.render();
+}
+
+fn record_or_report(result: std::io::Result<()>) {
+  if let Err(err) = result {
+    eprintln!("Failed to record a frame: {err}");
+  }
+}
+
+/* what `--cast`, `--gif`, `--frame-skip` and `--crop` after the input ask
+to be recorded, exiting on a malformed flag */
+pub fn recording_from_args() -> Option<Recording> {
+  let args: Vec<String> = env::args().skip(1).collect();
+  Recording::parse(&args).unwrap_or_else(|err| {
+    eprintln!("{err}");
+    process::exit(1);
+  })
+}
+
+/* `--render` after the input replays the solution in the terminal */
+pub fn render_requested() -> bool {
+  env::args().skip(1).any(|arg| arg == "--render")
+}
@@ -0,0 +1,82 @@
+use aoc_core::problem_solver_async::{ParseError, ProblemSolver};
+use async_trait::async_trait;
+use day_14::common::{prelude::*, read_paths};
+use day_14::fill_module::SAND_ENTRY;
+use day_14::recording::record_solution;
+use day_14::sand::Cave;
+use serde::Serialize;
+use std::process;
+
+use super::render::{recording_from_args, render_requested, render_solution};
+
+
+const STEP: u64 = 10 / 6;
+
+pub struct PSInput {
+  rock_structures: Vec<PathSegment>,
+  sand_entry: Coord,
+}
+
+#[derive(Serialize)]
+pub struct PSSolution {
+  #[serde(skip)]
+  rock_structures: Vec<PathSegment>,
+  #[serde(skip)]
+  sand_entry: Coord,
+  units_of_sand: usize,
+}
+
+/* the headless fill of `day_14`, replayed in the terminal with `--render`
+and recorded with `--cast` or `--gif` */
+pub struct ProblemSolverPattern<const FLOOR: bool>;
+
+#[async_trait]
+impl<const FLOOR: bool> ProblemSolver for ProblemSolverPattern<FLOOR> {
+  type Input = PSInput;
+  type Solution = PSSolution;
+
+  fn initialize(
+    lines: impl Iterator<Item = String>,
+  ) -> Result<Self::Input, ParseError> {
+    let rock_structures = read_paths(lines)?;
+    Ok(PSInput { rock_structures, sand_entry: SAND_ENTRY })
+  }
+
+  async fn solve_async(input: Self::Input) -> Self::Solution {
+    let units_of_sand =
+      Cave::new(&input.rock_structures, input.sand_entry, FLOOR).fill();
+
+    Self::Solution {
+      rock_structures: input.rock_structures,
+      sand_entry: input.sand_entry,
+      units_of_sand,
+    }
+  }
+
+  async fn output_async(solution: Self::Solution) {
+    let recording = recording_from_args();
+    if render_requested() {
+      render_solution(
+        solution.rock_structures,
+        solution.sand_entry,
+        STEP,
+        FLOOR,
+        recording,
+      )
+      .await;
+    } else if let Some(recording) = recording {
+      let recorded = record_solution(
+        &solution.rock_structures,
+        solution.sand_entry,
+        FLOOR,
+        recording,
+      );
+      if let Err(err) = recorded {
+        eprintln!("Failed to record the fill: {err}");
+        process::exit(1);
+      }
+    }
+
+    println!("units of sand: {}", solution.units_of_sand);
+  }
+}
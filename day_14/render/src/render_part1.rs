@@ -0,0 +1,12 @@
+use aoc_core::problem_solver_async::solve_problem;
+
+mod curses;
+mod render;
+mod render_module;
+use render_module::ProblemSolverPattern;
+
+
+#[tokio::main]
+async fn main() {
+  solve_problem::<ProblemSolverPattern<false>>().await;
+}
@@ -3,69 +3,71 @@ pub mod prelude {
   pub type PathSegment = (Coord, Coord);
 }
 
-use std::{sync::Arc, time::Duration};
+use aoc_core::problem_solver::ParseError;
+use std::cmp::{max, min};
 
-use crossterm::event::{self, poll, Event, KeyCode};
 use prelude::*;
 
-use crate::curses::Curses;
 
-pub fn read_paths(lines: impl Iterator<Item = String>) -> Vec<PathSegment> {
-  let mut rock_structures: Vec<PathSegment> = Vec::new();
-  for record in lines {
-    rock_structures.extend::<Vec<PathSegment>>(
-      record
-        .split(" -> ")
-        .collect::<Vec<_>>()
-        .windows(2)
-        .map(|w| {
-          let left = w[0].split_once(',').expect("invalid format");
-          let right = w[1].split_once(',').expect("invalid format");
+pub const AIR: char = '.';
+pub const SAND_ENTRY: char = '+';
+pub const SAND: char = 'o';
+pub const ROCK: char = '#';
 
-          (
-            (left.0.parse().unwrap(), left.1.parse().unwrap()),
-            (right.0.parse().unwrap(), right.1.parse().unwrap()),
-          )
+/* `<x>,<y>` points joined by ` -> `, each segment horizontal or vertical */
+pub fn read_paths(
+  lines: impl Iterator<Item = String>,
+) -> Result<Vec<PathSegment>, ParseError> {
+  let mut rock_structures: Vec<PathSegment> = Vec::new();
+  for (index, record) in lines.enumerate() {
+    let point = |point_str: &str| -> Result<Coord, ParseError> {
+      point_str
+        .split_once(',')
+        .and_then(|(x, y)| Some((x.parse().ok()?, y.parse().ok()?)))
+        .ok_or_else(|| {
+          ParseError::new(index, &record, "expected a '<x>,<y>' point")
+            .at(point_str)
         })
-        .collect(),
-    );
+    };
+    let mut offset = 0;
+    let points = record
+      .split(" -> ")
+      .map(|point_str| {
+        let start = offset;
+        offset += point_str.len() + " -> ".len();
+        Ok((start, point_str, point(point_str)?))
+      })
+      .collect::<Result<Vec<_>, ParseError>>()?;
+    for (&(start, _, from), &(end, end_str, to)) in
+      points.iter().zip(&points[1..])
+    {
+      if from.0 != to.0 && from.1 != to.1 {
+        let message = "expected a horizontal or vertical segment";
+        return Err(ParseError::new(index, &record, message).at_column(
+          record[..start].chars().count() + 1,
+          &record[start..end + end_str.len()],
+        ));
+      }
+      rock_structures.push((from, to));
+    }
   }
 
-  rock_structures
+  Ok(rock_structures)
 }
 
-pub async fn render_solution(
-  rock_structures: Vec<PathSegment>,
-  sand_entry: Coord,
-  units_of_sand: u32,
-  step: u64,
-  limit: bool,
-) {
-  let curses = Arc::new(Curses::new());
-  curses.init();
-  if limit {
-    curses.set_limit(true).await;
-  }
-  curses.set_paths(rock_structures).await;
-  curses.set_sand_entry(sand_entry).await;
+/* `(min x, max x, max y)` of the rock paths */
+pub fn get_render_bounds(paths: &[PathSegment]) -> (usize, usize, usize) {
+  let mut min_x = usize::MAX;
+  let mut max_x = usize::MIN;
+  let mut max_y = usize::MIN;
 
-  let curses_clone = Arc::clone(&curses);
-  tokio::spawn(async move {
-    tokio::time::sleep(Duration::from_millis(step)).await;
-    for _ in 0..units_of_sand {
-      curses_clone.release_sand(0).await;
-      if poll(Duration::from_millis(step)).ok().unwrap() {
-        if let Ok(Event::Key(key_event)) = event::read() {
-          if key_event.code == KeyCode::Esc {
-            curses_clone.stop_rendering();
-          }
-        }
-      }
+  for path in paths {
+    for (x, y) in [path.0, path.1] {
+      min_x = min(min_x, x);
+      max_x = max(max_x, x);
+      max_y = max(max_y, y);
     }
-    tokio::time::sleep(Duration::from_secs(1)).await;
-
-    curses_clone.stop_rendering();
-  });
+  }
 
-  curses.render();
+  (min_x, max_x, max_y)
 }
@@ -0,0 +1,43 @@
+use aoc_core::problem_solver::{ParseError, ProblemSolver};
+use serde::Serialize;
+
+use super::common::{prelude::*, read_paths};
+use super::sand::Cave;
+
+
+pub const SAND_ENTRY: Coord = (500, 0);
+
+pub struct PSInput {
+  rock_structures: Vec<PathSegment>,
+}
+
+#[derive(Serialize)]
+pub struct PSSolution {
+  units_of_sand: usize,
+}
+
+/* the units of sand coming to rest before one falls into the abyss, or with
+`FLOOR` before they block the entry */
+pub struct ProblemSolverPattern<const FLOOR: bool>;
+
+impl<const FLOOR: bool> ProblemSolver for ProblemSolverPattern<FLOOR> {
+  type Input = PSInput;
+  type Solution = PSSolution;
+
+  fn initialize(
+    lines: impl Iterator<Item = String>,
+  ) -> Result<Self::Input, ParseError> {
+    Ok(PSInput { rock_structures: read_paths(lines)? })
+  }
+
+  fn solve(input: Self::Input) -> Self::Solution {
+    let units_of_sand =
+      Cave::new(&input.rock_structures, SAND_ENTRY, FLOOR).fill();
+
+    PSSolution { units_of_sand }
+  }
+
+  fn output(solution: Self::Solution) {
+    println!("units of sand: {}", solution.units_of_sand);
+  }
+}
@@ -0,0 +1,4 @@
+pub mod common;
+pub mod fill_module;
+pub mod recording;
+pub mod sand;
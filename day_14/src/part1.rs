@@ -1,12 +1,7 @@
-mod part1_module;
-use part1_module::ProblemSolverPattern;
-mod problem_solver_async;
-use problem_solver_async::solve_problem;
-mod common;
-mod curses;
+use aoc_core::problem_solver::solve_problem;
+use day_14::fill_module::ProblemSolverPattern;
 
 
-#[tokio::main]
-async fn main() {
-  solve_problem::<ProblemSolverPattern>().await;
+fn main() {
+  solve_problem::<ProblemSolverPattern<false>>();
 }
@@ -1,7 +1,7 @@
+use aoc_core::problem_solver_async::solve_problem;
+
 mod problem_solver_module_pattern;
 use problem_solver_module_pattern::ProblemSolverPattern;
-mod problem_solver_async;
-use problem_solver_async::solve_problem;
 
 
 fn main() {
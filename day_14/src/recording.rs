@@ -0,0 +1,249 @@
+use gif::{Encoder, Frame, Repeat};
+use serde_json::json;
+use std::{
+  fs::File,
+  io::{self, BufWriter, Write},
+};
+
+use super::common::{
+  get_render_bounds, prelude::*, AIR, ROCK, SAND, SAND_ENTRY,
+};
+use super::sand::{Cave, Cell, Outcome};
+
+
+/* seconds between two recorded frames */
+const FRAME_TIME: f64 = 1.0 / 30.0;
+/* gif pixels per cave cell */
+const SCALE: usize = 4;
+const PALETTE: [u8; 12] = [
+  0x10, 0x10, 0x18, // air
+  0x80, 0x80, 0x88, // rock
+  0xe8, 0xc8, 0x60, // sand
+  0xe0, 0x40, 0x40, // entry
+];
+
+/* what `--cast <file>`, `--gif <file>`, `--frame-skip <n>` and `--crop`
+after the input ask to be recorded */
+pub struct Recording {
+  cast: Option<String>,
+  gif: Option<String>,
+  frame_skip: usize,
+  crop: bool,
+}
+
+impl Recording {
+  /* `None` when no output file is given, `Err` names a flag missing its
+  value or a frame skip that is not a number */
+  pub fn parse(args: &[String]) -> Result<Option<Self>, String> {
+    let value = |flag: &str| {
+      let Some(position) = args.iter().position(|arg| arg == flag) else {
+        return Ok(None);
+      };
+      match args.get(position + 1) {
+        Some(value) if !value.starts_with("--") => Ok(Some(value.clone())),
+        _ => Err(format!("{flag} expects a value")),
+      }
+    };
+    let frame_skip = match value("--frame-skip")? {
+      Some(skip) => skip.parse().map_err(|_| {
+        format!("--frame-skip expects a number of units, not `{skip}`")
+      })?,
+      None => 0,
+    };
+    let recording = Recording {
+      cast: value("--cast")?,
+      gif: value("--gif")?,
+      frame_skip,
+      crop: args.iter().any(|arg| arg == "--crop"),
+    };
+
+    let requested = recording.cast.is_some() || recording.gif.is_some();
+    Ok(requested.then_some(recording))
+  }
+}
+
+type GifEncoder = Encoder<BufWriter<File>>;
+
+/* writes a frame every `frame_skip + 1` units of sand that come to rest,
+cropped to the rocks' extent or showing the whole cave */
+pub struct Recorder {
+  bounds: (usize, usize, usize),
+  frame_skip: usize,
+  settled: usize,
+  captured: usize,
+  frames: usize,
+  cast: Option<BufWriter<File>>,
+  /* the encoder and the frame size in gif pixels */
+  gif: Option<(GifEncoder, (u16, u16))>,
+}
+
+impl Recorder {
+  pub fn create(
+    recording: &Recording,
+    paths: &[PathSegment],
+    cave: &Cave,
+  ) -> io::Result<Self> {
+    let bounds = match recording.crop {
+      true if paths.is_empty() => {
+        return Err(io::Error::new(
+          io::ErrorKind::InvalidInput,
+          "--crop needs rock paths to crop to",
+        ))
+      }
+      true => {
+        let (min_x, max_x, max_y) = get_render_bounds(paths);
+        (min_x, max_x, cave.bounds().2.min(max_y + 1))
+      }
+      false => cave.bounds(),
+    };
+    let (width, height) = (bounds.1 - bounds.0 + 1, bounds.2 + 1);
+
+    let cast = match &recording.cast {
+      Some(path) => {
+        let mut cast = BufWriter::new(File::create(path)?);
+        let header = json!({
+          "version": 2,
+          "width": width,
+          "height": height,
+        });
+        writeln!(cast, "{header}")?;
+        Some(cast)
+      }
+      None => None,
+    };
+    let gif = match &recording.gif {
+      Some(path) => {
+        let size = gif_size(width, height)?;
+        let file = BufWriter::new(File::create(path)?);
+        let mut encoder = Encoder::new(file, size.0, size.1, &PALETTE)
+          .map_err(io::Error::other)?;
+        encoder
+          .set_repeat(Repeat::Infinite)
+          .map_err(io::Error::other)?;
+        Some((encoder, size))
+      }
+      None => None,
+    };
+
+    Ok(Recorder {
+      bounds,
+      frame_skip: recording.frame_skip,
+      settled: 0,
+      captured: 0,
+      frames: 0,
+      cast,
+      gif,
+    })
+  }
+
+  /* to be called each time a unit of sand comes to rest */
+  pub fn settled(&mut self, cave: &Cave) -> io::Result<()> {
+    self.settled += 1;
+    if self.frames == 0 || self.settled - self.captured > self.frame_skip {
+      self.capture(cave)?;
+    }
+    Ok(())
+  }
+
+  /* records the final state unless it was the last frame already */
+  pub fn finish(mut self, cave: &Cave) -> io::Result<()> {
+    if self.frames == 0 || self.captured != self.settled {
+      self.capture(cave)?;
+    }
+    if let Some(cast) = &mut self.cast {
+      cast.flush()?;
+    }
+    Ok(())
+  }
+
+  fn capture(&mut self, cave: &Cave) -> io::Result<()> {
+    let (min_x, max_x, max_y) = self.bounds;
+    let rows: Vec<Vec<char>> = (0..=max_y)
+      .map(|y| {
+        (min_x..=max_x)
+          .map(|x| match cave.cell((x, y)) {
+            Cell::Rock => ROCK,
+            Cell::Sand => SAND,
+            Cell::Air if (x, y) == cave.entry() => SAND_ENTRY,
+            Cell::Air => AIR,
+          })
+          .collect()
+      })
+      .collect();
+
+    if let Some(cast) = &mut self.cast {
+      let clear = if self.frames == 0 { "\x1b[2J" } else { "" };
+      let screen: Vec<String> =
+        rows.iter().map(|row| row.iter().collect()).collect();
+      let output = format!("{clear}\x1b[H{}", screen.join("\r\n"));
+      let time = self.frames as f64 * FRAME_TIME;
+      writeln!(cast, "{}", json!([time, "o", output]))?;
+    }
+    if let Some((encoder, (width, height))) = &mut self.gif {
+      let pixels: Vec<u8> = rows
+        .iter()
+        .flat_map(|row| {
+          let line: Vec<u8> =
+            row.iter().flat_map(|&c| [color_index(c); SCALE]).collect();
+          std::iter::repeat_n(line, SCALE).flatten()
+        })
+        .collect();
+      let mut frame =
+        Frame::from_indexed_pixels(*width, *height, &pixels, None);
+      frame.delay = (FRAME_TIME * 100.0).round() as u16;
+      encoder.write_frame(&frame).map_err(io::Error::other)?;
+    }
+    self.frames += 1;
+    self.captured = self.settled;
+
+    Ok(())
+  }
+}
+
+/* records the whole fill without a terminal */
+pub fn record_solution(
+  rock_structures: &[PathSegment],
+  sand_entry: Coord,
+  floor: bool,
+  recording: Recording,
+) -> io::Result<()> {
+  let mut cave = Cave::new(rock_structures, sand_entry, floor);
+  let mut recorder = Recorder::create(&recording, rock_structures, &cave)?;
+  while let Outcome::Settled(_) = cave.drop_sand() {
+    recorder.settled(&cave)?;
+  }
+  recorder.finish(&cave)
+}
+
+/* the gif's size for `width` by `height` cells, an error when a side does
+not fit the format's 16 bits */
+fn gif_size(width: usize, height: usize) -> io::Result<(u16, u16)> {
+  let side = |cells: usize| {
+    cells
+      .checked_mul(SCALE)
+      .and_then(|pixels| u16::try_from(pixels).ok())
+  };
+  match (side(width), side(height)) {
+    (Some(width), Some(height)) => Ok((width, height)),
+    _ => Err(io::Error::new(
+      io::ErrorKind::InvalidInput,
+      format!(
+        "a {width}x{height} cave does not fit a gif at {SCALE} pixels per \
+         cell, try --crop"
+      ),
+    )),
+  }
+}
+
+fn color_index(cell: char) -> u8 {
+  match cell {
+    ROCK => 1,
+    SAND => 2,
+    SAND_ENTRY => 3,
+    _ => 0,
+  }
+}
+
+#[cfg(test)]
+#[path = "./tests/recording_tests.rs"]
+mod recording_tests;
@@ -0,0 +1,147 @@
+use aoc_core::grid::Grid;
+use std::cmp::{max, min};
+
+use super::common::prelude::*;
+
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Cell {
+  Air,
+  Rock,
+  Sand,
+}
+
+/* where a unit of sand ended up */
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Outcome {
+  Settled(Coord),
+  Abyss,
+  /* the entry itself is already filled */
+  Blocked,
+}
+
+/* the scan of the cave as a grid of cells, wide enough for sand piling up
+from the entry down to the floor when there is one. sand falls straight
+down, then down-left, then down-right, and settles when all three are
+taken */
+pub struct Cave {
+  grid: Grid<Cell>,
+  left: usize,
+  entry: Coord,
+  floor: Option<usize>,
+}
+
+impl Cave {
+  /* with `floor` an endless rock floor lies two below the lowest rock */
+  pub fn new(paths: &[PathSegment], entry: Coord, floor: bool) -> Self {
+    let (min_x, max_x, max_y) = paths
+      .iter()
+      .flat_map(|&(start, end)| [start, end])
+      .chain([entry])
+      .fold((usize::MAX, 0, 0), |(min_x, max_x, max_y), (x, y)| {
+        (min(min_x, x), max(max_x, x), max(max_y, y))
+      });
+    let floor = floor.then_some(max_y + 2);
+    /* a pile resting on the floor spreads at most its height to each side,
+    without one sand leaving the rocks' span falls into the abyss */
+    let (left, right) = match floor {
+      Some(floor) => (
+        min(min_x, entry.0.saturating_sub(floor)),
+        max(max_x, entry.0 + floor),
+      ),
+      None => (min_x, max_x),
+    };
+    let height = floor.unwrap_or(max_y + 1);
+
+    let mut cave = Cave {
+      grid: Grid::new(right - left + 1, height, Cell::Air),
+      left,
+      entry,
+      floor,
+    };
+    for &((x1, y1), (x2, y2)) in paths {
+      for x in min(x1, x2)..=max(x1, x2) {
+        for y in min(y1, y2)..=max(y1, y2) {
+          cave.grid[(x - left, y)] = Cell::Rock;
+        }
+      }
+    }
+
+    cave
+  }
+
+  pub fn entry(&self) -> Coord {
+    self.entry
+  }
+
+  /* `(min x, max x, max y)` of where sand can come to rest */
+  pub fn bounds(&self) -> (usize, usize, usize) {
+    (
+      self.left,
+      self.left + self.grid.width() - 1,
+      self.grid.height() - 1,
+    )
+  }
+
+  /* the scanned cell at `(x, y)` in cave coordinates, the floor and the
+  abyss read as rock and air */
+  pub fn cell(&self, (x, y): Coord) -> Cell {
+    if self.floor == Some(y) {
+      return Cell::Rock;
+    }
+    x.checked_sub(self.left)
+      .and_then(|x| self.grid.get((x, y)))
+      .copied()
+      .unwrap_or(Cell::Air)
+  }
+
+  /* lets one unit of sand fall from the entry until it comes to rest */
+  pub fn drop_sand(&mut self) -> Outcome {
+    self.drop_sand_observed(|_| {})
+  }
+
+  /* like `drop_sand`, reporting every position the sand falls through,
+  starting at the entry */
+  pub fn drop_sand_observed(
+    &mut self,
+    mut on_fall: impl FnMut(Coord),
+  ) -> Outcome {
+    if self.cell(self.entry) != Cell::Air {
+      return Outcome::Blocked;
+    }
+    let (mut x, mut y) = self.entry;
+    on_fall((x, y));
+    loop {
+      if y + 1 >= self.grid.height() && self.floor.is_none() {
+        return Outcome::Abyss;
+      }
+      let below = [Some(x), x.checked_sub(1), Some(x + 1)]
+        .into_iter()
+        .flatten()
+        .find(|&next_x| self.cell((next_x, y + 1)) == Cell::Air);
+      match below {
+        Some(next_x) if next_x < self.left => return Outcome::Abyss,
+        Some(next_x) => {
+          (x, y) = (next_x, y + 1);
+          on_fall((x, y));
+        }
+        None => {
+          self.grid[(x - self.left, y)] = Cell::Sand;
+          return Outcome::Settled((x, y));
+        }
+      }
+    }
+  }
+
+  /* drops sand until a unit falls into the abyss or the entry is blocked,
+  returning how many units came to rest */
+  pub fn fill(&mut self) -> usize {
+    (0..)
+      .take_while(|_| matches!(self.drop_sand(), Outcome::Settled(_)))
+      .count()
+  }
+}
+
+#[cfg(test)]
+#[path = "./tests/sand_tests.rs"]
+mod sand_tests;
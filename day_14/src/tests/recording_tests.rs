@@ -0,0 +1,193 @@
+use serde_json::Value;
+use std::{env, fs, io, path::PathBuf};
+
+use crate::common::read_paths;
+use crate::recording::*;
+use crate::sand::{Cave, Outcome};
+
+
+const SAMPLE: &str = include_str!("../../sample.txt");
+
+fn temporary(name: &str) -> PathBuf {
+  env::temp_dir().join(format!("day_14_{}_{name}", std::process::id()))
+}
+
+/* records the sample's fill to an asciicast, returning its lines */
+fn cast(name: &str, floor: bool, frame_skip: usize, crop: bool) -> Vec<Value> {
+  let path = temporary(name);
+  let recording = Recording {
+    cast: Some(path.display().to_string()),
+    gif: None,
+    frame_skip,
+    crop,
+  };
+  record(&recording, floor);
+  let contents = fs::read_to_string(&path).unwrap();
+  fs::remove_file(&path).unwrap();
+  contents
+    .lines()
+    .map(|line| serde_json::from_str(line).unwrap())
+    .collect()
+}
+
+fn record(recording: &Recording, floor: bool) {
+  let paths = read_paths(SAMPLE.lines().map(String::from)).unwrap();
+  let mut cave = Cave::new(&paths, (500, 0), floor);
+  let mut recorder = Recorder::create(recording, &paths, &cave).unwrap();
+  while let Outcome::Settled(_) = cave.drop_sand() {
+    recorder.settled(&cave).unwrap();
+  }
+  recorder.finish(&cave).unwrap();
+}
+
+fn screen(frame: &Value) -> Vec<String> {
+  let output = frame[2].as_str().unwrap();
+  let output = output
+    .trim_start_matches("\x1b[2J")
+    .trim_start_matches("\x1b[H");
+  output.split("\r\n").map(String::from).collect()
+}
+
+#[test]
+fn it_should_write_an_asciicast_header_and_a_frame_per_unit() {
+  // Act
+  let cast = cast("every.cast", false, 0, false);
+
+  // Assert
+  assert_eq!(
+    cast[0],
+    serde_json::json!({ "version": 2, "width": 10, "height": 10 })
+  );
+  assert_eq!(cast.len(), 1 + 24);
+  assert_eq!(cast[1][0], 0.0);
+  assert_eq!(cast[1][1], "o");
+  assert!(cast[1][2].as_str().unwrap().starts_with("\x1b[2J\x1b[H"));
+  assert!(cast[2][2].as_str().unwrap().starts_with("\x1b[H"));
+  assert!(cast[2][0].as_f64().unwrap() > 0.0);
+  assert_eq!(screen(&cast[1])[0], "......+...");
+  assert_eq!(screen(&cast[1])[8], "......o.#.");
+  assert_eq!(screen(&cast[24])[2], "......o...");
+}
+
+#[test]
+fn it_should_skip_frames_but_keep_the_final_state() {
+  // Act
+  let skipped = cast("skip.cast", false, 4, false);
+  let every = cast("all.cast", false, 0, false);
+
+  // Assert
+  assert_eq!(skipped.len(), 1 + 6);
+  assert_eq!(screen(&skipped[2]), screen(&every[6]));
+  assert_eq!(screen(&skipped[6]), screen(&every[24]));
+}
+
+#[test]
+fn it_should_crop_the_floor_cave_to_the_rocks() {
+  // Act
+  let whole = cast("whole.cast", true, 1000, false);
+  let cropped = cast("cropped.cast", true, 1000, true);
+
+  // Assert
+  assert_eq!(whole[0]["width"], 23);
+  assert_eq!(whole[0]["height"], 11);
+  assert_eq!(cropped[0]["width"], 10);
+  assert_eq!(cropped[0]["height"], 11);
+  assert_eq!(screen(&cropped[2])[0], "......o...");
+}
+
+#[test]
+fn it_should_write_a_gif_scaled_up_from_the_cells() {
+  // Arrange
+  let path = temporary("sample.gif");
+  let recording = Recording {
+    cast: None,
+    gif: Some(path.display().to_string()),
+    frame_skip: 0,
+    crop: false,
+  };
+
+  // Act
+  record(&recording, false);
+  let gif = fs::read(&path).unwrap();
+  fs::remove_file(&path).unwrap();
+
+  // Assert
+  assert_eq!(&gif[..6], b"GIF89a");
+  assert_eq!(u16::from_le_bytes([gif[6], gif[7]]), 40);
+  assert_eq!(u16::from_le_bytes([gif[8], gif[9]]), 40);
+}
+
+#[test]
+fn it_should_refuse_a_cave_too_large_for_a_gif() {
+  // Act & Assert
+  assert!(gif_size(16_383, 1).is_ok());
+  assert!(gif_size(16_384, 1).is_err());
+  assert!(gif_size(1, usize::MAX).is_err());
+}
+
+#[test]
+fn it_should_read_the_recording_flags() {
+  // Arrange
+  let args = |args: &[&str]| -> Vec<String> {
+    args.iter().map(|arg| arg.to_string()).collect()
+  };
+
+  // Act
+  let recording = Recording::parse(&args(&[
+    "--cast",
+    "a.cast",
+    "--frame-skip",
+    "3",
+    "--crop",
+  ]));
+  let nothing = Recording::parse(&args(&["--crop", "--frame-skip", "3"]));
+
+  // Assert
+  let recording = recording.unwrap().unwrap();
+  assert_eq!(recording.cast.as_deref(), Some("a.cast"));
+  assert_eq!(recording.frame_skip, 3);
+  assert!(recording.crop);
+  assert!(nothing.unwrap().is_none());
+}
+
+#[test]
+fn it_should_reject_malformed_recording_flags() {
+  // Arrange
+  let args = |args: &[&str]| -> Vec<String> {
+    args.iter().map(|arg| arg.to_string()).collect()
+  };
+
+  // Act
+  let skip = Recording::parse(&args(&["--gif", "a.gif", "--frame-skip", "x"]));
+  let negative =
+    Recording::parse(&args(&["--gif", "a.gif", "--frame-skip", "-1"]));
+  let missing = Recording::parse(&args(&["--crop", "--cast"]));
+
+  // Assert
+  assert_eq!(
+    skip.err().as_deref(),
+    Some("--frame-skip expects a number of units, not `x`")
+  );
+  assert!(negative.is_err());
+  assert_eq!(missing.err().as_deref(), Some("--cast expects a value"));
+}
+
+#[test]
+fn it_should_refuse_to_crop_a_cave_without_rocks() {
+  // Arrange
+  let path = temporary("empty.cast");
+  let recording = Recording {
+    cast: Some(path.display().to_string()),
+    gif: None,
+    frame_skip: 0,
+    crop: true,
+  };
+
+  // Act
+  let recorded = record_solution(&[], (500, 0), true, recording);
+
+  // Assert
+  let err = recorded.unwrap_err();
+  assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+  assert!(!path.exists());
+}
@@ -0,0 +1,97 @@
+use crate::common::{prelude::*, read_paths};
+use crate::sand::*;
+
+
+const SAMPLE: &str = include_str!("../../sample.txt");
+const ENTRY: Coord = (500, 0);
+
+fn cave(floor: bool) -> Cave {
+  let paths = read_paths(SAMPLE.lines().map(String::from)).unwrap();
+  Cave::new(&paths, ENTRY, floor)
+}
+
+#[test]
+fn it_should_fill_the_sample_until_sand_falls_into_the_abyss() {
+  // Arrange
+  let mut cave = cave(false);
+
+  // Act
+  let settled = cave.fill();
+
+  // Assert
+  assert_eq!(settled, 24);
+  assert_eq!(cave.drop_sand(), Outcome::Abyss);
+}
+
+#[test]
+fn it_should_fill_the_sample_up_to_the_entry_with_a_floor() {
+  // Arrange
+  let mut cave = cave(true);
+
+  // Act
+  let settled = cave.fill();
+
+  // Assert
+  assert_eq!(settled, 93);
+  assert_eq!(cave.cell(ENTRY), Cell::Sand);
+  assert_eq!(cave.drop_sand(), Outcome::Blocked);
+}
+
+#[test]
+fn it_should_settle_the_first_units_on_the_rocks() {
+  // Arrange
+  let mut cave = cave(false);
+
+  // Act
+  let outcomes = [cave.drop_sand(), cave.drop_sand(), cave.drop_sand()];
+
+  // Assert
+  assert_eq!(
+    outcomes,
+    [
+      Outcome::Settled((500, 8)),
+      Outcome::Settled((499, 8)),
+      Outcome::Settled((501, 8))
+    ]
+  );
+}
+
+#[test]
+fn it_should_read_the_floor_as_rock() {
+  // Arrange
+  let cave = cave(true);
+
+  // Act & Assert
+  assert_eq!(cave.bounds().2, 10);
+  assert_eq!(cave.cell((0, 11)), Cell::Rock);
+  assert_eq!(cave.cell((498, 4)), Cell::Rock);
+  assert_eq!(cave.cell((500, 10)), Cell::Air);
+}
+
+#[test]
+fn it_should_reject_a_diagonal_segment() {
+  // Arrange
+  let lines = ["498,4 -> 498,6", "0,0 -> 3,0 -> 5,2 -> 5,9"];
+
+  // Act
+  let err = read_paths(lines.iter().map(|l| l.to_string())).unwrap_err();
+
+  // Assert
+  assert_eq!(
+    err.render("input"),
+    "error: expected a horizontal or vertical segment\n \
+     --> input:2:8\n  \
+      |\n\
+     2 | 0,0 -> 3,0 -> 5,2 -> 5,9\n  \
+      |        ^^^^^^^^^^"
+  );
+}
+
+#[test]
+fn it_should_reject_a_malformed_point() {
+  // Act
+  let err = read_paths(["1,2 -> 3;4".to_string()].into_iter()).unwrap_err();
+
+  // Assert
+  assert_eq!((err.line, err.column, err.text.as_str()), (1, 8, "3;4"));
+}
@@ -1,3 +1,4 @@
+use aoc_core::interval::IntervalSet;
 use dioxus::prelude::*;
 use std::collections::HashSet;
 
@@ -5,8 +6,8 @@ use super::beacon::beacon_entity;
 use super::sensor_and_container::sensor_and_container;
 use super::AppState;
 use crate::common::{
-  derive_bounds, extend_coord_ranges, get_bounded_coordinate_indices,
-  manhattan_distance, parse_line, prelude::*, solve_to,
+  derive_bounds, get_bounded_coordinate_indices, manhattan_distance,
+  parse_line, prelude::*, solve_to,
 };
 mod rows_select;
 use rows_select::rows_select;
@@ -95,7 +96,7 @@ pub fn part_1(cx: Scope) -> Element {
 
 pub fn count_row(index: usize, bounds: Bounds, records: &[Record]) -> usize {
   let mut beacons: HashSet<usize> = HashSet::new();
-  let mut ranges: Vec<Coord> = Vec::new();
+  let mut covered = IntervalSet::new();
   records.iter().for_each(|record| {
     let (sensor, beacon) = record;
     let (bx, by) = get_bounded_coordinate_indices(&bounds, beacon).unwrap();
@@ -107,18 +108,16 @@ pub fn count_row(index: usize, bounds: Bounds, records: &[Record]) -> usize {
     let (x, y) = get_bounded_coordinate_indices(&bounds, sensor).unwrap();
     if y + path_length >= index && y - path_length <= index {
       //aco((x, y), path_length, &mut map);
-      let range = solve_to((x, y), index, path_length, bounds);
-      ranges = extend_coord_ranges(range, &mut ranges);
-      log::info!("{:?}", ranges);
+      covered.insert(solve_to((x, y), index, path_length, bounds));
+      log::info!("{}", covered);
     }
   });
 
   log::info!(
-    "ranges found: {:?} beacons found: {:?}",
-    ranges,
+    "ranges found: {} beacons found: {:?}",
+    covered,
     beacons.len()
   );
 
-  (ranges.iter().fold(0, |acc, cur| acc + cur.1 - cur.0 + 1)
-    - beacons.len() as isize) as usize
+  covered.coverage() as usize - beacons.len()
 }
@@ -40,6 +40,7 @@ pub mod prelude {
   }
 }
 
+use aoc_core::interval::Interval;
 use sscanf::sscanf;
 use std::cmp::{max, min};
 
@@ -130,7 +131,7 @@ pub fn solve_to(
   target_y: usize,
   path_length: usize,
   bounds: Bounds,
-) -> Coord {
+) -> Interval {
   let breadth = (origin.1 as isize - target_y as isize).unsigned_abs();
   let extent = path_length - breadth;
   log::info!(
@@ -138,105 +139,6 @@ pub fn solve_to(
     (origin.0 as isize - extent as isize + bounds.0 .0
       ..=origin.0 as isize + extent as isize + bounds.0 .0)
   );
-  ((origin.0 - extent) as isize, (origin.0 + extent) as isize)
-}
-
-pub fn extend_coord_ranges(range: Coord, ranges: &mut [Coord]) -> Vec<Coord> {
-  let mut left = usize::MAX;
-  let mut right = usize::MAX;
-  let mut current_merge = usize::MAX;
-  let mut exclude: Vec<usize> = Vec::new();
-
-  for (i, r) in ranges.iter().enumerate() {
-    match check_overlap(*r, range) {
-      Some(Overlap::Left) => {
-        left = i;
-        current_merge = i;
-      }
-      Some(Overlap::Right) => {
-        right = i;
-        current_merge = i;
-      }
-      Some(Overlap::Contains) => {
-        right = i;
-        break;
-      }
-      Some(Overlap::Contained) => {
-        exclude.push(i);
-      }
-      None => (),
-    }
-
-    if current_merge != usize::MAX {
-      for (j, r2) in ranges.iter().enumerate().skip(i + 1) {
-        match check_overlap(*r2, range) {
-          Some(Overlap::Left) => {
-            left = j;
-            break;
-          }
-          Some(Overlap::Right) => {
-            right = j;
-            break;
-          }
-          Some(Overlap::Contained) => {
-            exclude.push(j);
-          }
-          _ => (),
-        }
-      }
-      break;
-    }
-  }
-
-  let mut merged_ranges: Vec<Coord> = Vec::new();
-  let mut working_range = range;
-  if left != usize::MAX {
-    working_range = merge_coord_range(ranges[left], working_range);
-  }
-  if right != usize::MAX {
-    working_range = merge_coord_range(ranges[right], working_range);
-  }
-
-  merged_ranges.push(working_range);
-  merged_ranges.extend(
-    ranges
-      .iter()
-      .enumerate()
-      .filter(|(i, _)| *i != left && *i != right && !exclude.contains(i))
-      .map(|(_, r)| *r),
-  );
-
-  merged_ranges
-}
-
-fn merge_coord_range(left: Coord, right: Coord) -> Coord {
-  let x = if left.0 < right.0 { left.0 } else { right.0 };
-  let y = if left.1 > right.1 { left.1 } else { right.1 };
-
-  (x, y)
-}
-
-#[derive(Debug, PartialEq)]
-enum Overlap {
-  Left,
-  Right,
-  Contains,
-  Contained,
-}
-
-fn check_overlap(
-  origin: (isize, isize),
-  target: (isize, isize),
-) -> Option<Overlap> {
-  if origin.0 <= target.0 && origin.1 >= target.1 {
-    Some(Overlap::Contains)
-  } else if target.0 <= origin.0 && target.1 >= origin.1 {
-    Some(Overlap::Contained)
-  } else if origin.1 >= target.0 && origin.0 < target.0 {
-    Some(Overlap::Right)
-  } else if origin.0 <= target.1 && origin.1 > target.1 {
-    Some(Overlap::Left)
-  } else {
-    None
-  }
+  Interval::new((origin.0 - extent) as i64, (origin.0 + extent) as i64)
+    .expect("the row is within the sensor's reach")
 }
@@ -3,6 +3,7 @@ pub mod prelude {
 
 
   pub const MAX_STEP: usize = 30;
+  pub const START_LABEL: &str = "AA";
 
   #[derive(Clone, Copy, Debug, Eq, PartialOrd, Ord)]
   pub struct Valve {
@@ -40,31 +41,36 @@ pub mod prelude {
 
 use std::collections::{HashMap, HashSet};
 
+use aoc_core::problem_solver::ParseError;
 use petgraph::{prelude::GraphMap, Undirected};
 
 use prelude::*;
 
 
-pub fn parse_line(line: &str) -> (Valve, Vec<Tunnel>) {
+pub fn parse_line(
+  index: usize,
+  line: &str,
+) -> Result<(Valve, Vec<Tunnel>), ParseError> {
   // Valve AA has flow rate=0; tunnels lead to valves DD, II, BB
   let parts: Vec<&str> = line.split_whitespace().collect();
+  if parts.len() < 10 {
+    return Err(ParseError::new(
+      index,
+      line,
+      "expected 'Valve <label> has flow rate=<rate>; tunnels lead to valves \
+       <labels>'",
+    ));
+  }
 
-  let rate_str = parts
-    .get(4)
-    .cloned()
-    .unwrap_or_else(|| panic!("Invalid input: missing flow rate\n{line}"));
-  let rate_til_semicolon = rate_str
+  let rate_str = parts[4];
+  let flow_rate: usize = rate_str
     .split_once('=')
-    .unwrap_or_else(|| panic!("Invalid input: missing flow rate\n{line}"))
-    .1;
-  let flow_rate: usize = rate_til_semicolon[..rate_til_semicolon.len() - 1]
-    .parse()
-    .unwrap_or_else(|_| panic!("Invalid flow rate\n{line}"));
-
-  let label = parts
-    .get(1)
-    .cloned()
-    .unwrap_or_else(|| panic!("Invalid input: missing label\n{line}"));
+    .and_then(|(_, rate)| rate.trim_end_matches(';').parse().ok())
+    .ok_or_else(|| {
+      ParseError::new(index, line, "invalid flow rate").at(rate_str)
+    })?;
+
+  let label = parts[1];
   let label_static: &'static str = Box::leak(label.to_owned().into_boxed_str());
   let valve = Valve { label: label_static, coefficient: flow_rate };
 
@@ -78,7 +84,7 @@ pub fn parse_line(line: &str) -> (Valve, Vec<Tunnel>) {
     })
     .collect();
 
-  (valve, edges)
+  Ok((valve, edges))
 }
 
 pub fn find_node(
@@ -95,19 +101,19 @@ pub fn get_shortest_flow_paths(
 ) -> GraphMap<Valve, usize, Undirected> {
   let mut shortest_flow_paths: GraphMap<Valve, usize, Undirected> =
     GraphMap::new();
+  /* valves in separate networks of tunnels are `usize::MAX` apart and get
+  no edge */
+  let mut add_edge = |from: Valve, to: Valve| {
+    let distance = fw[&(from, to)];
+    if distance != usize::MAX && !shortest_flow_paths.contains_edge(from, to) {
+      shortest_flow_paths.add_edge(from, to, distance);
+    }
+  };
   nodes.iter().enumerate().for_each(|(i, from)| {
     if *from != current_node {
-      shortest_flow_paths.add_edge(
-        *from,
-        current_node,
-        fw[&(*from, current_node)],
-      );
+      add_edge(*from, current_node);
     }
-    nodes.iter().skip(i + 1).for_each(|to| {
-      if !shortest_flow_paths.contains_edge(*from, *to) {
-        shortest_flow_paths.add_edge(*from, *to, fw[&(*from, *to)]);
-      }
-    });
+    nodes.iter().skip(i + 1).for_each(|to| add_edge(*from, *to));
   });
 
   shortest_flow_paths
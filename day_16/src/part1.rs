@@ -1,8 +1,6 @@
-mod part1_solver;
-use part1_solver::ProblemSolverPattern;
-mod problem_solver;
-use problem_solver::solve_problem;
-mod common;
+use aoc_core::problem_solver::solve_problem;
+use day_16::part1_solver::ProblemSolverPattern;
+
 
 fn main() {
   solve_problem::<ProblemSolverPattern>();
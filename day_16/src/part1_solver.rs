@@ -1,7 +1,8 @@
+use aoc_core::problem_solver::{ParseError, ProblemSolver};
 use petgraph::{algo::floyd_warshall, prelude::GraphMap, Undirected};
+use serde::Serialize;
 use std::collections::HashSet;
 
-use super::problem_solver::ProblemSolver;
 use crate::common::{
   find_node, find_path, get_shortest_flow_paths, parse_line, prelude::*,
 };
@@ -9,8 +10,10 @@ use crate::common::{
 
 pub struct PSInput {
   graph: GraphMap<Valve, usize, Undirected>,
+  start: Valve,
 }
 
+#[derive(Serialize)]
 pub struct PSSolution {
   score: usize,
 }
@@ -21,10 +24,15 @@ impl ProblemSolver for ProblemSolverPattern {
   type Input = PSInput;
   type Solution = PSSolution;
 
-  fn initialize(lines: impl Iterator<Item = String>) -> Self::Input {
+  fn initialize(
+    lines: impl Iterator<Item = String>,
+  ) -> Result<Self::Input, ParseError> {
     let mut graph: GraphMap<Valve, usize, Undirected> = GraphMap::new();
     lines
-      .map(|l| parse_line(&l))
+      .enumerate()
+      .map(|(index, l)| parse_line(index, &l))
+      .collect::<Result<Vec<_>, _>>()?
+      .into_iter()
       .fold(Vec::new(), |acc, (valve, tunnels)| {
         graph.add_node(valve);
 
@@ -40,8 +48,11 @@ impl ProblemSolver for ProblemSolverPattern {
         };
         graph.add_edge(from_node, to_node, 1);
       });
+    let start = find_node(START_LABEL.to_owned(), &graph).ok_or_else(|| {
+      ParseError::whole_input(format!("no `{START_LABEL}` valve to start from"))
+    })?;
 
-    Self::Input { graph }
+    Ok(Self::Input { graph, start })
   }
 
   fn solve(input: Self::Input) -> Self::Solution {
@@ -52,8 +63,7 @@ impl ProblemSolver for ProblemSolverPattern {
       .collect();
     let fw = floyd_warshall(&input.graph, |_| 1).unwrap();
 
-    let start_node_label = "AA".to_owned();
-    let current_node = find_node(start_node_label, &input.graph).unwrap();
+    let current_node = input.start;
 
     let shortest_flow_paths: GraphMap<Valve, usize, Undirected> =
       get_shortest_flow_paths(current_node, flow_valve_network, fw);
@@ -61,7 +71,9 @@ impl ProblemSolver for ProblemSolverPattern {
     let (best_path, score) =
       find_path(1, 0, vec![current_node], &shortest_flow_paths);
 
-    dbg!(best_path.iter().map(|n| n.label).collect::<String>());
+    tracing::debug!(
+      best_path = best_path.iter().map(|n| n.label).collect::<String>()
+    );
 
     Self::Solution { score }
   }
@@ -1,4 +1,6 @@
 pub mod prelude {
+  use aoc_core::grid::Grid;
+  use aoc_core::problem_solver::ParseError;
   use indexmap::IndexSet;
   use lazy_static::lazy_static;
   use std::fmt;
@@ -8,19 +10,40 @@ pub mod prelude {
   pub const ORIGIN_OFFSET_Y: usize = 3;
   pub const ORIGIN_OFFSET_X: usize = 2;
 
+  #[derive(Clone, Copy, Debug, PartialEq, Eq)]
   pub enum AirDirection {
     Port,
     Starboard,
   }
 
   impl AirDirection {
-    pub fn from_char(pattern: char) -> AirDirection {
+    /* `column` is the 1-based position of `pattern` in the jet pattern
+    `line`, the first line of the input */
+    pub fn from_char(
+      pattern: char,
+      column: usize,
+      line: &str,
+    ) -> Result<AirDirection, ParseError> {
       match pattern {
-        '<' => AirDirection::Port,
-        '>' => AirDirection::Starboard,
-        _ => unimplemented!(),
+        '<' => Ok(AirDirection::Port),
+        '>' => Ok(AirDirection::Starboard),
+        _ => Err(
+          ParseError::new(0, line, "expected '<' or '>'")
+            .at_column(column, &pattern.to_string()),
+        ),
       }
     }
+
+    pub fn read_pattern(line: &str) -> Result<Vec<AirDirection>, ParseError> {
+      line
+        .trim_end()
+        .chars()
+        .enumerate()
+        .map(|(offset, pattern)| {
+          AirDirection::from_char(pattern, offset + 1, line)
+        })
+        .collect()
+    }
   }
   #[derive(Default)]
   pub struct CycleDetector {
@@ -76,13 +99,13 @@ pub mod prelude {
   }
 
   #[derive(Clone)]
-  pub struct Chamber(pub Vec<[bool; CHAMBER_WIDTH]>);
+  pub struct Chamber(pub Grid<bool>);
 
   impl fmt::Debug for Chamber {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
       let rows: Vec<String> = self
         .0
-        .iter()
+        .rows()
         .rev()
         .map(|row| {
           row
@@ -104,19 +127,21 @@ pub mod prelude {
       shape: &Shape,
     ) {
       // dbg!(&self, shape, current_pos);
-      let last = self.0.len() - 1;
+      let last = self.0.height() - 1;
       for (i, row) in shape.points.iter().rev().enumerate() {
         let mut new_row = [false; CHAMBER_WIDTH];
         let start = current_pos.0;
         let end = current_pos.0 + row.len();
         new_row[start..end].copy_from_slice(row);
         if current_pos.1 + i > last {
-          self.0.push(new_row);
+          self
+            .0
+            .push_row(new_row)
+            .expect("chamber rows are CHAMBER_WIDTH cells wide");
         } else {
-          new_row = merge_rows(&new_row, unsafe {
-            self.0.get_unchecked(current_pos.1 + i)
-          });
-          self.0[current_pos.1 + i] = new_row;
+          let row = self.0.row_mut(current_pos.1 + i);
+          new_row = merge_rows(&new_row, row);
+          row.copy_from_slice(&new_row);
         }
       }
     }
@@ -125,9 +150,9 @@ pub mod prelude {
       let mut result = [None; CHAMBER_WIDTH];
 
       for col in 0..CHAMBER_WIDTH {
-        for (row_index, row) in self.0.iter().rev().enumerate() {
+        for (row_index, row) in self.0.rows().rev().enumerate() {
           if row[col] {
-            result[col] = Some(self.0.len() - row_index - 1);
+            result[col] = Some(self.0.height() - row_index - 1);
             break;
           }
         }
@@ -220,10 +245,7 @@ pub mod prelude {
 
   }
 
-  fn merge_rows<'a>(
-    left: &'a [bool; CHAMBER_WIDTH],
-    right: &'a [bool; CHAMBER_WIDTH],
-  ) -> [bool; CHAMBER_WIDTH] {
+  fn merge_rows(left: &[bool], right: &[bool]) -> [bool; CHAMBER_WIDTH] {
     let mut merged_row = [false; CHAMBER_WIDTH];
 
     for i in 0..CHAMBER_WIDTH {
@@ -238,10 +260,11 @@ pub mod prelude {
   }
 }
 
+use aoc_core::grid::Grid;
 use prelude::*;
 
 pub fn simulate(
-  flow_input: &[char],
+  flow_input: &[AirDirection],
   shapes_count: u64,
   skip_cycle_padding: bool,
 ) -> (Chamber, u64) {
@@ -249,13 +272,13 @@ pub fn simulate(
   let mut air_flow = flow_input.iter().cycle();
   let s_l = SHAPES.len() as u64;
   let f_l = flow_input.len() as u64;
-  let mut chamber: Chamber = Chamber(vec![[false; CHAMBER_WIDTH]]);
+  let mut chamber = Chamber(Grid::new(CHAMBER_WIDTH, 1, false));
   let mut current_shape: Option<&Shape> = None;
   let mut current_pos: (usize, usize) = (0, 0);
   let mut i = 0;
   let mut j = 0;
   let mut height_adjust = 0;
-  let mut height = 0;
+  let height;
   loop {
     if current_shape.is_none() {
       if j == shapes_count {
@@ -273,7 +296,7 @@ pub fn simulate(
 
     // eprintln!("(x{},y{})", current_pos.0, current_pos.1);
     let direction = air_flow.next().unwrap();
-    offset_x(direction, shape, &mut current_pos, &chamber);
+    offset_x(*direction, shape, &mut current_pos, &chamber);
 
     // eprintln!("flow {} (x{},y{})", direction, current_pos.0, current_pos.1);
     if !descent_y(shape, &mut current_pos, &chamber) {
@@ -315,22 +338,27 @@ pub fn simulate(
         height_adjust += factor as u64 * cycles;
 
         j += cycles * factor_shapes;
-        eprintln!(
-          "cycle found. {} +{height_adjust} @ {cycles} cycles",
-          chamber
+        tracing::debug!(
+          height = chamber
             .get_rise()
             .iter()
-            .fold(0, |acc, o| acc.max(o.unwrap_or(0)))
+            .fold(0, |acc, o| acc.max(o.unwrap_or(0))),
+          height_adjust,
+          cycles,
+          "cycle found"
         );
       }
-      eprintln!("{} - {} {}", i, j, j % s_l);
+      tracing::trace!(jet = i, shape = j, shape_index = j % s_l);
     }
     i += 1;
   }
 
   if height_adjust > 0 && !skip_cycle_padding {
     for _ in 0..height_adjust as usize {
-      chamber.0.push([true; CHAMBER_WIDTH]);
+      chamber
+        .0
+        .push_row([true; CHAMBER_WIDTH])
+        .expect("chamber rows are CHAMBER_WIDTH cells wide");
     }
   }
 
@@ -355,13 +383,13 @@ fn get_origin_y(chamber: &Chamber) -> usize {
 /* these offset and descend methods could use a common collision detection system with rotation but it is more computationally efficient to do it this way. my simulation is linear within the loop, I'm not budging. */
 
 fn offset_x(
-  direction: &char,
+  direction: AirDirection,
   shape: &Shape,
   current_pos: &mut (usize, usize),
   chamber: &Chamber,
 ) {
-  let chamber_height = chamber.0.len() - 1;
-  match AirDirection::from_char(*direction) {
+  let chamber_height = chamber.0.height() - 1;
+  match direction {
     AirDirection::Port => {
       if current_pos.0 == 0 {
         return;
@@ -372,7 +400,7 @@ fn offset_x(
             return false;
           }
           if let Some(row_pos) = row.iter().position(|x| *x) {
-            return chamber.0[current_pos.1 + i][current_pos.0 + row_pos - 1];
+            return chamber.0[(current_pos.0 + row_pos - 1, current_pos.1 + i)];
           }
           false // if we never encountered a point in this row, it cannot collide
         })
@@ -392,7 +420,7 @@ fn offset_x(
           if let Some(pos) = row.iter().rev().position(|x| *x) /* has a positive rightmost position */ {
             let row_pos = (shape.points[0].len() - 1) - pos;
             // and the position right of it in the chamber is also positive
-            return chamber.0[current_pos.1 + i][current_pos.0 + row_pos + 1];
+            return chamber.0[(current_pos.0 + row_pos + 1, current_pos.1 + i)];
           }
           false
         })
@@ -408,7 +436,7 @@ fn descent_y(
   current_pos: &mut (usize, usize),
   chamber: &Chamber,
 ) -> bool {
-  if current_pos.1 > chamber.0.len() {
+  if current_pos.1 > chamber.0.height() {
     current_pos.1 -= 1;
     return true;
   } else if current_pos.1 == 0 {
@@ -419,10 +447,10 @@ fn descent_y(
 
   let can_descend = !limit.iter().enumerate().any(|(x, oy)| {
     if let Some(y) = oy {
-      if *y > chamber.0.len() {
+      if *y > chamber.0.height() {
         return false;
       }
-      return *y == 0 || chamber.0[y - 1][x];
+      return *y == 0 || chamber.0[(x, y - 1)];
     }
     false
   });
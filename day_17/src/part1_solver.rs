@@ -1,15 +1,18 @@
-use super::problem_solver::ProblemSolver;
-use crate::common::{prelude::*, simulate};
+use aoc_core::problem_solver::{ParseError, ProblemSolver};
+use serde::Serialize;
+
+use crate::common::{prelude::AirDirection, simulate};
 
 
 const SHAPES_COUNT: u64 = 2022;
 
 pub struct PSInput {
-  air_flow: Vec<char>,
+  air_flow: Vec<AirDirection>,
 }
 
+#[derive(Serialize)]
 pub struct PSSolution {
-  chamber: Chamber,
+  height: usize,
 }
 
 pub struct ProblemSolverPattern;
@@ -18,21 +21,25 @@ impl ProblemSolver for ProblemSolverPattern {
   type Input = PSInput;
   type Solution = PSSolution;
 
-  fn initialize(mut lines: impl Iterator<Item = String>) -> Self::Input {
-    let air_flow = lines.next().unwrap().chars().collect();
+  fn initialize(
+    mut lines: impl Iterator<Item = String>,
+  ) -> Result<Self::Input, ParseError> {
+    let line = lines
+      .next()
+      .ok_or_else(|| ParseError::whole_input("missing jet pattern line"))?;
+    let air_flow = AirDirection::read_pattern(&line)?;
 
-    Self::Input { air_flow }
+    Ok(Self::Input { air_flow })
   }
 
   fn solve(input: Self::Input) -> Self::Solution {
     let (chamber, _) = simulate(&input.air_flow, SHAPES_COUNT, false);
+    let height = chamber.0.height();
 
-    Self::Solution { chamber }
+    Self::Solution { height }
   }
 
   fn output(solution: Self::Solution) {
-    //println!("{:?}", solution.chamber);
-    let height = solution.chamber.0.len();
-    println!("height: {}", height);
+    println!("height: {}", solution.height);
   }
 }
@@ -1,13 +1,16 @@
-use super::problem_solver::ProblemSolver;
-use crate::common::simulate;
+use aoc_core::problem_solver::{ParseError, ProblemSolver};
+use serde::Serialize;
+
+use crate::common::{prelude::AirDirection, simulate};
 
 
 const SHAPES_COUNT: u64 = 1_000_000_000_000;
 
 pub struct PSInput {
-  air_flow: Vec<char>,
+  air_flow: Vec<AirDirection>,
 }
 
+#[derive(Serialize)]
 pub struct PSSolution {
   height: u64,
 }
@@ -18,10 +21,15 @@ impl ProblemSolver for ProblemSolverPattern {
   type Input = PSInput;
   type Solution = PSSolution;
 
-  fn initialize(mut lines: impl Iterator<Item = String>) -> Self::Input {
-    let air_flow = lines.next().unwrap().chars().collect();
+  fn initialize(
+    mut lines: impl Iterator<Item = String>,
+  ) -> Result<Self::Input, ParseError> {
+    let line = lines
+      .next()
+      .ok_or_else(|| ParseError::whole_input("missing jet pattern line"))?;
+    let air_flow = AirDirection::read_pattern(&line)?;
 
-    Self::Input { air_flow }
+    Ok(Self::Input { air_flow })
   }
 
   fn solve(input: Self::Input) -> Self::Solution {
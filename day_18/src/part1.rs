@@ -1,8 +1,5 @@
-mod part1_solver;
-use part1_solver::ProblemSolverPattern;
-mod problem_solver;
-use problem_solver::solve_problem;
-mod common;
+use aoc_core::problem_solver::solve_problem;
+use day_18::part1_solver::ProblemSolverPattern;
 
 
 fn main() {
@@ -1,8 +1,9 @@
+use aoc_core::problem_solver::{ParseError, ProblemSolver};
 use ndarray::{Array, ArrayBase, Dim, OwnedRepr};
+use serde::Serialize;
 use std::collections::HashMap;
 
-use super::problem_solver::ProblemSolver;
-use crate::common::{get_neighbors, prelude::*};
+use crate::common::get_neighbors;
 
 
 const MAX_COORDS: (usize, usize, usize) = (22, 22, 22);
@@ -11,8 +12,9 @@ pub struct PSInput {
   three_d: ArrayBase<OwnedRepr<bool>, Dim<[usize; 3]>>,
 }
 
+#[derive(Serialize)]
 pub struct PSSolution {
-  externalized_points: HashMap<Position, usize>,
+  total: usize,
 }
 
 pub struct ProblemSolverPattern;
@@ -21,21 +23,31 @@ impl ProblemSolver for ProblemSolverPattern {
   type Input = PSInput;
   type Solution = PSSolution;
 
-  fn initialize(lines: impl Iterator<Item = String>) -> Self::Input {
+  fn initialize(
+    lines: impl Iterator<Item = String>,
+  ) -> Result<Self::Input, ParseError> {
     let mut three_d = Array::from_elem(MAX_COORDS, false);
 
-    lines.for_each(|l| {
-      let coords: Vec<usize> =
-        l.split(',').map(|w| w.parse::<usize>().unwrap()).collect();
-
-      let x = coords[0];
-      let z = coords[1];
-      let y = coords[2];
+    for (index, l) in lines.enumerate() {
+      let coords: Vec<usize> = l
+        .split(',')
+        .map(|w| {
+          w.parse::<usize>()
+            .ok()
+            .filter(|c| *c < MAX_COORDS.0)
+            .ok_or_else(|| {
+              ParseError::new(index, &l, "expected a coordinate in range").at(w)
+            })
+        })
+        .collect::<Result<_, _>>()?;
+      let [x, z, y] = coords[..] else {
+        return Err(ParseError::new(index, &l, "expected 'x,y,z'"));
+      };
 
       three_d[[x, y, z]] = true;
-    });
+    }
 
-    Self::Input { three_d }
+    Ok(Self::Input { three_d })
   }
 
   fn solve(input: Self::Input) -> Self::Solution {
@@ -55,13 +67,12 @@ impl ProblemSolver for ProblemSolverPattern {
       }
     }
 
-    Self::Solution { externalized_points }
+    let total = externalized_points.values().sum();
+
+    Self::Solution { total }
   }
 
   fn output(solution: Self::Solution) {
-    println!(
-      "total {}",
-      solution.externalized_points.values().sum::<usize>()
-    );
+    println!("total {}", solution.total);
   }
 }
@@ -1,8 +1,5 @@
-mod part2_solver;
-use part2_solver::ProblemSolverPattern;
-mod problem_solver;
-use problem_solver::solve_problem;
-mod common;
+use aoc_core::problem_solver::solve_problem;
+use day_18::part2_solver::ProblemSolverPattern;
 
 
 fn main() {
@@ -1,7 +1,8 @@
+use aoc_core::problem_solver::{ParseError, ProblemSolver};
 use ndarray::{Array, ArrayBase, Dim, OwnedRepr};
+use serde::Serialize;
 use std::collections::{HashMap, HashSet};
 
-use super::problem_solver::ProblemSolver;
 use crate::common::{get_neighbors, prelude::*};
 
 
@@ -13,8 +14,11 @@ pub struct PSInput {
   three_d: ArrayBase<OwnedRepr<bool>, Dim<[usize; 3]>>,
 }
 
+#[derive(Serialize)]
 pub struct PSSolution {
+  #[serde(skip)]
   externalized_points: ReachableMap,
+  total: usize,
 }
 
 pub struct ProblemSolverPattern;
@@ -23,27 +27,39 @@ impl ProblemSolver for ProblemSolverPattern {
   type Input = PSInput;
   type Solution = PSSolution;
 
-  fn initialize(lines: impl Iterator<Item = String>) -> Self::Input {
+  fn initialize(
+    lines: impl Iterator<Item = String>,
+  ) -> Result<Self::Input, ParseError> {
     let mut three_d = Array::from_elem(MAX_COORDS, false);
 
-    lines.for_each(|l| {
-      let coords: Vec<usize> =
-        l.split(',').map(|w| w.parse::<usize>().unwrap()).collect();
-
-      let x = coords[0] + 1;
-      let z = coords[1] + 1;
-      let y = coords[2] + 1;
-
-      three_d[[x, y, z]] = true;
-    });
+    for (index, l) in lines.enumerate() {
+      let coords: Vec<usize> = l
+        .split(',')
+        .map(|w| {
+          w.parse::<usize>()
+            .ok()
+            .filter(|c| c + 1 < MAX_COORDS.0)
+            .ok_or_else(|| {
+              ParseError::new(index, &l, "expected a coordinate in range").at(w)
+            })
+        })
+        .collect::<Result<_, _>>()?;
+      let [x, z, y] = coords[..] else {
+        return Err(ParseError::new(index, &l, "expected 'x,y,z'"));
+      };
+
+      three_d[[x + 1, y + 1, z + 1]] = true;
+    }
 
-    Self::Input { three_d }
+    Ok(Self::Input { three_d })
   }
 
   fn solve(input: Self::Input) -> Self::Solution {
     let externalized_points = dfs(&input.three_d, (0, 0, 0));
 
-    Self::Solution { externalized_points }
+    let total = externalized_points.values().map(|v| v.len()).sum();
+
+    Self::Solution { externalized_points, total }
   }
 
   fn output(solution: Self::Solution) {
@@ -51,17 +67,10 @@ impl ProblemSolver for ProblemSolverPattern {
       .externalized_points
       .iter()
       .for_each(|((x, y, z), v)| {
-        println!("{:?}:{}", (x - 1, y - 1, z - 1), v.len())
+        tracing::debug!(point = ?(x - 1, y - 1, z - 1), faces = v.len())
       });
 
-    println!(
-      "total {}",
-      solution
-        .externalized_points
-        .values()
-        .map(|v| v.len())
-        .sum::<usize>()
-    );
+    println!("total {}", solution.total);
   }
 }
 
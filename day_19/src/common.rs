@@ -121,6 +121,7 @@ impl Default for System {
   }
 }
 
+use aoc_core::problem_solver::ParseError;
 use good_lp::{
   constraint, default_solver, solvers::scip::SCIPSolved, variable, variables,
   Solution, SolverModel, Variable,
@@ -131,7 +132,13 @@ use std::collections::{HashMap, VecDeque};
 use prelude::*;
 
 
-pub fn factory_system(line: String, time_steps: usize) -> System {
+const MATERIALS: [&str; 4] = ["ore", "clay", "obsidian", "geode"];
+
+pub fn factory_system(
+  index: usize,
+  line: String,
+  time_steps: usize,
+) -> Result<System, ParseError> {
   /* Blueprint 1: Each ore robot costs 4 ore. Each clay robot costs 2 ore. Each
   obsidian robot costs 3 ore and 14 clay. Each geode robot costs 2 ore and 7
   obsidian. */
@@ -139,63 +146,78 @@ pub fn factory_system(line: String, time_steps: usize) -> System {
     line.split("Each").map(|s| s.to_string()).collect();
 
   let Some(id_str) = blueprint.pop_front() else {
-    panic!("invalid blueprint:\n{}", line);
-  };
-  let id = match sscanf!(id_str.trim(), "Blueprint {}:", usize) {
-    Ok(n) => n,
-    Err(_) => panic!("invalid blueprint: '{}'\n{}", id_str, line),
+    return Err(ParseError::new(index, &line, "invalid blueprint"));
   };
+  let id = sscanf!(id_str.trim(), "Blueprint {}:", usize).map_err(|_| {
+    ParseError::new(index, &line, "expected 'Blueprint <id>:'")
+      .at(id_str.trim())
+  })?;
 
-  let robots: HashMap<String, Robot> = blueprint
+  let mut robots: HashMap<String, Robot> = blueprint
     .iter()
     .map(|s| {
-      let robot = factory_robot(s.to_string());
+      let robot = factory_robot(s).map_err(|(fragment, message)| {
+        ParseError::new(index, &line, message).at(&fragment)
+      })?;
 
-      (robot.output.type_str(), robot)
+      Ok((robot.output.type_str(), robot))
+    })
+    .collect::<Result<_, ParseError>>()?;
+  let mut robot = |material: &str| {
+    robots.remove(material).ok_or_else(|| {
+      ParseError::new(
+        index,
+        &line,
+        format!("blueprint has no {material} robot"),
+      )
     })
-    .collect();
+  };
 
-  System {
+  Ok(System {
     id,
     ore: 1,
     time_steps,
-    ore_robot: robots["ore"].clone(),
-    clay_robot: robots["clay"].clone(),
-    obsidian_robot: robots["obsidian"].clone(),
-    geode_robot: robots["geode"].clone(),
+    ore_robot: robot("ore")?,
+    clay_robot: robot("clay")?,
+    obsidian_robot: robot("obsidian")?,
+    geode_robot: robot("geode")?,
     ..System::default()
-  }
+  })
 }
 
-fn factory_robot(spec: String) -> Robot {
-  let parts: Vec<&str> = spec.split("costs").collect();
-  let mut robot_type = parts[0].trim().to_string();
-  robot_type = robot_type.split_whitespace().next().unwrap().to_string();
-  let robot_costs = parts[1].trim().to_string();
-  let mut robot = Robot::from_type(robot_type);
-  let costs: Vec<String> =
-    robot_costs.split("and").map(|s| s.to_string()).collect();
-  let requirements =
-    costs
-      .iter()
-      .fold(Requirement::default(), |mut acc, cost_str| {
-        let (amt, mat) = match sscanf!(
-          cost_str.trim().trim_end_matches('.'),
-          r"{} {}",
-          usize,
-          String
-        ) {
-          Ok((a, b)) => (a, b),
-          Err(_) => panic!("invalid blueprint: '{cost_str}'\n{spec}"),
-        };
-
-        acc[&mat] = Some(amt);
-
-        acc
-      });
+/* on failure, reports the offending fragment alongside the reason */
+fn factory_robot(spec: &str) -> Result<Robot, (String, &'static str)> {
+  let Some((robot_type, robot_costs)) = spec.split_once("costs") else {
+    return Err((
+      spec.trim().to_string(),
+      "expected '<material> robot costs <costs>'",
+    ));
+  };
+  let robot_type = robot_type.split_whitespace().next().unwrap_or_default();
+  if !MATERIALS.contains(&robot_type) {
+    return Err((robot_type.to_string(), "unknown robot material"));
+  }
+  let mut robot = Robot::from_type(robot_type.to_string());
+  let requirements = robot_costs.trim().split("and").try_fold(
+    Requirement::default(),
+    |mut acc, cost_str| {
+      let cost_str = cost_str.trim().trim_end_matches('.');
+      let (amt, mat) =
+        sscanf!(cost_str, r"{} {}", usize, String).map_err(|_| {
+          (cost_str.to_string(), "expected '<amount> <material>'")
+        })?;
+      if !MATERIALS.contains(&mat.as_str()) {
+        return Err((mat, "unknown material"));
+      }
+
+      acc[&mat] = Some(amt);
+
+      Ok(acc)
+    },
+  )?;
   robot.requirements = requirements;
 
-  robot
+  Ok(robot)
 }
 
 pub fn score_system(system: &System) -> (usize, usize) {
@@ -349,7 +371,7 @@ fn log_solution(
   these are _after_ values */
   for i in 1..minutes {
     let sci = if i == minutes - 1 { i } else { i + 1 };
-    println!(
+    tracing::debug!(
       "[blueprint {} @{}] ore:{}({}) clay:{}({}) obsidian:{}({}) geode:{}({})",
       system.id,
       i,
@@ -1,7 +1,7 @@
+use aoc_core::problem_solver::solve_problem;
+
 mod part2_solver;
 use part2_solver::ProblemSolverPattern;
-mod problem_solver;
-use problem_solver::solve_problem;
 mod common;
 
 
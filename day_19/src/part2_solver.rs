@@ -1,6 +1,7 @@
+use aoc_core::problem_solver::{ParseError, ProblemSolver};
+use serde::Serialize;
 use std::collections::HashMap;
 
-use super::problem_solver::ProblemSolver;
 use crate::common::{factory_system, prelude::*, score_system};
 
 
@@ -10,8 +11,10 @@ pub struct PSInput {
   systems: Vec<System>,
 }
 
+#[derive(Serialize)]
 pub struct PSSolution {
   scores: HashMap<usize, usize>,
+  geodes_product: usize,
 }
 
 pub struct ProblemSolverPattern;
@@ -20,36 +23,33 @@ impl ProblemSolver for ProblemSolverPattern {
   type Input = PSInput;
   type Solution = PSSolution;
 
-  fn initialize(lines: impl Iterator<Item = String>) -> Self::Input {
-    let mut systems: Vec<System> =
-      lines.map(|l| factory_system(l, TIME_STEPS)).collect();
+  fn initialize(
+    lines: impl Iterator<Item = String>,
+  ) -> Result<Self::Input, ParseError> {
+    let mut systems: Vec<System> = lines
+      .enumerate()
+      .map(|(index, l)| factory_system(index, l, TIME_STEPS))
+      .collect::<Result<_, _>>()?;
     systems.truncate(3);
 
-    Self::Input { systems }
+    Ok(Self::Input { systems })
   }
 
   fn solve(input: Self::Input) -> Self::Solution {
-    let scores = input.systems.iter().map(score_system).collect();
+    let scores: HashMap<usize, usize> =
+      input.systems.iter().map(score_system).collect();
+    let geodes_product = scores.values().product();
 
-    Self::Solution { scores }
+    Self::Solution { scores, geodes_product }
   }
 
   fn output(solution: Self::Solution) {
     let mut order = solution.scores.keys().collect::<Vec<_>>();
     order.sort();
-    let res = order
-      .iter()
-      .map(|key| {
-        let value = solution.scores.get(key.to_owned()).unwrap();
-        format!("{}:{}", key, value)
-      })
-      .collect::<Vec<_>>()
-      .join("\n");
-
-    println!(
-      "geodes product: {}",
-      solution.scores.values().product::<usize>(),
-    );
-    println!("{res}");
+    order.iter().for_each(|&key| {
+      tracing::debug!(blueprint = key, score = solution.scores[key])
+    });
+
+    println!("geodes product: {}", solution.geodes_product);
   }
 }
@@ -0,0 +1,127 @@
+use super::rules::Rules;
+
+
+/* every mapping of the guess symbols is scored, `n!` of them for `n` moves,
+so larger rule sets are refused */
+pub const MAX_MOVES: usize = 8;
+
+/* what the guide's rounds, read as guesses, could have scored */
+pub struct Analysis {
+  /* the highest scoring move against each opponent move */
+  pub optimal: Vec<usize>,
+  pub optimal_score: i32,
+  /* every assignment of the guess symbols to moves, `mapping[symbol]` being
+  the move played for the symbol of move `symbol` in the rules, with its
+  score. the rules' own assignment comes first */
+  pub mappings: Vec<(Vec<usize>, i32)>,
+  /* index into `mappings` of the best scoring one, the earliest on ties so
+  the rules' own assignment wins them */
+  pub best: usize,
+  /* index into `mappings` of the one the guide most likely intended, the
+  mapping playing the best response in the most rounds, the earliest on
+  ties. it differs from `best` when the guide trades a few best responses
+  for rounds that score more, like wins over draws */
+  pub intended: usize,
+  /* the rounds in which the intended mapping plays the best response */
+  pub intended_agreement: usize,
+}
+
+/* `rounds` as read with the guess interpretation, `Err` for rule sets of
+more than `MAX_MOVES` moves */
+pub fn analyze(
+  rules: &Rules,
+  rounds: &[(usize, usize)],
+) -> Result<Analysis, String> {
+  if rules.moves.len() > MAX_MOVES {
+    return Err(format!(
+      "the analysis tries every mapping of the guess symbols, so it takes at \
+       most {MAX_MOVES} moves, not {}",
+      rules.moves.len()
+    ));
+  }
+  let optimal: Vec<usize> = rounds
+    .iter()
+    .map(|&(opponent, _)| best_response(rules, opponent))
+    .collect();
+  let optimal_score = rounds
+    .iter()
+    .zip(&optimal)
+    .map(|(&(opponent, _), &subject)| rules.score(opponent, subject))
+    .sum();
+  let mappings: Vec<(Vec<usize>, i32)> = permutations(rules.moves.len())
+    .into_iter()
+    .map(|mapping| {
+      let score = rounds
+        .iter()
+        .map(|&(opponent, symbol)| rules.score(opponent, mapping[symbol]))
+        .sum();
+      (mapping, score)
+    })
+    .collect();
+  let best = (0..mappings.len())
+    .rev()
+    .max_by_key(|&index| mappings[index].1)
+    .unwrap_or_default();
+  let agreement = |mapping: &[usize]| {
+    rounds
+      .iter()
+      .zip(&optimal)
+      .filter(|&(&(_, symbol), &subject)| mapping[symbol] == subject)
+      .count()
+  };
+  let (intended, intended_agreement) = mappings
+    .iter()
+    .map(|(mapping, _)| agreement(mapping))
+    .enumerate()
+    .rev()
+    .max_by_key(|&(_, agreement)| agreement)
+    .unwrap_or_default();
+
+  Ok(Analysis {
+    optimal,
+    optimal_score,
+    mappings,
+    best,
+    intended,
+    intended_agreement,
+  })
+}
+
+/* the highest scoring move against `opponent`, the first listed on ties */
+pub fn best_response(rules: &Rules, opponent: usize) -> usize {
+  (0..rules.moves.len())
+    .rev()
+    .max_by_key(|&subject| rules.score(opponent, subject))
+    .unwrap_or_default()
+}
+
+/* `symbol=move` for every guess symbol */
+pub fn describe_mapping(rules: &Rules, mapping: &[usize]) -> String {
+  mapping
+    .iter()
+    .enumerate()
+    .map(|(symbol, &played)| {
+      format!("{}={}", rules.moves[symbol].guess, rules.moves[played].name)
+    })
+    .collect::<Vec<_>>()
+    .join(" ")
+}
+
+/* the orderings of `0..n` in lexicographic order, the identity first */
+fn permutations(n: usize) -> Vec<Vec<usize>> {
+  if n == 0 {
+    return vec![vec![]];
+  }
+  (0..n)
+    .flat_map(|first| {
+      permutations(n - 1).into_iter().map(move |rest| {
+        let rest = rest.into_iter().map(|i| if i >= first { i + 1 } else { i });
+        [first].into_iter().chain(rest).collect()
+      })
+    })
+    .collect()
+}
+
+#[cfg(test)]
+#[path = "./tests/analysis_tests.rs"]
+mod analysis_tests;
@@ -0,0 +1,94 @@
+use aoc_core::{problem_solver::InputSource, trace};
+use std::{env, path::Path, process};
+
+use super::analysis::{analyze, describe_mapping};
+use super::guide::{read_rounds, score_rounds, Interpretation};
+use super::rules::Rules;
+
+
+/* `<input> [--rules <file>] [--as guess|strategy]` scores the guide under the
+given rules, the classic game by default, reading its second column as
+`interpretation` unless `--as` says otherwise. `--analyze` instead reports the
+best responses to the opponent's column, the score of every way of mapping
+the guess symbols to moves, the rules' own mapping first, and the mapping
+the guide most likely intended */
+pub fn run(interpretation: Interpretation) {
+  trace::init(trace::level_from_args());
+  let args: Vec<String> = env::args().skip(1).collect();
+  let Some((source, rest)) = InputSource::from_args(&args) else {
+    exit_with(
+      "usage: <input file | - | --input-text <text>> [--rules <file>] \
+       [--as guess|strategy | --analyze]"
+        .to_owned(),
+    );
+  };
+  let value = |flag: &str| {
+    let position = rest.iter().position(|arg| arg == flag)?;
+    match rest.get(position + 1) {
+      Some(value) => Some(value),
+      None => exit_with(format!("{flag} expects a value")),
+    }
+  };
+  let analysis = rest.iter().any(|arg| arg == "--analyze");
+  let interpretation = match value("--as") {
+    Some(_) if analysis => {
+      exit_with("--analyze reads the guide as guesses".to_owned())
+    }
+    Some(name) => name.parse().unwrap_or_else(|err| exit_with(err)),
+    None if analysis => Interpretation::Guess,
+    None => interpretation,
+  };
+  let rules = match value("--rules") {
+    Some(path) => {
+      Rules::read(Path::new(path)).unwrap_or_else(|err| exit_with(err))
+    }
+    None => Rules::classic(),
+  };
+
+  let origin = source.origin().to_owned();
+  let lines = source
+    .lines()
+    .unwrap_or_else(|err| exit_with(format!("Failed to open input: {err}")));
+  let rounds = read_rounds(lines, &rules, interpretation)
+    .unwrap_or_else(|err| exit_with(err.render(&origin)));
+  if analysis {
+    report_analysis(&rules, &rounds);
+    return;
+  }
+  println!("{}", score_rounds(&rules, &rounds).iter().sum::<i32>());
+}
+
+fn report_analysis(rules: &Rules, rounds: &[(usize, usize)]) {
+  let analysis = analyze(rules, rounds).unwrap_or_else(|err| exit_with(err));
+  let responses: Vec<&str> = analysis
+    .optimal
+    .iter()
+    .map(|&subject| rules.moves[subject].guess.as_str())
+    .collect();
+  println!("optimal responses: {}", responses.join(" "));
+  println!("optimal score: {}", analysis.optimal_score);
+  for (mapping, score) in &analysis.mappings {
+    println!("{}: {score}", describe_mapping(rules, mapping));
+  }
+  let (best, _) = &analysis.mappings[analysis.best];
+  let declared = match analysis.best {
+    0 => " (as the rules read the guide)",
+    _ => "",
+  };
+  println!(
+    "best scoring mapping: {}{declared}",
+    describe_mapping(rules, best)
+  );
+  let (intended, _) = &analysis.mappings[analysis.intended];
+  println!(
+    "intended mapping: {} (the best response in {} of {} rounds)",
+    describe_mapping(rules, intended),
+    analysis.intended_agreement,
+    rounds.len()
+  );
+}
+
+fn exit_with(message: String) -> ! {
+  eprintln!("{message}");
+  process::exit(1);
+}
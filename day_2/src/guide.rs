@@ -0,0 +1,73 @@
+use aoc_core::problem_solver::ParseError;
+use std::str::FromStr;
+
+use super::rules::Rules;
+
+
+/* how the second column of the strategy guide is read */
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Interpretation {
+  /* the move to play */
+  Guess,
+  /* the outcome to bring about */
+  Strategy,
+}
+
+impl FromStr for Interpretation {
+  type Err = String;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    match s {
+      "guess" => Ok(Interpretation::Guess),
+      "strategy" => Ok(Interpretation::Strategy),
+      _ => Err(format!("expected guess or strategy, not {s}")),
+    }
+  }
+}
+
+/* one `<opponent> <symbol>` round per line as the opponent's and the
+subject's move, blank lines are skipped */
+pub fn read_rounds(
+  lines: impl Iterator<Item = String>,
+  rules: &Rules,
+  interpretation: Interpretation,
+) -> Result<Vec<(usize, usize)>, ParseError> {
+  lines
+    .enumerate()
+    .filter(|(_, line)| !line.trim().is_empty())
+    .map(|(index, line)| {
+      let error = |message: &str| ParseError::new(index, &line, message);
+      let [opponent, symbol] = line.split_whitespace().collect::<Vec<_>>()[..]
+      else {
+        return Err(error("expected `<opponent move> <symbol>`"));
+      };
+      let opponent_move = rules
+        .opponent_move(opponent)
+        .ok_or_else(|| error("unknown opponent move").at(opponent))?;
+      let subject_move = match interpretation {
+        Interpretation::Guess => rules
+          .guess_move(symbol)
+          .ok_or_else(|| error("unknown move").at(symbol)),
+        Interpretation::Strategy => {
+          let outcome = rules
+            .strategy_outcome(symbol)
+            .ok_or_else(|| error("unknown outcome").at(symbol))?;
+          rules.response(opponent_move, outcome).ok_or_else(|| {
+            error(&format!("no move results in a {}", outcome.name()))
+              .at(symbol)
+          })
+        }
+      }?;
+      tracing::trace!(opponent_move, subject_move);
+
+      Ok((opponent_move, subject_move))
+    })
+    .collect()
+}
+
+pub fn score_rounds(rules: &Rules, rounds: &[(usize, usize)]) -> Vec<i32> {
+  rounds
+    .iter()
+    .map(|&(opponent, subject)| rules.score(opponent, subject))
+    .collect()
+}
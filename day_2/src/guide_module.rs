@@ -0,0 +1,52 @@
+use aoc_core::problem_solver::{ParseError, ProblemSolver};
+use serde::Serialize;
+
+use super::guide::{read_rounds, score_rounds, Interpretation};
+use super::rules::Rules;
+
+
+pub struct PSInput {
+  rules: Rules,
+  rounds: Vec<(usize, usize)>,
+}
+
+#[derive(Serialize)]
+pub struct PSSolution {
+  score: i32,
+}
+
+/* the guide's score under the classic rules, its second column read as
+the move to play, or with `STRATEGY` as the outcome to bring about */
+pub struct ProblemSolverPattern<const STRATEGY: bool>;
+
+impl<const STRATEGY: bool> ProblemSolver for ProblemSolverPattern<STRATEGY> {
+  type Input = PSInput;
+  type Solution = PSSolution;
+
+  fn initialize(
+    lines: impl Iterator<Item = String>,
+  ) -> Result<Self::Input, ParseError> {
+    let rules = Rules::classic();
+    let interpretation = match STRATEGY {
+      true => Interpretation::Strategy,
+      false => Interpretation::Guess,
+    };
+    let rounds = read_rounds(lines, &rules, interpretation)?;
+
+    Ok(PSInput { rules, rounds })
+  }
+
+  fn solve(input: Self::Input) -> Self::Solution {
+    let score = score_rounds(&input.rules, &input.rounds).iter().sum();
+
+    PSSolution { score }
+  }
+
+  fn output(solution: Self::Solution) {
+    println!("score: {}", solution.score);
+  }
+}
+
+#[cfg(test)]
+#[path = "./tests/guide_module_tests.rs"]
+mod guide_module_tests;
@@ -0,0 +1,6 @@
+pub mod analysis;
+pub mod cli;
+pub mod guide;
+pub mod guide_module;
+pub mod rps_constants;
+pub mod rules;
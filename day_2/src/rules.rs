@@ -0,0 +1,201 @@
+use aoc_core::problem_solver::ParseError;
+use std::{fs, path::Path};
+
+use super::rps_constants::{Outcomes, CLASSIC_RULES};
+
+
+/* one move of the game and the symbols the guide uses for it */
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Move {
+  pub name: String,
+  pub score: i32,
+  pub opponent: String,
+  pub guess: String,
+}
+
+/* one outcome's score and the symbol the strategy reading uses for it */
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct Outcome {
+  score: i32,
+  symbol: String,
+}
+
+/* a game of cyclic moves where every two different moves have a winner.
+moves are referred to by their index in the order the rules list them */
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Rules {
+  pub moves: Vec<Move>,
+  /* `beats[a][b]` when move `a` wins against move `b` */
+  beats: Vec<Vec<bool>>,
+  outcomes: [Outcome; 3],
+}
+
+impl Rules {
+  pub fn classic() -> Self {
+    Rules::parse(CLASSIC_RULES.lines().map(String::from))
+      .expect("the bundled rules are valid")
+  }
+
+  /* errors are rendered for the terminal */
+  pub fn read(path: &Path) -> Result<Self, String> {
+    let origin = path.display().to_string();
+    let contents = fs::read_to_string(path)
+      .map_err(|err| format!("Failed to open rules {origin}: {err}"))?;
+    Rules::parse(contents.lines().map(String::from))
+      .map_err(|err| err.render(&origin))
+  }
+
+  /* `move`, `beats` and `outcome` lines as in `rules/rps.txt`, blank lines
+  and `#` comments are skipped */
+  pub fn parse(
+    lines: impl Iterator<Item = String>,
+  ) -> Result<Self, ParseError> {
+    let mut moves: Vec<Move> = vec![];
+    let mut beaten: Vec<(usize, String, String, Vec<String>)> = vec![];
+    let mut outcomes: [Option<Outcome>; 3] = [None, None, None];
+    for (index, line) in lines.enumerate() {
+      let tokens: Vec<&str> = line.split_whitespace().collect();
+      let error = |message: String, at: &str| {
+        ParseError::new(index, &line, message).at(at)
+      };
+      let score = |score: &str| {
+        score
+          .parse::<i32>()
+          .map_err(|_| error("expected an integer score".to_owned(), score))
+      };
+      match tokens[..] {
+        [] => {}
+        [comment, ..] if comment.starts_with('#') => {}
+        ["move", name, move_score, opponent, guess] => {
+          let taken = moves.iter().find_map(|other| {
+            [
+              (name, &other.name),
+              (opponent, &other.opponent),
+              (guess, &other.guess),
+            ]
+            .into_iter()
+            .find(|(token, used)| token == used)
+          });
+          if let Some((token, _)) = taken {
+            return Err(error(format!("'{token}' is already used"), token));
+          }
+          moves.push(Move {
+            name: name.to_owned(),
+            score: score(move_score)?,
+            opponent: opponent.to_owned(),
+            guess: guess.to_owned(),
+          });
+        }
+        ["beats", winner, ref losers @ ..] if !losers.is_empty() => {
+          let losers = losers.iter().map(|&loser| loser.to_owned()).collect();
+          beaten.push((index, line.clone(), winner.to_owned(), losers));
+        }
+        ["outcome", name, outcome_score, symbol] => {
+          let Some(outcome) =
+            Outcomes::ALL.into_iter().find(|o| o.name() == name)
+          else {
+            return Err(error("expected loss, draw or win".to_owned(), name));
+          };
+          let used = outcomes.iter().flatten().any(|o| o.symbol == symbol);
+          if outcomes[outcome as usize].is_some() || used {
+            return Err(error("outcome is already given".to_owned(), name));
+          }
+          outcomes[outcome as usize] = Some(Outcome {
+            score: score(outcome_score)?,
+            symbol: symbol.to_owned(),
+          });
+        }
+        _ => {
+          return Err(ParseError::new(
+            index,
+            &line,
+            "expected `move <name> <score> <opponent> <guess>`, \
+             `beats <move> <move>...` or `outcome <name> <score> <symbol>`",
+          ))
+        }
+      }
+    }
+
+    let mut beats = vec![vec![false; moves.len()]; moves.len()];
+    for (index, line, winner, losers) in beaten {
+      let position = |name: &str| moves.iter().position(|m| m.name == name);
+      let unknown =
+        |name: &str| ParseError::new(index, &line, "unknown move").at(name);
+      let winner_index = position(&winner).ok_or_else(|| unknown(&winner))?;
+      for loser in &losers {
+        let loser_index = position(loser).ok_or_else(|| unknown(loser))?;
+        if loser_index == winner_index || beats[loser_index][winner_index] {
+          return Err(
+            ParseError::new(index, &line, "moves cannot beat each other")
+              .at(loser),
+          );
+        }
+        beats[winner_index][loser_index] = true;
+      }
+    }
+    if moves.is_empty() {
+      return Err(ParseError::whole_input("the rules have no moves"));
+    }
+    for (a, first) in moves.iter().enumerate() {
+      for (b, second) in moves.iter().enumerate().skip(a + 1) {
+        if !beats[a][b] && !beats[b][a] {
+          return Err(ParseError::whole_input(format!(
+            "neither {} nor {} beats the other",
+            first.name, second.name
+          )));
+        }
+      }
+    }
+    let [Some(loss), Some(draw), Some(win)] = outcomes else {
+      return Err(ParseError::whole_input(
+        "the rules need a loss, a draw and a win outcome",
+      ));
+    };
+
+    Ok(Rules { moves, beats, outcomes: [loss, draw, win] })
+  }
+
+  pub fn opponent_move(&self, symbol: &str) -> Option<usize> {
+    self.moves.iter().position(|m| m.opponent == symbol)
+  }
+
+  pub fn guess_move(&self, symbol: &str) -> Option<usize> {
+    self.moves.iter().position(|m| m.guess == symbol)
+  }
+
+  pub fn strategy_outcome(&self, symbol: &str) -> Option<Outcomes> {
+    Outcomes::ALL
+      .into_iter()
+      .find(|&outcome| self.outcomes[outcome as usize].symbol == symbol)
+  }
+
+  /* the outcome for the subject playing `subject` against `opponent` */
+  pub fn outcome(&self, opponent: usize, subject: usize) -> Outcomes {
+    if subject == opponent {
+      Outcomes::Draw
+    } else if self.beats[subject][opponent] {
+      Outcomes::Win
+    } else {
+      Outcomes::Loss
+    }
+  }
+
+  /* the subject's score for a round, the move's plus the outcome's */
+  pub fn score(&self, opponent: usize, subject: usize) -> i32 {
+    let outcome = self.outcome(opponent, subject);
+    self.moves[subject].score + self.outcomes[outcome as usize].score
+  }
+
+  /* the move bringing about `outcome` against `opponent`, the highest
+  scoring one when several do. `None` when no move does */
+  pub fn response(&self, opponent: usize, outcome: Outcomes) -> Option<usize> {
+    (0..self.moves.len())
+      .filter(|&subject| self.outcome(opponent, subject) == outcome)
+      .rev()
+      .max_by_key(|&subject| self.moves[subject].score)
+  }
+}
+
+#[cfg(test)]
+#[path = "./tests/rules_tests.rs"]
+mod rules_tests;
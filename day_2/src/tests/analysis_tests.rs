@@ -0,0 +1,184 @@
+use crate::{
+  analysis::*,
+  guide::{read_rounds, Interpretation},
+  rules::Rules,
+};
+
+
+const RPS: &str = include_str!("../../rules/rps.txt");
+const RPSLS: &str = include_str!("../../rules/rpsls.txt");
+const SAMPLE: &str = "A Y\nB X\nC Z";
+
+fn rules(source: &str) -> Rules {
+  Rules::parse(source.lines().map(String::from)).unwrap()
+}
+
+fn guesses(rules: &Rules, guide: &str) -> Vec<(usize, usize)> {
+  read_rounds(
+    guide.lines().map(String::from),
+    rules,
+    Interpretation::Guess,
+  )
+  .unwrap()
+}
+
+#[test]
+fn it_should_answer_each_opponent_move_with_the_best_response() {
+  // Arrange
+  let rules = rules(RPS);
+  let rounds = guesses(&rules, SAMPLE);
+
+  // Act
+  let analysis = analyze(&rules, &rounds).unwrap();
+
+  // Assert
+  assert_eq!(analysis.optimal, [1, 2, 0]);
+  assert_eq!(analysis.optimal_score, 8 + 9 + 7);
+}
+
+#[test]
+fn it_should_score_every_mapping_with_the_rules_own_first() {
+  // Arrange
+  let rules = rules(RPS);
+  let rounds = guesses(&rules, SAMPLE);
+
+  // Act
+  let analysis = analyze(&rules, &rounds).unwrap();
+
+  // Assert
+  assert_eq!(
+    analysis.mappings,
+    [
+      (vec![0, 1, 2], 15),
+      (vec![0, 2, 1], 6),
+      (vec![1, 0, 2], 15),
+      (vec![1, 2, 0], 15),
+      (vec![2, 0, 1], 15),
+      (vec![2, 1, 0], 24),
+    ]
+  );
+  assert_eq!(analysis.best, 5);
+  assert_eq!(
+    describe_mapping(&rules, &analysis.mappings[analysis.best].0),
+    "X=Scissors Y=Paper Z=Rock"
+  );
+  assert_eq!((analysis.intended, analysis.intended_agreement), (5, 3));
+}
+
+#[test]
+fn it_should_intend_the_mapping_playing_the_most_best_responses() {
+  // Arrange
+  let rules = rules(RPS);
+  let rounds = guesses(&rules, "A X\nA Y\nC Z");
+
+  // Act
+  let analysis = analyze(&rules, &rounds).unwrap();
+
+  // Assert
+  assert_eq!(analysis.mappings[0], (vec![0, 1, 2], 18));
+  assert_eq!(analysis.mappings[3], (vec![1, 2, 0], 18));
+  assert_eq!(analysis.best, 0);
+  assert_eq!((analysis.intended, analysis.intended_agreement), (3, 2));
+}
+
+#[test]
+fn it_should_refuse_rules_with_too_many_moves_to_map() {
+  // Arrange
+  let moves = MAX_MOVES + 1;
+  let source: String = (0..moves)
+    .map(|a| format!("move M{a} 1 A{a} X{a}\n"))
+    .chain((0..moves - 1).map(|a| {
+      let beaten: String = (a + 1..moves).map(|b| format!(" M{b}")).collect();
+      format!("beats M{a}{beaten}\n")
+    }))
+    .chain(["outcome loss 0 X\noutcome draw 3 Y\noutcome win 6 Z".to_owned()])
+    .collect();
+  let rules = rules(&source);
+  let rounds = guesses(&rules, "A0 X1");
+
+  // Act
+  let result = analyze(&rules, &rounds);
+
+  // Assert
+  assert_eq!(
+    result.err(),
+    Some(
+      "the analysis tries every mapping of the guess symbols, so it takes at \
+       most 8 moves, not 9"
+        .to_owned()
+    )
+  );
+}
+
+#[test]
+fn it_should_prefer_the_rules_own_mapping_on_ties() {
+  // Arrange
+  let rules = rules(RPS);
+  let rounds = guesses(&rules, "A Y");
+
+  // Act
+  let analysis = analyze(&rules, &rounds).unwrap();
+
+  // Assert
+  assert_eq!(analysis.mappings[0].1, 8);
+  assert_eq!(analysis.mappings[5], (vec![2, 1, 0], 8));
+  assert_eq!(analysis.best, 0);
+}
+
+#[test]
+fn it_should_find_the_best_responses_among_five_moves() {
+  // Arrange
+  let rules = rules(RPSLS);
+  let [rock, paper, scissors, lizard, spock] = [0, 1, 2, 3, 4];
+
+  // Act & Assert
+  assert_eq!(best_response(&rules, rock), spock);
+  assert_eq!(best_response(&rules, paper), lizard);
+  assert_eq!(best_response(&rules, scissors), spock);
+  assert_eq!(best_response(&rules, lizard), scissors);
+  assert_eq!(best_response(&rules, spock), lizard);
+}
+
+#[test]
+fn it_should_enumerate_every_mapping_of_five_moves() {
+  // Arrange
+  let rules = rules(RPSLS);
+  let rounds = guesses(&rules, "A V\nE Z");
+
+  // Act
+  let mappings: Vec<Vec<usize>> = analyze(&rules, &rounds)
+    .unwrap()
+    .mappings
+    .into_iter()
+    .map(|(mapping, _)| mapping)
+    .collect();
+
+  // Assert
+  assert_eq!(mappings.len(), 120);
+  assert_eq!(mappings[0], [0, 1, 2, 3, 4]);
+  assert_eq!(mappings[119], [4, 3, 2, 1, 0]);
+  assert!(mappings.windows(2).all(|pair| pair[0] < pair[1]));
+  assert!(mappings.iter().all(|mapping| {
+    let mut sorted = mapping.clone();
+    sorted.sort();
+    sorted == [0, 1, 2, 3, 4]
+  }));
+}
+
+#[test]
+fn it_should_enumerate_permutations_in_lexicographic_order() {
+  // Act & Assert
+  assert_eq!(permutations(0), [Vec::<usize>::new()]);
+  assert_eq!(permutations(1), [[0]]);
+  assert_eq!(
+    permutations(3),
+    [
+      [0, 1, 2],
+      [0, 2, 1],
+      [1, 0, 2],
+      [1, 2, 0],
+      [2, 0, 1],
+      [2, 1, 0]
+    ]
+  );
+}
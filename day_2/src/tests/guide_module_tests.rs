@@ -0,0 +1,22 @@
+use aoc_core::problem_solver::ProblemSolver;
+
+use crate::guide_module::*;
+
+
+fn score<const STRATEGY: bool>(input: &str) -> i32 {
+  let lines = input.lines().map(String::from);
+  let input = ProblemSolverPattern::<STRATEGY>::initialize(lines).unwrap();
+  ProblemSolverPattern::<STRATEGY>::solve(input).score
+}
+
+#[test]
+fn it_should_read_the_second_column_as_the_move_to_play() {
+  // Act & Assert
+  assert_eq!(score::<false>(include_str!("../../sample.txt")), 15);
+}
+
+#[test]
+fn it_should_read_the_second_column_as_the_outcome_with_strategy() {
+  // Act & Assert
+  assert_eq!(score::<true>(include_str!("../../sample.txt")), 12);
+}
@@ -0,0 +1,73 @@
+use crate::rules::*;
+
+
+const MOVES: &str = "move Rock 1 A X\nmove Paper 2 B Y\nmove Scissors 3 C Z";
+const OUTCOMES: &str = "outcome loss 0 X\noutcome draw 3 Y\noutcome win 6 Z";
+
+fn render(source: &str) -> String {
+  Rules::parse(source.lines().map(String::from))
+    .unwrap_err()
+    .render("rules")
+}
+
+#[test]
+fn it_should_reject_a_symbol_used_by_two_moves() {
+  // Act
+  let rendered = render("move Rock 1 A X\nmove Paper 2 A Y");
+
+  // Assert
+  assert_eq!(
+    rendered,
+    "error: 'A' is already used\n \
+     --> rules:2:14\n  \
+      |\n\
+     2 | move Paper 2 A Y\n  \
+      |              ^"
+  );
+}
+
+#[test]
+fn it_should_reject_moves_without_a_winner_between_them() {
+  // Act
+  let rendered = render(&format!(
+    "{MOVES}\nbeats Rock Scissors\nbeats Paper Rock\n{OUTCOMES}"
+  ));
+
+  // Assert
+  assert_eq!(
+    rendered,
+    "error: neither Paper nor Scissors beats the other\n --> rules"
+  );
+}
+
+#[test]
+fn it_should_reject_an_unknown_move_in_a_beats_line() {
+  // Act
+  let rendered = render(&format!("{MOVES}\nbeats Rock Lizard\n{OUTCOMES}"));
+
+  // Assert
+  assert_eq!(
+    rendered,
+    "error: unknown move\n \
+     --> rules:4:12\n  \
+      |\n\
+     4 | beats Rock Lizard\n  \
+      |            ^^^^^^"
+  );
+}
+
+#[test]
+fn it_should_reject_an_unknown_outcome_in_an_outcome_line() {
+  // Act
+  let rendered = render(&format!("{MOVES}\noutcome tie 3 Y"));
+
+  // Assert
+  assert_eq!(
+    rendered,
+    "error: expected loss, draw or win\n \
+     --> rules:4:9\n  \
+      |\n\
+     4 | outcome tie 3 Y\n  \
+      |         ^^^"
+  );
+}
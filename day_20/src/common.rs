@@ -1,11 +1,38 @@
+use aoc_core::problem_solver::ParseError;
+
+
 const INDICES: [i64; 3] = [1000, 2000, 3000];
 
+/* one integer per line, exactly one of which is the 0 the coordinates are
+counted from */
+pub fn read_codex(
+  lines: impl Iterator<Item = String>,
+) -> Result<Vec<i64>, ParseError> {
+  let codex: Vec<i64> = lines
+    .enumerate()
+    .map(|(index, s)| {
+      str::parse::<i64>(s.as_str()).map_err(|_| {
+        ParseError::new(index, &s, "expected an integer").at(s.trim())
+      })
+    })
+    .collect::<Result<_, _>>()?;
+  let zeros = codex.iter().filter(|&&x| x == 0).count();
+  if zeros != 1 {
+    return Err(ParseError::whole_input(format!(
+      "expected exactly one 0 to count the coordinates from, found {zeros}"
+    )));
+  }
+
+  Ok(codex)
+}
+
+/* expects a codex from `read_codex` */
 pub fn get_coordinates(codex: Vec<i64>) -> [i64; 3] {
   let len = codex.len();
   let zero = codex
     .iter()
     .position(|x| *x == 0)
-    .unwrap_or_else(|| panic!("no zero in input!"));
+    .expect("read_codex checks for a zero");
 
   INDICES
     .iter()
@@ -0,0 +1,3 @@
+pub mod common;
+pub mod part1_solver;
+pub mod part2_solver;
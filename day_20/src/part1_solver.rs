@@ -1,13 +1,17 @@
-use super::problem_solver::ProblemSolver;
-use crate::common::get_coordinates;
+use aoc_core::problem_solver::{ParseError, ProblemSolver};
+use serde::Serialize;
+
+use crate::common::{get_coordinates, read_codex};
 
 
 pub struct PSInput {
   codex: Vec<i64>,
 }
 
+#[derive(Serialize)]
 pub struct PSSolution {
   coordinates: [i64; 3],
+  sum: i64,
 }
 
 pub struct ProblemSolverPattern;
@@ -16,15 +20,12 @@ impl ProblemSolver for ProblemSolverPattern {
   type Input = PSInput;
   type Solution = PSSolution;
 
-  fn initialize(lines: impl Iterator<Item = String>) -> Self::Input {
-    let codex = lines
-      .map(|s| {
-        str::parse::<i64>(s.as_str())
-          .unwrap_or_else(|_| panic!("invalid input format"))
-      })
-      .collect();
+  fn initialize(
+    lines: impl Iterator<Item = String>,
+  ) -> Result<Self::Input, ParseError> {
+    let codex = read_codex(lines)?;
 
-    Self::Input { codex }
+    Ok(Self::Input { codex })
   }
 
   fn solve(input: Self::Input) -> Self::Solution {
@@ -34,19 +35,17 @@ impl ProblemSolver for ProblemSolverPattern {
 
     let coordinates = get_coordinates(codex);
 
-    Self::Solution { coordinates }
+    let sum = coordinates.iter().sum();
+
+    Self::Solution { coordinates, sum }
   }
 
   fn output(solution: Self::Solution) {
-    println!(
-      "sum {} {:?}",
-      solution.coordinates.iter().sum::<i64>(),
-      solution.coordinates
-    );
+    println!("sum {} {:?}", solution.sum, solution.coordinates);
   }
 }
 
-fn move_items(numbers: &mut Vec<i64>) -> Vec<i64> {
+fn move_items(numbers: &mut [i64]) -> Vec<i64> {
   let len = numbers.len();
   let mut index = 0;
   let mut work: Vec<(usize, i64)> =
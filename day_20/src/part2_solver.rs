@@ -1,5 +1,7 @@
-use super::problem_solver::ProblemSolver;
-use crate::common::get_coordinates;
+use aoc_core::problem_solver::{ParseError, ProblemSolver};
+use serde::Serialize;
+
+use crate::common::{get_coordinates, read_codex};
 
 
 const DECRYPTION_KEY: i64 = 811589153;
@@ -9,8 +11,10 @@ pub struct PSInput {
   codex: Vec<i64>,
 }
 
+#[derive(Serialize)]
 pub struct PSSolution {
   coordinates: [i64; 3],
+  sum: i64,
 }
 
 pub struct ProblemSolverPattern;
@@ -19,19 +23,25 @@ impl ProblemSolver for ProblemSolverPattern {
   type Input = PSInput;
   type Solution = PSSolution;
 
-  fn initialize(lines: impl Iterator<Item = String>) -> Self::Input {
-    let codex = lines
-      .map(|s| {
-        str::parse::<i64>(s.as_str())
-          .unwrap_or_else(|_| panic!("invalid input format"))
-      })
-      .collect();
+  fn initialize(
+    lines: impl Iterator<Item = String>,
+  ) -> Result<Self::Input, ParseError> {
+    let codex = read_codex(lines)?;
+    if let Some(x) = codex
+      .iter()
+      .find(|x| x.checked_mul(DECRYPTION_KEY).is_none())
+    {
+      return Err(ParseError::whole_input(format!(
+        "{x} is too large to apply the decryption key to"
+      )));
+    }
 
-    Self::Input { codex }
+    Ok(Self::Input { codex })
   }
 
   fn solve(input: Self::Input) -> Self::Solution {
-    let mut codex = input.codex.iter().map(|x| x * DECRYPTION_KEY).collect();
+    let mut codex: Vec<i64> =
+      input.codex.iter().map(|x| x * DECRYPTION_KEY).collect();
     let mut indices: Vec<usize> = (0..input.codex.len()).collect();
     for _ in 0..MIXES {
       (codex, indices) = move_items(&mut codex, &indices);
@@ -40,20 +50,18 @@ impl ProblemSolver for ProblemSolverPattern {
 
     let coordinates = get_coordinates(codex);
 
-    Self::Solution { coordinates }
+    let sum = coordinates.iter().sum();
+
+    Self::Solution { coordinates, sum }
   }
 
   fn output(solution: Self::Solution) {
-    println!(
-      "sum {} {:?}",
-      solution.coordinates.iter().sum::<i64>(),
-      solution.coordinates
-    );
+    println!("sum {} {:?}", solution.sum, solution.coordinates);
   }
 }
 
 fn move_items(
-  numbers: &mut Vec<i64>,
+  numbers: &mut [i64],
   indices: &[usize],
 ) -> (Vec<i64>, Vec<usize>) {
   let len = numbers.len();
@@ -44,34 +44,48 @@ pub mod prelude {
   }
 
   impl FnType {
-    pub fn from_components(components: &[&str]) -> FnType {
+    /* on failure, reports the offending component alongside the reason */
+    pub fn from_components<'a>(
+      components: &[&'a str],
+    ) -> Result<FnType, (&'a str, &'static str)> {
+      const EXPECTED_JOB: &str =
+        "expected an integer or '<monkey> <operation> <monkey>'";
       let len = components.len();
       if len > 3 || len == 2 {
-        panic!("invalid function type {:?}", components)
+        return Err((components[1], EXPECTED_JOB));
+      }
+      if len == 0 {
+        return Err(("", EXPECTED_JOB));
       }
       if len == 1 {
-        return FnType::Value(components[0].parse().unwrap_or_else(|_| {
-          panic!("invalid function type '{}'", components[0])
-        }));
+        return components[0]
+          .parse()
+          .map(FnType::Value)
+          .map_err(|_| (components[0], EXPECTED_JOB));
       }
 
-      let mut t = FnType::get_ops_type_from_str(components[1]);
-      let left = MonkeyBusiness::id_from_str(components[0]);
-      let right = MonkeyBusiness::id_from_str(components[2]);
+      let mut t = FnType::try_ops_type_from_str(components[1])
+        .ok_or((components[1], "expected one of '+', '-', '*', '/' or '='"))?;
+      let participant = |name: &'a str| {
+        MonkeyBusiness::try_id_from_str(name)
+          .ok_or((name, "invalid monkey name, expected lowercase letters"))
+      };
+      let left = participant(components[0])?;
+      let right = participant(components[2])?;
       let participants = Participants { left, right };
       t.set_participants(participants);
 
-      t
+      Ok(t)
     }
 
-    pub fn get_ops_type_from_str(value: &str) -> FnType {
+    pub fn try_ops_type_from_str(value: &str) -> Option<FnType> {
       match value {
-        "+" => FnType::Add(None),
-        "/" => FnType::Div(None),
-        "-" => FnType::Sub(None),
-        "*" => FnType::Mul(None),
-        "=" => FnType::Eq(None),
-        _ => panic!("invalid function operation type '{value}'"),
+        "+" => Some(FnType::Add(None)),
+        "/" => Some(FnType::Div(None)),
+        "-" => Some(FnType::Sub(None)),
+        "*" => Some(FnType::Mul(None)),
+        "=" => Some(FnType::Eq(None)),
+        _ => None,
       }
     }
 
@@ -158,10 +172,17 @@ pub mod prelude {
 
   impl MonkeyBusiness {
     pub fn id_from_str(name_str: &str) -> i64 {
-      str_to_base26_number(name_str)
+      MonkeyBusiness::try_id_from_str(name_str)
         .unwrap_or_else(|| panic!("invalid monkey name {name_str}"))
     }
 
+    pub fn try_id_from_str(name_str: &str) -> Option<i64> {
+      if name_str.is_empty() {
+        return None;
+      }
+      str_to_base26_number(name_str)
+    }
+
     pub fn string_from_id(id: i64) -> String {
       base26_number_to_str(id)
         .unwrap_or_else(|| panic!("invalid monkey ID {}", id))
@@ -173,7 +194,7 @@ pub mod prelude {
     let mut result: i64 = 0;
 
     for c in s.chars() {
-      let digit = (c as u8).to_ascii_lowercase() - b'a';
+      let digit = (c as u8).to_ascii_lowercase().wrapping_sub(b'a');
       if !(0..26).contains(&digit) {
         return None;
       }
@@ -208,77 +229,89 @@ pub mod prelude {
   }
 }
 
+use aoc_core::problem_solver::ParseError;
 use petgraph::prelude::*;
 use std::collections::HashMap;
 
 use prelude::*;
 
 
+/* every monkey and its job, one of them named `root` */
 pub fn parse_nodes(
   lines: impl Iterator<Item = String>,
-) -> GraphMap<MonkeyBusiness, usize, Directed> {
-  lines
-    .map(|l| {
-      let (name_str, fn_str) = l.split_once(':').unwrap();
-      let name = MonkeyBusiness::id_from_str(name_str);
+) -> Result<GraphMap<MonkeyBusiness, usize, Directed>, ParseError> {
+  let graph = lines.enumerate().try_fold(
+    GraphMap::<MonkeyBusiness, usize, Directed>::new(),
+    |mut acc, (index, l)| {
+      let invalid = |message: &str| ParseError::new(index, &l, message);
+      let (name_str, fn_str) = l
+        .split_once(':')
+        .ok_or_else(|| invalid("expected '<monkey>: <job>'"))?;
+      let name =
+        MonkeyBusiness::try_id_from_str(name_str).ok_or_else(|| {
+          invalid("invalid monkey name, expected lowercase letters")
+            .at(name_str)
+        })?;
       let fn_components: Vec<&str> = fn_str.split_whitespace().collect();
-      let fn_type = FnType::from_components(&fn_components);
-
-      MonkeyBusiness { id: name, fn_type }
-    })
-    .fold(
-      GraphMap::<MonkeyBusiness, usize, Directed>::new(),
-      |mut acc, cur| {
-        acc.add_node(cur);
-
-        acc
-      },
-    )
+      let fn_type = FnType::from_components(&fn_components)
+        .map_err(|(component, message)| invalid(message).at(component))?;
+      acc.add_node(MonkeyBusiness { id: name, fn_type });
+
+      Ok(acc)
+    },
+  )?;
+  let root_id = MonkeyBusiness::id_from_str(ROOT_NAME);
+  if !graph.nodes().any(|n| n.id == root_id) {
+    return Err(ParseError::whole_input("no `root` monkey"));
+  }
+
+  Ok(graph)
 }
 
+/* expects lines already validated by `parse_nodes` */
 pub fn apply_edges(
   lines: impl Iterator<Item = String>,
   graph: &mut GraphMap<MonkeyBusiness, usize, Directed>,
-) {
+) -> Result<(), ParseError> {
   let nodes: HashMap<i64, MonkeyBusiness> =
     graph.nodes().map(|mb| (mb.id, mb)).collect();
 
-  lines
-    .filter_map(|l| {
-      let (name_str, fn_str) = l.split_once(':').unwrap();
+  for (index, l) in lines.enumerate() {
+    let Some((name_str, fn_str)) = l.split_once(':') else {
+      continue;
+    };
+    let fn_components: Vec<&str> = fn_str.split_whitespace().collect();
+    if let Ok(FnType::Value(_)) | Err(_) =
+      FnType::from_components(&fn_components)
+    {
+      continue;
+    }
 
-      let fn_components: Vec<String> =
-        fn_str.split_whitespace().map(|s| s.to_owned()).collect();
-      let fn_components_slice: Vec<&str> =
-        fn_components.iter().map(|s| s.as_str()).collect();
-      let fn_type = FnType::from_components(&fn_components_slice);
+    let node = |name: &str| {
+      nodes
+        .get(&MonkeyBusiness::id_from_str(name))
+        .copied()
+        .ok_or_else(|| ParseError::new(index, &l, "undefined monkey").at(name))
+    };
+    let c = node(name_str)?;
+    let a = node(fn_components[0])?;
+    let b = node(fn_components[2])?;
+    graph.add_edge(a, c, 0);
+    graph.add_edge(b, c, 0);
+  }
 
-      match fn_type {
-        FnType::Value(_) => None,
-        _ => {
-          let left = fn_components.first().unwrap().to_owned();
-          let right = fn_components.last().unwrap().to_owned();
-          Some((name_str.to_owned(), (left, right)))
-        }
-      }
-    })
-    .for_each(|(to, (left, right))| {
-      let c = nodes[&MonkeyBusiness::id_from_str(&to)];
-      let a = nodes[&MonkeyBusiness::id_from_str(&left)];
-      let b = nodes[&MonkeyBusiness::id_from_str(&right)];
-      graph.add_edge(a, c, 0);
-      graph.add_edge(b, c, 0);
-    });
+  Ok(())
 }
 
+/* expects a graph from `parse_nodes`, which holds the root */
 pub fn reduce_nodes(
   graph: GraphMap<MonkeyBusiness, usize, Directed>,
   limit: NodeReductionLimit,
 ) -> Vec<MonkeyBusiness> {
   let nodes: HashMap<i64, MonkeyBusiness> =
     graph.nodes().map(|mb| (mb.id, mb)).collect();
-  let root = *nodes.get(&MonkeyBusiness::id_from_str(ROOT_NAME)).unwrap();
-  println!("root {:?}", root);
+  let root = nodes[&MonkeyBusiness::id_from_str(ROOT_NAME)];
+  tracing::debug!(?root);
 
   let mut g: Graph<MonkeyBusiness, usize, Directed, _> =
     graph.clone().into_graph();
@@ -326,13 +359,13 @@ fn search_nodes_to_limit(
       if inputs.len() != 2 {
         return;
       }
-      println!("inputs {:?}", inputs);
+      tracing::trace!(?inputs);
 
       let value = FnType::apply(mb.fn_type, inputs[0], inputs[1]);
       mb.fn_type = FnType::Value(value);
       if let Some(n) = nodes.get_mut(&mb.id) {
         n.fn_type = mb.fn_type;
-        println!("changed Ops Node {:?}", nodes[&mb.id]);
+        tracing::trace!(node = ?nodes[&mb.id], "reduced");
       }
       values.insert(*mb, value);
       nodes_to_remove.push(inputs[0]);
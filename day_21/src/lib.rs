@@ -0,0 +1,4 @@
+pub mod common;
+pub mod part1_solver;
+pub mod part2_solver;
+pub mod simple_parser;
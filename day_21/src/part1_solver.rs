@@ -1,6 +1,7 @@
+use aoc_core::problem_solver::{ParseError, ProblemSolver};
 use petgraph::prelude::*;
+use serde::Serialize;
 
-use super::problem_solver::ProblemSolver;
 use crate::common::{apply_edges, parse_nodes, prelude::*, reduce_nodes};
 
 
@@ -8,6 +9,7 @@ pub struct PSInput {
   graph: GraphMap<MonkeyBusiness, usize, Directed>,
 }
 
+#[derive(Serialize)]
 pub struct PSSolution {
   value: Option<i64>,
 }
@@ -18,12 +20,14 @@ impl ProblemSolver for ProblemSolverPattern {
   type Input = PSInput;
   type Solution = PSSolution;
 
-  fn initialize(lines: impl Iterator<Item = String>) -> Self::Input {
+  fn initialize(
+    lines: impl Iterator<Item = String>,
+  ) -> Result<Self::Input, ParseError> {
     let records: Vec<String> = lines.collect();
-    let mut graph = parse_nodes(records.iter().map(|n| n.to_owned()));
-    apply_edges(records.iter().map(|n| n.to_owned()), &mut graph);
+    let mut graph = parse_nodes(records.iter().map(|n| n.to_owned()))?;
+    apply_edges(records.iter().map(|n| n.to_owned()), &mut graph)?;
 
-    Self::Input { graph }
+    Ok(Self::Input { graph })
   }
 
   fn solve(input: Self::Input) -> Self::Solution {
@@ -1,9 +1,6 @@
-mod part2_solver;
-use part2_solver::ProblemSolverPattern;
-mod problem_solver;
-use problem_solver::solve_problem;
-pub mod common;
-mod simple_parser;
+use aoc_core::problem_solver::solve_problem;
+use day_21::part2_solver::ProblemSolverPattern;
+
 
 fn main() {
   solve_problem::<ProblemSolverPattern>();
@@ -1,7 +1,8 @@
+use aoc_core::problem_solver::{ParseError, ProblemSolver};
 use petgraph::prelude::*;
+use serde::Serialize;
 use std::collections::HashMap;
 
-use super::problem_solver::ProblemSolver;
 use crate::common::{apply_edges, parse_nodes, prelude::*};
 use crate::simple_parser::resolve_equation;
 
@@ -12,6 +13,7 @@ pub struct PSInput {
   graph: GraphMap<MonkeyBusiness, usize, Directed>,
 }
 
+#[derive(Serialize)]
 pub struct PSSolution {
   value: Option<i64>,
 }
@@ -22,23 +24,27 @@ impl ProblemSolver for ProblemSolverPattern {
   type Input = PSInput;
   type Solution = PSSolution;
 
-  fn initialize(lines: impl Iterator<Item = String>) -> Self::Input {
+  fn initialize(
+    lines: impl Iterator<Item = String>,
+  ) -> Result<Self::Input, ParseError> {
     let records: Vec<String> = lines.collect();
-    let mut graph = parse_nodes(records.iter().map(|n| n.to_owned()));
+    let mut graph = parse_nodes(records.iter().map(|n| n.to_owned()))?;
 
     let root_id = MonkeyBusiness::id_from_str(ROOT_NAME);
     let mut root = graph
       .nodes()
       .find(|n| n.id == root_id)
-      .unwrap_or_else(|| panic!("invalid input, no root node"));
+      .ok_or_else(|| ParseError::whole_input("no `root` monkey"))?;
+    let participants = root.fn_type.get_participants().ok_or_else(|| {
+      ParseError::whole_input("the `root` monkey must compare two monkeys")
+    })?;
     graph.remove_node(root);
-    let participants = root.fn_type.get_participants();
-    root.fn_type = FnType::Eq(participants);
+    root.fn_type = FnType::Eq(Some(participants));
     graph.add_node(root);
 
-    apply_edges(records.iter().map(|n| n.to_owned()), &mut graph);
+    apply_edges(records.iter().map(|n| n.to_owned()), &mut graph)?;
 
-    Self::Input { graph }
+    Ok(Self::Input { graph })
   }
 
   fn solve(input: Self::Input) -> Self::Solution {
@@ -47,7 +53,7 @@ impl ProblemSolver for ProblemSolverPattern {
 
     let equation =
       convert_graph_to_equation(&root, &input.graph, &mut HashMap::new());
-    dbg!(&equation);
+    tracing::debug!(%equation);
 
     let value = resolve_equation(equation.as_str());
 
@@ -2,7 +2,7 @@ use evalexpr::*;
 use regex::Regex;
 
 pub fn resolve_equation(input: &str) -> Option<i64> {
-  let (left, right) = input.split_once('=').unwrap();
+  let (left, right) = input.split_once('=')?;
 
   let mut expression = eval_int(right).ok()?.to_string();
 
@@ -25,13 +25,13 @@ pub fn resolve_equation(input: &str) -> Option<i64> {
     if source.trim() == "a" {
       break;
     }
-    eprintln!("evaluating {source}");
+    tracing::trace!(%source, "evaluating");
 
     if let Some(capture_group) = right_reduce.captures(source.as_str()) {
       let lhs = capture_group.get(1).map_or("", |m| m.as_str());
       let op = capture_group.get(2).map_or("", |m| m.as_str());
       let rhs = capture_group.get(3).map_or("", |m| m.as_str());
-      dbg!(lhs, op, rhs);
+      tracing::trace!(lhs, op, rhs);
 
       expression = match reverse_op(op) {
         "+" | "-" => {
@@ -48,7 +48,7 @@ pub fn resolve_equation(input: &str) -> Option<i64> {
       let lhs = capture_group.get(1).map_or("", |m| m.as_str());
       let op = capture_group.get(2).map_or("", |m| m.as_str());
       let rhs = capture_group.get(3).map_or("", |m| m.as_str());
-      dbg!(lhs, op, rhs);
+      tracing::trace!(lhs, op, rhs);
 
       match op {
         "+" => {
@@ -70,15 +70,14 @@ pub fn resolve_equation(input: &str) -> Option<i64> {
         _ => unimplemented!(),
       }
     } else {
-      let Some(capture_group) = trim_parens.captures(source.as_str()) else {
-      unreachable!();
-    };
+      /* `humn` is not among the monkeys the root listens to */
+      let capture_group = trim_parens.captures(source.as_str())?;
       let inner = capture_group.get(1).map_or("", |m| m.as_str());
       stack.push(inner.to_string());
     }
   }
 
-  dbg!(&expression);
+  tracing::debug!(%expression, "resolved");
 
 
   eval_int(expression.as_str()).ok()
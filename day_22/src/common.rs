@@ -1,4 +1,6 @@
 pub mod prelude {
+  use aoc_core::grid::Grid;
+  use aoc_core::problem_solver::ParseError;
   use chumsky::prelude::*;
   use nalgebra::DVector;
   use std::{
@@ -123,6 +125,7 @@ pub mod prelude {
   pub struct Turtle {
     pub location: Coord,
     pub heading: Heading,
+    #[allow(dead_code)]
     pub cube_face_index: usize,
     pub previous_way_points: Vec<(Coord, usize, Heading)>,
   }
@@ -144,25 +147,11 @@ pub mod prelude {
     working over a field (as in the case of vector spaces), we work over a ring.
     */
     pub fn get_module(&self, board: &Board) -> DVector<Legend> {
-      DVector::from(match self.heading {
-        Left | Right => board.0[self.location.y].clone(),
-        Up | Down => board
-          .0
-          .iter()
-          .map(|row| row[self.location.x].clone())
-          .collect(),
-      })
+      DVector::from(board.lane(&self.heading, &self.location))
     }
 
     pub(crate) fn get_offsets(&self, board: &Board) -> Range<usize> {
-      let vector = match self.heading {
-        Left | Right => board.0[self.location.y].clone(),
-        Up | Down => board
-          .0
-          .iter()
-          .map(|row| row[self.location.x].clone())
-          .collect(),
-      };
+      let vector = board.lane(&self.heading, &self.location);
 
       Range {
         start: vector
@@ -180,60 +169,50 @@ pub mod prelude {
   }
 
   #[derive(Clone)]
-  pub struct Board(Vec<Vec<Legend>>);
+  pub struct Board(Grid<Legend>);
 
   impl Board {
-    pub fn from(source: impl Iterator<Item = String>) -> Board {
-      let lines: Vec<String> = source.collect();
-      let max_len = lines.iter().fold(0, |acc, cur| acc.max(cur.len()));
-
-      Board(
-        lines
-          .into_iter()
-          .map(|mut line| {
-            if line.len() < max_len {
-              let spaces = max_len - line.len();
-              line.extend(vec![' '; spaces]);
-            }
-
-            line.chars().map(Legend::from).collect()
-          })
-          .collect::<Vec<Vec<_>>>(),
-      )
+    /* rows shorter than the widest one are padded with space */
+    pub fn from(
+      source: impl Iterator<Item = String>,
+    ) -> Result<Board, ParseError> {
+      let grid =
+        Grid::parse_padded(source, "expected ' ', '.' or '#'", Space, |c| {
+          matches!(c, ' ' | '.' | '#').then(|| Legend::from(c))
+        })?;
+
+      Ok(Board(grid))
     }
 
     pub fn get_first_open_position(&self) -> Option<Coord> {
-      for (y, row) in self.0.iter().enumerate() {
-        for (x, tile) in row.iter().enumerate() {
-          if matches!(tile, Open) {
-            return Some(Coord::from((x, y)));
-          }
-        }
-      }
-
-      None
+      self
+        .0
+        .position(|tile| matches!(tile, Open))
+        .map(Coord::from)
     }
 
-    pub fn get_mut_ref(&mut self) -> &mut Vec<Vec<Legend>> {
+    pub fn get_mut_ref(&mut self) -> &mut Grid<Legend> {
       &mut self.0
     }
+
+    /* the row or column a turtle with `heading` moves along */
+    fn lane(&self, heading: &Heading, location: &Coord) -> Vec<Legend> {
+      match heading {
+        Left | Right => self.0.row(location.y).to_vec(),
+        Up | Down => self
+          .0
+          .column(location.x)
+          .into_iter()
+          .flatten()
+          .cloned()
+          .collect(),
+      }
+    }
   }
 
   impl Display for Board {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-      let representation: Vec<String> = self
-        .0
-        .iter()
-        .map(|row| {
-          row
-            .iter()
-            .map(|tile| tile.to_string())
-            .collect::<Vec<_>>()
-            .join("")
-        })
-        .collect();
-
-      writeln!(f, "{}", representation.join("\n"))
+      writeln!(f, "{}", self.0)
     }
   }
 
@@ -284,25 +263,40 @@ pub mod prelude {
   }
 }
 
+use aoc_core::problem_solver::ParseError;
 use chumsky::Parser;
 use prelude::*;
 
 
 pub fn extract_board_and_turns_from_stream(
   source: impl Iterator<Item = String>,
-) -> Result<(Board, Tape), String> {
+) -> Result<(Board, Tape), ParseError> {
   let mut board_src: Vec<String> = source.collect();
   let Some(instructions_src) = board_src.pop() else {
-    return Err("no valid board in source".to_string());
+    return Err(ParseError::whole_input("no valid board in source"));
   };
   board_src.pop();
+  let instructions_index = board_src.len() + 1;
 
-  let board = Board::from(board_src.into_iter());
+  let board = Board::from(board_src.into_iter())?;
 
   let parser = tokenizer();
-  let Ok(instructions) = parser.parse(instructions_src) else {
-    return Err("no valid instructions in source".to_string());
-  };
+  let instructions =
+    parser.parse(instructions_src.as_str()).map_err(|errs| {
+      let err = ParseError::new(
+        instructions_index,
+        &instructions_src,
+        "no valid instructions in source",
+      );
+      match errs.first() {
+        Some(e) => {
+          let column = e.span().start + 1;
+          let text = instructions_src.get(e.span()).unwrap_or_default();
+          err.at_column(column, text)
+        }
+        None => err,
+      }
+    })?;
 
   Ok((board, instructions))
 }
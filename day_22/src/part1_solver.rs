@@ -1,4 +1,6 @@
-use super::problem_solver::ProblemSolver;
+use aoc_core::problem_solver::{ParseError, ProblemSolver};
+use serde::Serialize;
+
 use crate::common::{
   extract_board_and_turns_from_stream, get_password, prelude::*,
 };
@@ -9,8 +11,11 @@ pub struct PSInput {
   tape: Tape,
 }
 
+#[derive(Serialize)]
 pub struct PSSolution {
+  #[serde(skip)]
   read_head: Option<Turtle>,
+  password: Option<usize>,
 }
 
 pub struct ProblemSolverPattern;
@@ -18,12 +23,10 @@ pub struct ProblemSolverPattern;
 impl ProblemSolver for ProblemSolverPattern {
   type Input = PSInput;
   type Solution = PSSolution;
-  type Err = String;
-
 
   fn initialize(
     lines: impl Iterator<Item = String>,
-  ) -> Result<Self::Input, Self::Err> {
+  ) -> Result<Self::Input, ParseError> {
     let (board, tape) = extract_board_and_turns_from_stream(lines)?;
 
     Ok(Self::Input { board, tape })
@@ -32,24 +35,32 @@ impl ProblemSolver for ProblemSolverPattern {
   fn solve(input: Self::Input) -> Self::Solution {
     let mut read_head = Turtle::new();
     let Some(location) = input.board.get_first_open_position() else {
-      return Self::Solution { read_head: None }
+      return Self::Solution { read_head: None, password: None };
     };
     read_head.location = location;
 
     input.tape.iter().for_each(|instruction| {
       read_head.apply(instruction.to_owned(), &input.board)
     });
-    represent_solution(&mut input.board.clone(), &read_head);
+    if tracing::enabled!(tracing::Level::DEBUG) {
+      represent_solution(&mut input.board.clone(), &read_head);
+    }
 
-    Self::Solution { read_head: Some(read_head) }
+    let password = get_password(&read_head.location, &read_head.heading);
+
+    Self::Solution {
+      read_head: Some(read_head),
+      password: Some(password),
+    }
   }
 
   fn output(solution: Self::Solution) {
-    let Some(read_head) = solution.read_head else {
+    let (Some(read_head), Some(password)) =
+      (solution.read_head, solution.password)
+    else {
       println!("no solution found!");
       return;
     };
-    let password = get_password(&read_head.location, &read_head.heading);
 
     println!(
       "final coord {} heading {} : password {}",
@@ -64,11 +75,12 @@ fn represent_solution(representation: &mut Board, read_head: &Turtle) {
     .previous_way_points
     .iter()
     .for_each(|(pt, _, heading)| {
-      canvas[pt.y][pt.x] = Legend::RepresentationOnlyTurtle(heading.to_owned())
+      canvas[(pt.x, pt.y)] =
+        Legend::RepresentationOnlyTurtle(heading.to_owned())
     });
-  canvas[read_head.location.y][read_head.location.x] =
+  canvas[(read_head.location.x, read_head.location.y)] =
     Legend::RepresentationOnlyTurtle(read_head.heading.clone());
-  println!("{}", representation);
+  tracing::debug!("walked board\n{representation}");
 }
 
 trait Apply {
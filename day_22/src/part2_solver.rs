@@ -1,13 +1,13 @@
+use aoc_core::problem_solver::{ParseError, ProblemSolver};
 use regex::Regex;
+use serde::Serialize;
 use std::{
-  borrow::Cow,
   collections::HashMap,
   fmt::{self, Display},
   ops::{Index, IndexMut},
 };
 
 
-const DATA: &str = include_str!("../input.txt");
 const SIZE: usize = 50;
 
 #[derive(Clone, Copy, Default, Debug)]
@@ -163,12 +163,106 @@ struct Position {
   rotation: Heading,
 }
 
-fn main() -> Result<(), String> {
-  let mut grid: Vec<&str> =
-    DATA.split('\n').take_while(|l| !l.is_empty()).collect();
+pub struct PSInput {
+  grid: Vec<String>,
+  faces: HashMap<Coord, Face>,
+  tape: String,
+}
+
+#[derive(Serialize)]
+pub struct PSSolution {
+  #[serde(skip)]
+  grid: Vec<String>,
+  password: usize,
+}
+
+pub struct ProblemSolverPattern;
+
+impl ProblemSolver for ProblemSolverPattern {
+  type Input = PSInput;
+  type Solution = PSSolution;
+
+  fn initialize(
+    mut lines: impl Iterator<Item = String>,
+  ) -> Result<Self::Input, ParseError> {
+    let grid: Vec<String> =
+      lines.by_ref().take_while(|l| !l.is_empty()).collect();
+    let tape = lines
+      .next()
+      .ok_or_else(|| ParseError::whole_input("invalid tape of instructions"))?;
+    let faces = read_faces(&grid).map_err(ParseError::whole_input)?;
+
+    Ok(Self::Input { grid, faces, tape })
+  }
+
+  fn solve(input: Self::Input) -> Self::Solution {
+    let PSInput { mut grid, mut faces, tape } = input;
+    fold_faces(&mut faces);
+
+    let Some(&start) = faces.keys().min_by_key(|coord| (coord.y, coord.x))
+    else {
+      unreachable!()
+    };
+    let mut turtle = Position {
+      face: start,
+      coord: Coord::from((0, 0)),
+      rotation: Heading::Right,
+    };
+
+    let re = Regex::new(r"(?P<instruction>\d+|[RL])").unwrap();
+    let mut moves = Vec::new();
+    for cap in re.captures_iter(&tape) {
+      moves.push(turtle.clone());
+      if let Some(instr) = cap.name("instruction").map(|instr| instr.as_str()) {
+        match instr {
+          "L" => {
+            turtle.rotation = Heading::from(
+              (turtle.rotation as isize - 1).rem_euclid(4) as usize,
+            )
+          }
+          "R" => {
+            turtle.rotation = Heading::from(
+              (turtle.rotation as isize + 1).rem_euclid(4) as usize,
+            )
+          }
+          _ if instr.chars().all(|c| c.is_ascii_digit()) => {
+            if let Ok(num) = instr.parse::<usize>() {
+              turtle = move_count(turtle, num, &faces);
+            } else {
+              unreachable!()
+            }
+          }
+          _ => {
+            unimplemented!()
+          }
+        }
+      }
+    }
+    moves.push(turtle.clone());
+
+    moves.iter().for_each(|pos| {
+      let row = &mut grid[(pos.face.y - 1) * SIZE + pos.coord.y];
+      let x = (pos.face.x - 1) * SIZE + pos.coord.x;
+      row.replace_range(x..x + 1, &pos.rotation.to_string());
+    });
+
+    let password = 1000 * ((turtle.face.y - 1) * SIZE + turtle.coord.y + 1)
+      + 4 * ((turtle.face.x - 1) * SIZE + turtle.coord.x + 1)
+      + turtle.rotation as usize;
+
+    Self::Solution { grid, password }
+  }
+
+  fn output(solution: Self::Solution) {
+    tracing::debug!("walked cube net\n{}", solution.grid.join("\n"));
+    println!("password {}", solution.password);
+  }
+}
+
+fn read_faces(grid: &[String]) -> Result<HashMap<Coord, Face>, String> {
   let face_data = {
     let mut collection: Vec<Vec<Option<String>>> = vec![vec![None; 7]; 7];
-    grid.as_slice().chunks(SIZE).enumerate().for_each(|(y, v)| {
+    grid.chunks(SIZE).enumerate().for_each(|(y, v)| {
       v.iter().for_each(|line| {
         for x in 0..line.len() / SIZE {
           let row = &line[x * SIZE..(x + 1) * SIZE].to_string();
@@ -203,7 +297,7 @@ fn main() -> Result<(), String> {
     .try_into()
     .map_err(|err| format!("Error building face_coords: {:?}", err))?;
 
-  let mut faces: HashMap<Coord, Face> = face_coords.iter().try_fold(
+  face_coords.iter().try_fold(
     HashMap::new(),
     |mut acc: HashMap<Coord, Face>, cur| {
       let data: CubeFace = {
@@ -240,8 +334,11 @@ fn main() -> Result<(), String> {
 
       Ok::<HashMap<Coord, Face>, String>(acc)
     },
-  )?;
+  )
+}
 
+fn fold_faces(faces: &mut HashMap<Coord, Face>) {
+  let face_coords: Vec<Coord> = faces.keys().copied().collect();
   faces.iter_mut().for_each(|(coord, face)| {
     Heading::headings().for_each(|&heading| {
       let face_coord = heading.relative_coordinate(coord.to_owned());
@@ -304,75 +401,6 @@ fn main() -> Result<(), String> {
       }
     }
   }
-
-  let mut face_x = std::usize::MAX;
-  let mut face_y = std::usize::MAX;
-
-  for &coord in &face_coords {
-    if coord.y < face_y || (coord.y == face_y && coord.x < face_x) {
-      face_x = coord.x;
-      face_y = coord.y;
-    }
-  }
-
-  let mut turtle = Position {
-    face: Coord::from((face_x, face_y)),
-    coord: Coord::from((0, 0)),
-    rotation: Heading::Right,
-  };
-
-  //let moves: Vec<> = Vec::new();
-  let tape = DATA
-    .split('\n')
-    .skip_while(|line| !line.is_empty())
-    .nth(1)
-    .ok_or("invalid tape of instructions")?;
-  let re = Regex::new(r"(?P<instruction>\d+|[RL])").unwrap();
-  let mut moves = Vec::new();
-  for cap in re.captures_iter(tape) {
-    moves.push(turtle.clone());
-    if let Some(instr) = cap.name("instruction").map(|instr| instr.as_str()) {
-      match instr {
-        "L" => {
-          turtle.rotation =
-            Heading::from((turtle.rotation as isize - 1).rem_euclid(4) as usize)
-        }
-        "R" => {
-          turtle.rotation =
-            Heading::from((turtle.rotation as isize + 1).rem_euclid(4) as usize)
-        }
-        _ if instr.chars().all(|c| c.is_ascii_digit()) => {
-          if let Ok(num) = instr.parse::<usize>() {
-            turtle = move_count(turtle, num, &faces);
-          } else {
-            unreachable!()
-          }
-        }
-        _ => {
-          unimplemented!()
-        }
-      }
-    }
-  }
-  moves.push(turtle.clone());
-
-  moves.iter().for_each(|pos| {
-    let row = grid[(pos.face.y - 1) * SIZE + pos.coord.y].to_string();
-    let mut new_row = String::with_capacity(row.len());
-    new_row.push_str(&row[..(pos.face.x - 1) * SIZE + pos.coord.x]);
-    new_row.push_str(&pos.rotation.to_string());
-    new_row.push_str(&row[(pos.face.x - 1) * SIZE + pos.coord.x + 1..]);
-    grid[(pos.face.y - 1) * SIZE + pos.coord.y] =
-      Box::leak(new_row.into_boxed_str());
-  });
-  grid.iter().for_each(|row| eprintln!("{row}"));
-
-  let password = 1000 * ((turtle.face.y - 1) * SIZE + turtle.coord.y + 1)
-    + 4 * ((turtle.face.x - 1) * SIZE + turtle.coord.x + 1)
-    + turtle.rotation as usize;
-  eprintln!("password {password}");
-
-  Ok(())
 }
 
 fn step(p: &mut Position, faces: &HashMap<Coord, Face>) {
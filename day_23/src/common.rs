@@ -1,48 +1,221 @@
+use aoc_core::grid::{Edge, Grid};
+use aoc_core::problem_solver::ParseError;
 use std::cell::RefCell;
+use std::collections::HashMap;
 use std::rc::Rc;
 
-pub fn extend_vector_up<T: Clone + Default>(
-  grid: Rc<RefCell<Vec<Vec<T>>>>,
-  num_rows: usize,
-) {
-  let num_cols = grid.borrow()[0].len();
-  let new_row = vec![Default::default(); num_cols];
-  for _ in 0..num_rows {
-    let mut grid_ref = grid.borrow_mut();
-    grid_ref.insert(0, new_row.clone());
+
+pub type Map = Rc<RefCell<Grid<char>>>;
+pub type Elves = Rc<RefCell<Vec<Coord>>>;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum CardinalDirection {
+  North = 0,
+  South = 1,
+  West = 2,
+  East = 3,
+}
+
+impl CardinalDirection {
+  fn get_steps(
+    step: CardinalDirection,
+  ) -> impl Iterator<Item = CardinalDirection> {
+    (0..4).map(move |i| CardinalDirection::from((step as usize + i) % 4))
+  }
+}
+
+impl From<usize> for CardinalDirection {
+  fn from(value: usize) -> Self {
+    match value % 4 {
+      0 => CardinalDirection::North,
+      1 => CardinalDirection::South,
+      2 => CardinalDirection::West,
+      3 => CardinalDirection::East,
+      _ => unreachable!(),
+    }
+  }
+}
+
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+pub struct Coord {
+  x: usize,
+  y: usize,
+}
+
+impl Coord {
+  fn propose(&self, step: CardinalDirection, elves: Elves) -> Option<Coord> {
+    if !self.is_indicated_to_move(elves.clone()) {
+      return None;
+    }
+    for cardinal_direction in CardinalDirection::get_steps(step) {
+      if self.is_direction_clear(cardinal_direction, elves.clone()) {
+        return Some(self.get_coord_at(cardinal_direction));
+      }
+    }
+    None
+  }
+
+  fn is_indicated_to_move(&self, elves: Elves) -> bool {
+    let elves_inner = elves.borrow();
+    let coords = [
+      Coord { x: self.x, y: self.y + 1 },
+      Coord { x: self.x, y: self.y - 1 },
+      Coord { x: self.x - 1, y: self.y - 1 },
+      Coord { x: self.x - 1, y: self.y },
+      Coord { x: self.x - 1, y: self.y + 1 },
+      Coord { x: self.x + 1, y: self.y - 1 },
+      Coord { x: self.x + 1, y: self.y },
+      Coord { x: self.x + 1, y: self.y + 1 },
+    ];
+
+    elves_inner.iter().any(|e| coords.contains(e))
+  }
+
+  fn is_direction_clear(
+    &self,
+    cardinal_direction: CardinalDirection,
+    elves: Elves,
+  ) -> bool {
+    let elves_inner = elves.borrow();
+    match cardinal_direction {
+      CardinalDirection::North => {
+        let coords = [
+          Coord { x: self.x - 1, y: self.y - 1 },
+          Coord { x: self.x, y: self.y - 1 },
+          Coord { x: self.x + 1, y: self.y - 1 },
+        ];
+        !elves_inner.iter().any(|e| coords.contains(e))
+      }
+      CardinalDirection::South => {
+        let coords = [
+          Coord { x: self.x - 1, y: self.y + 1 },
+          Coord { x: self.x, y: self.y + 1 },
+          Coord { x: self.x + 1, y: self.y + 1 },
+        ];
+        !elves_inner.iter().any(|e| coords.contains(e))
+      }
+      CardinalDirection::West => {
+        let coords = [
+          Coord { x: self.x - 1, y: self.y - 1 },
+          Coord { x: self.x - 1, y: self.y },
+          Coord { x: self.x - 1, y: self.y + 1 },
+        ];
+        !elves_inner.iter().any(|e| coords.contains(e))
+      }
+      CardinalDirection::East => {
+        let coords = [
+          Coord { x: self.x + 1, y: self.y - 1 },
+          Coord { x: self.x + 1, y: self.y },
+          Coord { x: self.x + 1, y: self.y + 1 },
+        ];
+        !elves_inner.iter().any(|e| coords.contains(e))
+      }
+    }
+  }
+
+  fn get_coord_at(&self, cardinal_direction: CardinalDirection) -> Coord {
+    match cardinal_direction {
+      CardinalDirection::North => Coord { x: self.x, y: self.y - 1 },
+      CardinalDirection::South => Coord { x: self.x, y: self.y + 1 },
+      CardinalDirection::West => Coord { x: self.x - 1, y: self.y },
+      CardinalDirection::East => Coord { x: self.x + 1, y: self.y },
+    }
   }
 }
 
-pub fn extend_vector_down<T: Clone + Default>(
-  grid: Rc<RefCell<Vec<Vec<T>>>>,
-  num_rows: usize,
-) {
-  let num_cols = grid.borrow()[0].len();
-  let new_row = vec![Default::default(); num_cols];
-  for _ in 0..num_rows {
-    let mut grid_ref = grid.borrow_mut();
-    grid_ref.push(new_row.clone());
+pub fn read_grove(
+  lines: impl Iterator<Item = String>,
+) -> Result<(Map, Elves), ParseError> {
+  let map = Grid::parse(lines, "expected '.' or '#'", |c| {
+    matches!(c, '.' | '#').then_some(c)
+  })?;
+  let elves = map
+    .iter()
+    .filter(|(_, &c)| c == '#')
+    .map(|((x, y), _)| Coord { x, y })
+    .collect();
+
+  Ok((Rc::new(RefCell::new(map)), Rc::new(RefCell::new(elves))))
+}
+
+/* runs up to `rounds` rounds, returning the first round in which no elf
+proposed a move */
+pub fn spread_out(map: Map, elves: Elves, rounds: usize) -> usize {
+  // readout(map.clone(), elves.clone());
+  let mut out = 0;
+  for i in 0..rounds {
+    conditionally_expand_map(map.clone(), elves.clone());
+    let propositions: HashMap<Coord, Vec<Coord>> =
+      elves.borrow().iter().fold(HashMap::new(), |mut acc, elf| {
+        if let Some(coord) =
+          elf.propose(CardinalDirection::from(i), elves.clone())
+        {
+          if !acc.contains_key(&coord) {
+            acc.insert(coord.clone(), vec![]);
+          }
+          acc.get_mut(&coord).unwrap().push(elf.clone());
+        }
+        acc
+      });
+    if propositions.is_empty() {
+      break;
+    }
+    propositions.iter().for_each(|(destination, pedestrians)| {
+      if pedestrians.len() == 1 {
+        let peoton = pedestrians.last().unwrap();
+        let index = elves.borrow().iter().position(|e| e == peoton).unwrap();
+        elves.borrow_mut()[index] = destination.to_owned();
+      }
+    });
+    out = i;
+    // readout(map.clone(), elves.clone());
   }
+
+  out + 2
 }
 
-pub fn extend_vector_left<T: Clone + Default>(
-  grid: Rc<RefCell<Vec<Vec<T>>>>,
-  num_cols: usize,
-) {
-  let mut grid_ref = grid.borrow_mut();
-  for row in grid_ref.iter_mut() {
-    let new_elements = vec![Default::default(); num_cols];
-    row.splice(..0, new_elements);
+fn conditionally_expand_map(map: Map, elves: Elves) {
+  let mut map = map.borrow_mut();
+  let mut elves_inner = elves.borrow_mut();
+
+  let last_y = map.height() - 1;
+  let last_x = map.width() - 1;
+
+  if elves_inner.iter().any(|e| e.y == 0) {
+    map.extend(Edge::Top, 1, '.');
+    *elves_inner = elves_inner
+      .iter()
+      .map(|e| Coord { x: e.x, y: e.y + 1 })
+      .collect();
+  }
+  if elves_inner.iter().any(|e| e.x == 0) {
+    map.extend(Edge::Left, 1, '.');
+    *elves_inner = elves_inner
+      .iter()
+      .map(|e| Coord { x: e.x + 1, y: e.y })
+      .collect();
+  }
+  if elves_inner.iter().any(|e| e.y == last_y) {
+    map.extend(Edge::Bottom, 1, '.');
+  }
+  if elves_inner.iter().any(|e| e.x == last_x) {
+    map.extend(Edge::Right, 1, '.');
   }
 }
 
-pub fn extend_vector_right<T: Clone + Default>(
-  grid: Rc<RefCell<Vec<Vec<T>>>>,
-  num_cols: usize,
-) {
-  let mut grid_ref = grid.borrow_mut();
-  for row in grid_ref.iter_mut() {
-    let new_elements = vec![Default::default(); num_cols];
-    row.extend(new_elements);
+/* crops the grove to the elves and counts the empty ground tiles */
+pub fn readout(elves: Elves) -> (Grid<char>, usize) {
+  let elves = elves.borrow();
+  let min_x = elves.iter().map(|e| e.x).min().unwrap_or(0);
+  let min_y = elves.iter().map(|e| e.y).min().unwrap_or(0);
+  let max_x = elves.iter().map(|e| e.x).max().unwrap_or(0);
+  let max_y = elves.iter().map(|e| e.y).max().unwrap_or(0);
+
+  let mut grove = Grid::new(max_x - min_x + 1, max_y - min_y + 1, '.');
+  for elf in elves.iter() {
+    grove[(elf.x - min_x, elf.y - min_y)] = '#';
   }
+  let empty_ground = grove.cells().iter().filter(|&&c| c == '.').count();
+
+  (grove, empty_ground)
 }
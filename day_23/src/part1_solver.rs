@@ -0,0 +1,47 @@
+use aoc_core::grid::Grid;
+use aoc_core::problem_solver::{ParseError, ProblemSolver};
+use serde::Serialize;
+
+use crate::common::{read_grove, readout, spread_out, Elves, Map};
+
+
+const ROUNDS: usize = 10;
+
+pub struct PSInput {
+  map: Map,
+  elves: Elves,
+}
+
+#[derive(Serialize)]
+pub struct PSSolution {
+  #[serde(skip)]
+  grove: Grid<char>,
+  empty_ground: usize,
+}
+
+pub struct ProblemSolverPattern;
+
+impl ProblemSolver for ProblemSolverPattern {
+  type Input = PSInput;
+  type Solution = PSSolution;
+
+  fn initialize(
+    lines: impl Iterator<Item = String>,
+  ) -> Result<Self::Input, ParseError> {
+    let (map, elves) = read_grove(lines)?;
+
+    Ok(Self::Input { map, elves })
+  }
+
+  fn solve(input: Self::Input) -> Self::Solution {
+    spread_out(input.map, input.elves.clone(), ROUNDS);
+    let (grove, empty_ground) = readout(input.elves);
+
+    Self::Solution { grove, empty_ground }
+  }
+
+  fn output(solution: Self::Solution) {
+    tracing::debug!("grove\n{}", solution.grove);
+    println!("Total Period Count: {}", solution.empty_ground);
+  }
+}
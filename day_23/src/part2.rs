@@ -0,0 +1,7 @@
+use aoc_core::problem_solver::solve_problem;
+use day_23::part2_solver::ProblemSolverPattern;
+
+
+fn main() {
+  solve_problem::<ProblemSolverPattern>();
+}
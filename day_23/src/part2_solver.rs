@@ -0,0 +1,40 @@
+use aoc_core::problem_solver::{ParseError, ProblemSolver};
+use serde::Serialize;
+
+use crate::common::{read_grove, spread_out, Elves, Map};
+
+
+pub struct PSInput {
+  map: Map,
+  elves: Elves,
+}
+
+#[derive(Serialize)]
+pub struct PSSolution {
+  rounds: usize,
+}
+
+pub struct ProblemSolverPattern;
+
+impl ProblemSolver for ProblemSolverPattern {
+  type Input = PSInput;
+  type Solution = PSSolution;
+
+  fn initialize(
+    lines: impl Iterator<Item = String>,
+  ) -> Result<Self::Input, ParseError> {
+    let (map, elves) = read_grove(lines)?;
+
+    Ok(Self::Input { map, elves })
+  }
+
+  fn solve(input: Self::Input) -> Self::Solution {
+    let rounds = spread_out(input.map, input.elves, usize::MAX);
+
+    Self::Solution { rounds }
+  }
+
+  fn output(solution: Self::Solution) {
+    println!("moves taken {}", solution.rounds);
+  }
+}
@@ -1,25 +1,22 @@
+use aoc_core::grid::Grid;
+use aoc_core::problem_solver::ParseError;
 use std::{collections::HashSet, rc::Rc};
 
 
-const DATA: &str = include_str!("../input.txt");
 const STORM_SYMBOLS: [char; 4] = ['<', '>', '^', 'v'];
 const NEIGHBORS: [[isize; 2]; 5] = [[1, 0], [0, 1], [-1, 0], [0, -1], [0, 0]];
 const ESCAPE_HATCH: usize = 10_000;
-#[cfg(not(feature = "part2"))]
-const REPEATED_SEARCH: bool = false;
-#[cfg(feature = "part2")]
-const REPEATED_SEARCH: bool = true;
 
-type ProblemDescription = (Coord, Coord, Coord, Rc<Vec<Storm>>);
+pub type ProblemDescription = (Coord, Coord, Coord, Rc<Vec<Storm>>);
 
 #[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
-struct Coord {
+pub struct Coord {
   x: isize,
   y: isize,
 }
 
 #[derive(Debug)]
-struct Storm {
+pub struct Storm {
   direction: char,
   coord: Coord,
 }
@@ -52,65 +49,49 @@ impl Storm {
   }
 }
 
-fn main() -> Result<(), String> {
-  let (start, end, terminus, storms) = extract()?;
-  let time = transform(&start, &end, &terminus, storms.clone())?;
-
-  println!("time {time}");
-
-  Ok(())
-}
-
-fn extract() -> Result<ProblemDescription, String> {
-  let input: Vec<Vec<char>> = DATA
-    .split('\n')
-    .map(|line| line.chars().collect::<Vec<_>>())
-    .collect::<Vec<_>>();
-  let last_row = input.len() - 1;
-  let mut start = Coord::default();
-  let mut end = Coord::default();
-  let mut storms: Vec<Storm> = Vec::new();
+pub fn read_valley(
+  lines: impl Iterator<Item = String>,
+) -> Result<ProblemDescription, ParseError> {
+  let valley = Grid::parse(lines, "expected a wall, ground or storm", |c| {
+    (matches!(c, '#' | '.') || STORM_SYMBOLS.contains(&c)).then_some(c)
+  })?;
+  if valley.height() < 3 {
+    return Err(ParseError::whole_input("valley needs at least 3 rows"));
+  }
+  let last_row = valley.height() - 1;
+  let row_source = |y: usize| valley.row(y).iter().collect::<String>();
+  let opening = |y: usize, message: &str| {
+    let x = valley.row(y).iter().position(|&c| c == '.');
+    x.map(|x| Coord { x: x as isize, y: y as isize })
+      .ok_or_else(|| ParseError::new(y, &row_source(y), message))
+  };
+  let start = opening(0, "No starting element found.")?;
+  let end = opening(last_row, "No ending element found.")?;
   let terminus = Coord {
-    x: input[0].len() as isize - 2,
-    y: input.len() as isize - 2,
+    x: valley.width() as isize - 2,
+    y: valley.height() as isize - 2,
   };
-  for (y, row) in input.iter().enumerate() {
-    for (x, tile) in row.iter().enumerate() {
-      if y == 0 {
-        let x = row
-          .iter()
-          .position(|&c| c == '.')
-          .ok_or("No starting element found.")?;
-        start = Coord { x: x as isize, y: y as isize };
-        continue;
-      } else if y == last_row {
-        let x = row
-          .iter()
-          .position(|&c| c == '.')
-          .ok_or("No ending element found.")?;
-        end = Coord { x: x as isize, y: y as isize };
-        continue;
-      }
-
-      if STORM_SYMBOLS.contains(tile) {
-        storms.push(Storm {
-          direction: tile.to_owned(),
-          coord: Coord { x: x as isize, y: y as isize },
-        })
-      }
-    }
-  }
+  let storms: Vec<Storm> = valley
+    .iter()
+    .filter(|(_, tile)| STORM_SYMBOLS.contains(tile))
+    .map(|((x, y), tile)| Storm {
+      direction: tile.to_owned(),
+      coord: Coord { x: x as isize, y: y as isize },
+    })
+    .collect();
 
   Ok((start, end, terminus, Rc::new(storms)))
 }
 
-fn transform(
+/* part 2 goes back for the snacks, crossing the valley three times */
+pub fn transform(
   start: &Coord,
   end: &Coord,
   terminus: &Coord,
   storms: Rc<Vec<Storm>>,
+  repeated_search: bool,
 ) -> Result<usize, String> {
-  if REPEATED_SEARCH {
+  if repeated_search {
     let mut t = search(start, end, terminus, storms.clone(), 0)?;
     t = search(end, start, terminus, storms.clone(), t)?;
     t = search(start, end, terminus, storms, t)?;
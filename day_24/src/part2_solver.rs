@@ -0,0 +1,45 @@
+use aoc_core::problem_solver::{ParseError, ProblemSolver};
+use serde::Serialize;
+
+use crate::common::{read_valley, transform, ProblemDescription};
+
+
+const REPEATED_SEARCH: bool = true;
+
+pub struct PSInput {
+  valley: ProblemDescription,
+}
+
+#[derive(Serialize)]
+pub struct PSSolution {
+  time: Result<usize, String>,
+}
+
+pub struct ProblemSolverPattern;
+
+impl ProblemSolver for ProblemSolverPattern {
+  type Input = PSInput;
+  type Solution = PSSolution;
+
+  fn initialize(
+    lines: impl Iterator<Item = String>,
+  ) -> Result<Self::Input, ParseError> {
+    let valley = read_valley(lines)?;
+
+    Ok(Self::Input { valley })
+  }
+
+  fn solve(input: Self::Input) -> Self::Solution {
+    let (start, end, terminus, storms) = input.valley;
+    let time = transform(&start, &end, &terminus, storms, REPEATED_SEARCH);
+
+    Self::Solution { time }
+  }
+
+  fn output(solution: Self::Solution) {
+    match solution.time {
+      Ok(time) => println!("time {time}"),
+      Err(err) => eprintln!("{err}"),
+    }
+  }
+}
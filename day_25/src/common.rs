@@ -1,4 +1,7 @@
-use std::{cmp::Ordering, env, fmt, fs, iter::Sum, ops::Add, rc::Rc};
+use aoc_core::problem_solver::ParseError;
+use serde::{Serialize, Serializer};
+use std::{cmp::Ordering, fmt, iter::Sum, ops::Add, rc::Rc, str::FromStr};
+
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 enum BalancedQuinaryDigit {
@@ -45,7 +48,7 @@ impl BalancedQuinaryDigit {
 
 impl PartialOrd for BalancedQuinaryDigit {
   fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-    Some(self.to_value().cmp(&other.to_value()))
+    Some(self.cmp(other))
   }
 }
 
@@ -98,7 +101,7 @@ impl fmt::Display for BalancedQuinaryDigit {
 }
 
 #[derive(Clone, Debug, PartialEq)]
-struct BalancedQuinaryNumber {
+pub struct BalancedQuinaryNumber {
   digits: Rc<Vec<BalancedQuinaryDigit>>,
 }
 
@@ -113,23 +116,28 @@ impl BalancedQuinaryNumber {
       digits.insert(0, BalancedQuinaryDigit::Zero);
     }
   }
+}
+
+impl FromStr for BalancedQuinaryNumber {
+  /* column and character of the first invalid digit */
+  type Err = (usize, char);
 
-  fn from_str(input: &str) -> Option<Self> {
+  fn from_str(input: &str) -> Result<Self, Self::Err> {
     let mut digits = Vec::new();
 
-    for c in input.chars() {
+    for (column, c) in input.chars().enumerate() {
       let digit = match c {
         '0' => BalancedQuinaryDigit::Zero,
         '1' => BalancedQuinaryDigit::One,
         '2' => BalancedQuinaryDigit::Two,
         '-' => BalancedQuinaryDigit::MinusOne,
         '=' => BalancedQuinaryDigit::MinusTwo,
-        _ => return None, // Invalid character
+        _ => return Err((column + 1, c)),
       };
       digits.push(digit);
     }
 
-    Some(BalancedQuinaryNumber { digits: Rc::new(digits) })
+    Ok(BalancedQuinaryNumber { digits: Rc::new(digits) })
   }
 }
 
@@ -224,39 +232,25 @@ impl fmt::Display for BalancedQuinaryNumber {
   }
 }
 
-fn main() -> Result<(), String> {
-  let src = extract()?;
-  let results = transform(src)?;
-
-  readout(&results)
-}
-
-fn extract() -> Result<String, String> {
-  let filename = env::args().nth(1).ok_or("Expected file argument")?;
-
-  fs::read_to_string(filename).map_err(|_| "Failed to read file".to_string())
-}
-
-fn transform(src: String) -> Result<Vec<BalancedQuinaryNumber>, String> {
-  let mut output: Vec<BalancedQuinaryNumber> = Vec::new();
-  for line in src.split('\n') {
-    output.push(
-      BalancedQuinaryNumber::from_str(line).ok_or("cannot parse number")?,
-    );
+/* serialized in its snafu notation, the same way it is displayed */
+impl Serialize for BalancedQuinaryNumber {
+  fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.collect_str(self)
   }
-
-  Ok(output)
 }
 
-fn readout(results: &[BalancedQuinaryNumber]) -> Result<(), String> {
-  results.iter().for_each(|result| {
-    let value: i128 = result.to_owned().into();
-    println!("{}", value);
-  });
-  println!(
-    "total: {}",
-    results.iter().cloned().sum::<BalancedQuinaryNumber>()
-  );
-
-  Ok(())
+pub fn read_numbers(
+  lines: impl Iterator<Item = String>,
+) -> Result<Vec<BalancedQuinaryNumber>, ParseError> {
+  lines
+    .enumerate()
+    .map(|(index, line)| {
+      line
+        .parse::<BalancedQuinaryNumber>()
+        .map_err(|(column, c)| {
+          ParseError::new(index, &line, "invalid balanced quinary digit")
+            .at_column(column, &c.to_string())
+        })
+    })
+    .collect()
 }
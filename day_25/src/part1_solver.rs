@@ -0,0 +1,45 @@
+use aoc_core::problem_solver::{ParseError, ProblemSolver};
+use serde::Serialize;
+
+use crate::common::{read_numbers, BalancedQuinaryNumber};
+
+
+pub struct PSInput {
+  numbers: Vec<BalancedQuinaryNumber>,
+}
+
+#[derive(Serialize)]
+pub struct PSSolution {
+  values: Vec<i128>,
+  total: BalancedQuinaryNumber,
+}
+
+pub struct ProblemSolverPattern;
+
+impl ProblemSolver for ProblemSolverPattern {
+  type Input = PSInput;
+  type Solution = PSSolution;
+
+  fn initialize(
+    lines: impl Iterator<Item = String>,
+  ) -> Result<Self::Input, ParseError> {
+    let numbers = read_numbers(lines)?;
+
+    Ok(Self::Input { numbers })
+  }
+
+  fn solve(input: Self::Input) -> Self::Solution {
+    let values = input.numbers.iter().cloned().map(i128::from).collect();
+    let total = input.numbers.into_iter().sum::<BalancedQuinaryNumber>();
+
+    Self::Solution { values, total }
+  }
+
+  fn output(solution: Self::Solution) {
+    solution
+      .values
+      .iter()
+      .for_each(|value| tracing::debug!(value));
+    println!("total: {}", solution.total);
+  }
+}
@@ -0,0 +1,3 @@
+pub mod rucksack;
+pub mod rucksack_priorities_module;
+pub mod safety_badges_module;
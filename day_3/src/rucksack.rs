@@ -0,0 +1,154 @@
+use aoc_core::problem_solver::ParseError;
+use std::ops::BitAnd;
+
+
+/* a set of items as bits, bit `p` standing for the item of priority `p`:
+a to z are 1 to 26 and A to Z 27 to 52 */
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ItemSet(u64);
+
+impl ItemSet {
+  /* every item there is, the identity of intersection */
+  pub const ALL: ItemSet = ItemSet(((1 << 52) - 1) << 1);
+
+  /* `Err` holds the byte offset of the first byte that is not an ascii
+  letter */
+  pub fn from_items(items: &[u8]) -> Result<Self, usize> {
+    items
+      .iter()
+      .enumerate()
+      .try_fold(ItemSet(0), |set, (offset, &item)| {
+        priority(item)
+          .map(|priority| ItemSet(set.0 | 1 << priority))
+          .ok_or(offset)
+      })
+  }
+
+  pub fn is_empty(self) -> bool {
+    self.0 == 0
+  }
+
+  /* the priorities of the items in the set, lowest first */
+  pub fn priorities(self) -> impl Iterator<Item = u32> {
+    (1..=52).filter(move |priority| self.0 & 1 << priority != 0)
+  }
+
+  pub fn priority_sum(self) -> u32 {
+    self.priorities().sum()
+  }
+
+  pub fn items(self) -> impl Iterator<Item = char> {
+    self.priorities().map(item)
+  }
+}
+
+impl BitAnd for ItemSet {
+  type Output = ItemSet;
+
+  fn bitand(self, other: ItemSet) -> ItemSet {
+    ItemSet(self.0 & other.0)
+  }
+}
+
+pub fn priority(item: u8) -> Option<u32> {
+  match item {
+    b'a'..=b'z' => Some((item - b'a') as u32 + 1),
+    b'A'..=b'Z' => Some((item - b'A') as u32 + 27),
+    _ => None,
+  }
+}
+
+pub fn item(priority: u32) -> char {
+  match priority {
+    1..=26 => (b'a' + (priority - 1) as u8) as char,
+    _ => (b'A' + (priority - 27) as u8) as char,
+  }
+}
+
+/* the items in each of a rucksack's two equally sized compartments */
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Rucksack {
+  pub left: ItemSet,
+  pub right: ItemSet,
+}
+
+impl Rucksack {
+  pub fn parse(index: usize, line: &str) -> Result<Self, ParseError> {
+    let items = line.as_bytes();
+    if items.is_empty() {
+      return Err(ParseError::new(index, line, "a rucksack cannot be empty"));
+    }
+    if let Err(offset) = ItemSet::from_items(items) {
+      let text = line[offset..].chars().next().map(String::from);
+      return Err(
+        ParseError::new(index, line, "items must be ascii letters").at_column(
+          line[..offset].chars().count() + 1,
+          &text.unwrap_or_default(),
+        ),
+      );
+    }
+    if !items.len().is_multiple_of(2) {
+      return Err(ParseError::new(
+        index,
+        line,
+        format!("{} items do not split into two compartments", items.len()),
+      ));
+    }
+    let (left, right) = items.split_at(items.len() / 2);
+    let set = |items| ItemSet::from_items(items).unwrap_or_default();
+
+    Ok(Rucksack { left: set(left), right: set(right) })
+  }
+
+  pub fn items(self) -> ItemSet {
+    ItemSet(self.left.0 | self.right.0)
+  }
+
+  /* the items packed in both compartments */
+  pub fn shared(self) -> ItemSet {
+    self.left & self.right
+  }
+}
+
+/* the items common to each group of `group_size` consecutive rucksacks.
+every line is validated as a rucksack, a group sharing no item or an
+incomplete last group are errors */
+pub fn group_badges(
+  lines: impl Iterator<Item = String>,
+  group_size: usize,
+) -> Result<Vec<ItemSet>, ParseError> {
+  let mut badges: Vec<ItemSet> = vec![];
+  let mut group = ItemSet::ALL;
+  let mut count = 0;
+  for (index, line) in lines.enumerate() {
+    group = group & Rucksack::parse(index, &line)?.items();
+    count += 1;
+    if count < group_size {
+      continue;
+    }
+    if group.is_empty() {
+      return Err(ParseError::new(
+        index,
+        &line,
+        format!(
+          "no item is common to the group of lines {} to {}",
+          index + 2 - group_size,
+          index + 1
+        ),
+      ));
+    }
+    badges.push(group);
+    (group, count) = (ItemSet::ALL, 0);
+  }
+  if count > 0 {
+    return Err(ParseError::whole_input(format!(
+      "the last group has {count} of {group_size} rucksacks"
+    )));
+  }
+
+  Ok(badges)
+}
+
+#[cfg(test)]
+#[path = "./tests/rucksack_tests.rs"]
+mod rucksack_tests;
@@ -1,7 +1,5 @@
-mod rucksack_priorities_module;
-use rucksack_priorities_module::ProblemSolverPattern;
-mod problem_solver;
-use problem_solver::solve_problem;
+use aoc_core::problem_solver::solve_problem;
+use day_3::rucksack_priorities_module::ProblemSolverPattern;
 
 
 fn main() {
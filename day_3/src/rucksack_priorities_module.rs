@@ -1,12 +1,17 @@
-use std::collections::HashMap;
+use aoc_core::problem_solver::{ParseError, ProblemSolver};
+use serde::Serialize;
+
+use super::rucksack::Rucksack;
 
-use super::problem_solver::ProblemSolver;
 
 pub struct PSInput {
-  common: Vec<char>,
+  rucksacks: Vec<Rucksack>,
 }
 
+#[derive(Serialize)]
 pub struct PSSolution {
+  /* the items packed in both compartments, one entry per line */
+  shared: Vec<String>,
   score: u32,
 }
 
@@ -16,44 +21,36 @@ impl ProblemSolver for ProblemSolverPattern {
   type Input = PSInput;
   type Solution = PSSolution;
 
-  fn initialize(lines: impl Iterator<Item = String>) -> Self::Input {
-    let mut common: Vec<char> = vec![];
-    for record in lines {
-      let mut interim: HashMap<char, bool> = HashMap::new();
-      let i = record.len() / 2;
-      let first = &record[..i].chars().collect::<Vec<_>>();
-      let rest = &record[i..];
-      for c in first {
-        if rest.contains(*c) && !interim.contains_key(c) {
-          interim.insert(*c, true);
-        }
-      }
-      common.extend(interim.keys().cloned());
-    }
+  fn initialize(
+    lines: impl Iterator<Item = String>,
+  ) -> Result<Self::Input, ParseError> {
+    let rucksacks = lines
+      .enumerate()
+      .map(|(index, line)| Rucksack::parse(index, &line))
+      .collect::<Result<_, _>>()?;
 
-    PSInput { common }
+    Ok(PSInput { rucksacks })
   }
 
   fn solve(input: Self::Input) -> Self::Solution {
+    let shared: Vec<String> = input
+      .rucksacks
+      .iter()
+      .map(|rucksack| rucksack.shared().items().collect())
+      .collect();
     let score = input
-      .common
+      .rucksacks
       .iter()
-      .map(|c| {
-        let mut priority = *c as u32 - 64;
-        if priority < 27 {
-          priority += 26;
-        } else {
-          priority -= 32;
-        }
-        println!("{} -> {}", *c, priority);
-        priority
-      })
+      .map(|rucksack| rucksack.shared().priority_sum())
       .sum();
 
-    PSSolution { score }
+    PSSolution { shared, score }
   }
 
   fn output(solution: Self::Solution) {
+    for (index, items) in solution.shared.iter().enumerate() {
+      tracing::trace!(line = index + 1, shared = %items);
+    }
     println!("score: {}", solution.score);
   }
 }
@@ -1,7 +1,5 @@
-mod safety_badges_module;
-use safety_badges_module::ProblemSolverPattern;
-mod problem_solver;
-use problem_solver::solve_problem;
+use aoc_core::problem_solver::solve_problem;
+use day_3::safety_badges_module::ProblemSolverPattern;
 
 
 fn main() {
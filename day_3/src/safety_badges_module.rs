@@ -1,50 +1,60 @@
-use super::problem_solver::ProblemSolver;
+use aoc_core::problem_solver::{Params, ParseError, ProblemSolver};
+use serde::Serialize;
+
+use super::rucksack::{group_badges, ItemSet};
 
 
 pub struct PSInput {
-  common: Vec<char>,
+  badges: Vec<ItemSet>,
 }
 
+#[derive(Serialize)]
 pub struct PSSolution {
   score: u32,
 }
 
-pub struct ProblemSolverPattern;
+/* the badges of groups of `GROUP_SIZE` elves, three in the puzzle, or as
+many as the `group` parameter asks for */
+pub struct ProblemSolverPattern<const GROUP_SIZE: usize = 3>;
 
-impl ProblemSolver for ProblemSolverPattern {
+impl<const GROUP_SIZE: usize> ProblemSolver
+  for ProblemSolverPattern<GROUP_SIZE>
+{
   type Input = PSInput;
   type Solution = PSSolution;
+  const PARAMS: &'static [&'static str] = &["group"];
 
-  fn initialize(lines: impl Iterator<Item = String>) -> Self::Input {
-    let mut common: Vec<char> = vec![];
-    let lines_vec: Vec<_> = lines.collect();
-    let line_chunks = lines_vec.chunks(3);
-    for group in line_chunks {
-      for c in group[0].chars() {
-        if group[1].contains(c) && group[2].contains(c) {
-          common.push(c);
-          break;
-        }
-      }
+  fn initialize(
+    lines: impl Iterator<Item = String>,
+  ) -> Result<Self::Input, ParseError> {
+    Self::initialize_with(lines, &Params::new())
+  }
+
+  fn initialize_with(
+    lines: impl Iterator<Item = String>,
+    params: &Params,
+  ) -> Result<Self::Input, ParseError> {
+    let group_size = params.get("group")?.unwrap_or(GROUP_SIZE);
+    if group_size == 0 {
+      return Err(ParseError::whole_input(
+        "--group expects a positive number of elves",
+      ));
     }
 
-    PSInput { common }
+    Ok(PSInput { badges: group_badges(lines, group_size)? })
   }
 
   fn solve(input: Self::Input) -> Self::Solution {
     let score = input
-      .common
+      .badges
       .iter()
       .enumerate()
-      .map(|(i, c)| {
-        let mut priority = *c as u32 - 64;
-        if priority < 27 {
-          priority += 26;
-        } else {
-          priority -= 32;
+      .map(|(i, badge)| {
+        if tracing::enabled!(tracing::Level::TRACE) {
+          let items: String = badge.items().collect();
+          tracing::trace!(group = i, badge = %items);
         }
-        println!("[{}] {} -> {}", i, *c, priority);
-        priority
+        badge.priority_sum()
       })
       .sum();
 
@@ -55,3 +65,7 @@ impl ProblemSolver for ProblemSolverPattern {
     println!("score: {}", solution.score);
   }
 }
+
+#[cfg(test)]
+#[path = "./tests/safety_badges_module_tests.rs"]
+mod safety_badges_module_tests;
@@ -0,0 +1,74 @@
+use super::*;
+
+
+fn lines(text: &str) -> impl Iterator<Item = String> + '_ {
+  text.lines().map(String::from)
+}
+
+#[test]
+fn it_should_find_the_item_shared_by_both_compartments() {
+  // Arrange
+  let line = "vJrwpWtwJgWrhcsFMMfFFhFp";
+
+  // Act
+  let rucksack = Rucksack::parse(0, line).unwrap();
+
+  // Assert
+  assert_eq!(rucksack.shared().items().collect::<String>(), "p");
+  assert_eq!(rucksack.shared().priority_sum(), 16);
+}
+
+#[test]
+fn it_should_reject_an_empty_line() {
+  // Arrange
+  let line = "";
+
+  // Act
+  let result = Rucksack::parse(4, line);
+
+  // Assert
+  let err = result.unwrap_err().to_string();
+  assert!(err.contains("line 5"), "{err}");
+  assert!(err.contains("a rucksack cannot be empty"), "{err}");
+}
+
+#[test]
+fn it_should_reject_an_item_that_is_not_a_letter() {
+  // Arrange
+  let line = "ab1d";
+
+  // Act
+  let result = Rucksack::parse(0, line);
+
+  // Assert
+  let err = result.unwrap_err().to_string();
+  assert!(err.contains("items must be ascii letters"), "{err}");
+}
+
+#[test]
+fn it_should_find_the_badges_of_groups_of_any_size() {
+  // Arrange
+  let text = "abCz\nxbCy\nqrCb\nbsCt";
+
+  // Act
+  let pairs = group_badges(lines(text), 2).unwrap();
+  let fours = group_badges(lines(text), 4).unwrap();
+
+  // Assert
+  let items = |set: &ItemSet| set.items().collect::<String>();
+  assert_eq!(pairs.iter().map(items).collect::<Vec<_>>(), ["bC", "bC"]);
+  assert_eq!(fours.iter().map(items).collect::<Vec<_>>(), ["bC"]);
+}
+
+#[test]
+fn it_should_reject_an_incomplete_last_group() {
+  // Arrange
+  let text = "abCz\nxbCy\nqrCb\nbsCt";
+
+  // Act
+  let result = group_badges(lines(text), 3);
+
+  // Assert
+  let err = result.unwrap_err().to_string();
+  assert!(err.contains("the last group has 1 of 3 rucksacks"), "{err}");
+}
@@ -0,0 +1,55 @@
+use aoc_core::problem_solver::{Params, ProblemSolver};
+
+use super::*;
+
+
+fn lines() -> impl Iterator<Item = String> {
+  "vJrwpWtwJgWrhcsFMMfFFhFp
+jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL
+PmmdzqPrVvPwwTWBwg
+wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn
+ttgJtRGJQctTZtZT
+CrZsJsPPZsGzwwsLwLmpwMDw"
+    .lines()
+    .map(String::from)
+}
+
+#[test]
+fn it_should_group_elves_by_three_by_default() {
+  // Act
+  let input = ProblemSolverPattern::<3>::initialize(lines()).unwrap();
+  let solution = ProblemSolverPattern::<3>::solve(input);
+
+  // Assert
+  assert_eq!(solution.score, 70);
+}
+
+#[test]
+fn it_should_take_the_group_parameter_over_the_group_size() {
+  // Arrange
+  let params = Params::new().with("group", "6");
+
+  // Act
+  let result = ProblemSolverPattern::<3>::initialize_with(lines(), &params);
+
+  // Assert
+  assert_eq!(
+    result.err().map(|err| err.message),
+    Some("no item is common to the group of lines 1 to 6".to_owned())
+  );
+}
+
+#[test]
+fn it_should_reject_an_empty_group() {
+  // Arrange
+  let params = Params::new().with("group", "0");
+
+  // Act
+  let result = ProblemSolverPattern::<3>::initialize_with(lines(), &params);
+
+  // Assert
+  assert_eq!(
+    result.err().map(|err| err.message),
+    Some("--group expects a positive number of elves".to_owned())
+  );
+}
@@ -0,0 +1,41 @@
+use aoc_core::{interval::Interval, problem_solver::ParseError};
+
+
+const RECORD_FORMAT: &str =
+  "lines must be ranges of positive integers of the form x-y,a-b";
+
+pub fn read_range_pairs(
+  lines: impl Iterator<Item = String>,
+) -> Result<(Vec<Interval>, Vec<Interval>), ParseError> {
+  let mut left_ranges: Vec<Interval> = vec![];
+  let mut right_ranges: Vec<Interval> = vec![];
+  for (index, record) in lines.enumerate() {
+    let (left, right) = record
+      .split_once(',')
+      .ok_or_else(|| ParseError::new(index, &record, RECORD_FORMAT))?;
+    left_ranges.push(parse_range(index, &record, left)?);
+    right_ranges.push(parse_range(index, &record, right)?);
+  }
+
+  Ok((left_ranges, right_ranges))
+}
+
+fn parse_range(
+  index: usize,
+  record: &str,
+  range: &str,
+) -> Result<Interval, ParseError> {
+  let (start, end) = range
+    .split_once('-')
+    .ok_or_else(|| ParseError::new(index, record, RECORD_FORMAT).at(range))?;
+  let parse_bound = |bound: &str| {
+    bound.parse::<u32>().map_err(|_| {
+      ParseError::new(index, record, "expected a positive integer").at(bound)
+    })
+  };
+
+  Interval::new(parse_bound(start)?.into(), parse_bound(end)?.into())
+    .ok_or_else(|| {
+      ParseError::new(index, record, "range ends before it starts").at(range)
+    })
+}
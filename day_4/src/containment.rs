@@ -1,7 +1,5 @@
-mod containment_module;
-use containment_module::ProblemSolverPattern;
-mod problem_solver;
-use problem_solver::solve_problem;
+use aoc_core::problem_solver::solve_problem;
+use day_4::containment_module::ProblemSolverPattern;
 
 
 fn main() {
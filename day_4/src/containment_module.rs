@@ -1,11 +1,16 @@
-use super::problem_solver::ProblemSolver;
+use aoc_core::interval::Interval;
+use aoc_core::problem_solver::{ParseError, ProblemSolver};
+use serde::Serialize;
+
+use super::common::read_range_pairs;
 
 
 pub struct PSInput {
-  left_ranges: Vec<(u32, u32)>,
-  right_ranges: Vec<(u32, u32)>,
+  left_ranges: Vec<Interval>,
+  right_ranges: Vec<Interval>,
 }
 
+#[derive(Serialize)]
 pub struct PSSolution {
   containments: u32,
 }
@@ -16,50 +21,27 @@ impl ProblemSolver for ProblemSolverPattern {
   type Input = PSInput;
   type Solution = PSSolution;
 
-  fn initialize(lines: impl Iterator<Item = String>) -> Self::Input {
-    let mut left_ranges: Vec<(u32, u32)> = vec![];
-    let mut right_ranges: Vec<(u32, u32)> = vec![];
-    for record in lines {
-      let (left, right) = record.split_once(',').unwrap_or_else(|| {
-        panic!(
-          "precondition failure! lines must be ranges of positive integers of \
-           the form x-y,a-b, found (record):\n{}",
-          record
-        )
-      });
-      let fmt_error = &format!(
-        "precondition failure! lines must be ranges of positive integers of \
-         the form x-y,a-b, found:\n{}",
-        record
-      );
-      let (x, y) = left.split_once('-').expect(fmt_error);
-      let left_range =
-        (x.parse().expect(fmt_error), y.parse().expect(fmt_error));
-      left_ranges.push(left_range);
-      let (a, b) = right.split_once('-').expect(fmt_error);
-      let right_range =
-        (a.parse().expect(fmt_error), b.parse().expect(fmt_error));
-      right_ranges.push(right_range);
-    }
+  fn initialize(
+    lines: impl Iterator<Item = String>,
+  ) -> Result<Self::Input, ParseError> {
+    let (left_ranges, right_ranges) = read_range_pairs(lines)?;
 
-    PSInput { left_ranges, right_ranges }
+    Ok(PSInput { left_ranges, right_ranges })
   }
 
   fn solve(input: Self::Input) -> Self::Solution {
-    let containments =
-      input
-        .left_ranges
-        .iter()
-        .enumerate()
-        .fold(0, |acc, (i, (x, y))| {
-          let (a, b) = input.right_ranges[i];
-          if (&a >= x && &b <= y) || (x >= &a && y <= &b) {
-            println!("containment found for {:?} and {:?}", (a, b), (x, y));
-            acc + 1
-          } else {
-            acc
-          }
-        });
+    let containments = input
+      .left_ranges
+      .iter()
+      .zip(&input.right_ranges)
+      .filter(|(left, right)| {
+        let found = left.contains(right) || right.contains(left);
+        if found {
+          tracing::trace!(%left, %right, "containment");
+        }
+        found
+      })
+      .count() as u32;
 
     PSSolution { containments }
   }
@@ -0,0 +1,3 @@
+pub mod common;
+pub mod containment_module;
+pub mod overlap_module;
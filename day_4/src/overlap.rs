@@ -1,7 +1,6 @@
-mod overlap_module;
-use overlap_module::ProblemSolverPattern;
-mod problem_solver;
-use problem_solver::solve_problem;
+use aoc_core::problem_solver::solve_problem;
+use day_4::overlap_module::ProblemSolverPattern;
+
 
 fn main() {
   solve_problem::<ProblemSolverPattern>();
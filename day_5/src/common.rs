@@ -0,0 +1,161 @@
+use aoc_core::problem_solver::ParseError;
+use lazy_static::lazy_static;
+use regex::Regex;
+
+
+pub struct Move {
+  pub count: usize,
+  pub from: usize,
+  pub to: usize,
+}
+
+lazy_static! {
+  static ref RE: Regex =
+    Regex::new(r"^move (\d+) from (\d+) to (\d+)$").unwrap();
+}
+
+/* the stack drawing, its numbered line, a blank line and then the moves.
+stacks are listed bottom crate first and moves address them from 0. a move
+taking more crates than its stack holds at that point is an error */
+pub fn read_crane_plan(
+  lines: impl Iterator<Item = String>,
+) -> Result<(Vec<Vec<char>>, Vec<Move>), ParseError> {
+  let mut lines = lines.enumerate();
+  let mut rows: Vec<(usize, String, Vec<Option<char>>)> = vec![];
+  let stack_count = loop {
+    let Some((index, line)) = lines.next() else {
+      return Err(ParseError::whole_input(
+        "the stack drawing has no line numbering its stacks",
+      ));
+    };
+    if line.trim_start().starts_with(|c: char| c.is_ascii_digit()) {
+      break stack_count(index, &line)?;
+    }
+    let crates = crates_from_record(index, &line)?;
+    rows.push((index, line, crates));
+  };
+
+  let mut stacks: Vec<Vec<char>> = vec![vec![]; stack_count];
+  for (height, (index, line, crates)) in rows.into_iter().rev().enumerate() {
+    if crates.len() > stack_count {
+      let column = stack_count * 4 + 1;
+      return Err(
+        ParseError::new(index, &line, format!("only {stack_count} stacks"))
+          .at_column(column, &line[column - 1..]),
+      );
+    }
+    for (stack, c) in crates.into_iter().enumerate() {
+      match (c, stacks[stack].len() < height) {
+        (Some(_), true) => {
+          return Err(
+            ParseError::new(index, &line, "crate floats above an empty slot")
+              .at_column(stack * 4 + 1, &line[stack * 4..stack * 4 + 3]),
+          )
+        }
+        (Some(c), false) => stacks[stack].push(c),
+        (None, _) => {}
+      }
+    }
+  }
+
+  let mut heights: Vec<usize> = stacks.iter().map(Vec::len).collect();
+  let mut moves: Vec<Move> = vec![];
+  for (index, line) in lines.filter(|(_, line)| !line.trim().is_empty()) {
+    let mv = move_from_record(index, &line, stack_count)?;
+    if heights[mv.from] < mv.count {
+      return Err(ParseError::new(
+        index,
+        &line,
+        format!(
+          "stack {} holds only {} crates",
+          mv.from + 1,
+          heights[mv.from]
+        ),
+      ));
+    }
+    heights[mv.from] -= mv.count;
+    heights[mv.to] += mv.count;
+    moves.push(mv);
+  }
+
+  Ok((stacks, moves))
+}
+
+fn stack_count(index: usize, line: &str) -> Result<usize, ParseError> {
+  let numbers: Vec<&str> = line.split_whitespace().collect();
+  for (expected, number) in numbers.iter().enumerate() {
+    if number.parse::<usize>().ok() != Some(expected + 1) {
+      return Err(
+        ParseError::new(
+          index,
+          line,
+          format!("expected stack number {}", expected + 1),
+        )
+        .at(number),
+      );
+    }
+  }
+
+  Ok(numbers.len())
+}
+
+fn move_from_record(
+  index: usize,
+  line: &str,
+  stack_count: usize,
+) -> Result<Move, ParseError> {
+  let captures = RE.captures(line.trim_end()).ok_or_else(|| {
+    ParseError::new(index, line, "expected `move <n> from <stack> to <stack>`")
+  })?;
+  let stack = |group: usize| {
+    let number = &captures[group];
+    match number.parse::<usize>() {
+      Ok(stack) if (1..=stack_count).contains(&stack) => Ok(stack - 1),
+      _ => Err(
+        ParseError::new(
+          index,
+          line,
+          format!("expected a stack from 1 to {stack_count}"),
+        )
+        .at_column(captures.get(group).unwrap().start() + 1, number),
+      ),
+    }
+  };
+  let count = captures[1].parse::<usize>().map_err(|_| {
+    ParseError::new(index, line, "crate count is too large").at(&captures[1])
+  })?;
+
+  Ok(Move { count, from: stack(2)?, to: stack(3)? })
+}
+
+/* one optional crate per stack, drawn as `[X]` or three spaces with a space
+between stacks. trailing empty stacks may be left out */
+fn crates_from_record(
+  index: usize,
+  line: &str,
+) -> Result<Vec<Option<char>>, ParseError> {
+  let error = |column: usize, text: &str| {
+    ParseError::new(index, line, "expected `[X]` or blanks for each stack")
+      .at_column(column + 1, text)
+  };
+  if !line.is_ascii() {
+    return Err(ParseError::new(index, line, "expected an ascii drawing"));
+  }
+  let line_trimmed = line.trim_end();
+  line_trimmed
+    .as_bytes()
+    .chunks(4)
+    .enumerate()
+    .map(|(stack, cell)| {
+      let column = stack * 4;
+      let text = &line_trimmed[column..column + cell.len()];
+      match cell {
+        [b'[', c, b']'] | [b'[', c, b']', b' '] if c.is_ascii_alphabetic() => {
+          Ok(Some(*c as char))
+        }
+        [b' ', b' ', b' '] | [b' ', b' ', b' ', b' '] => Ok(None),
+        _ => Err(error(column, text)),
+      }
+    })
+    .collect()
+}
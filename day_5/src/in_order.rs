@@ -1,7 +1,5 @@
-mod in_order_module;
-use in_order_module::ProblemSolverPattern;
-mod problem_solver;
-use problem_solver::solve_problem;
+use aoc_core::problem_solver::solve_problem;
+use day_5::in_order_module::ProblemSolverPattern;
 
 
 fn main() {
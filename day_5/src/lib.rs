@@ -0,0 +1,3 @@
+pub mod common;
+pub mod in_order_module;
+pub mod top_module;
@@ -1,7 +1,5 @@
-mod top_module;
-use top_module::ProblemSolverPattern;
-mod problem_solver;
-use problem_solver::solve_problem;
+use aoc_core::problem_solver::solve_problem;
+use day_5::top_module::ProblemSolverPattern;
 
 
 fn main() {
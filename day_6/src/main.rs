@@ -1,8 +1,7 @@
 use axum::{routing::post, Router};
 mod tuning_trouble_module;
+use aoc_core::problem_solver_contexts::{solve_problem, ProblemContexts};
 use tuning_trouble_module::{PSContext, ProblemSolverPattern};
-mod problem_solver_shuttle_axum;
-use problem_solver_shuttle_axum::{solve_problem, ProblemContexts};
 
 
 const PACKET_WINDOW_SIZE: usize = 4;
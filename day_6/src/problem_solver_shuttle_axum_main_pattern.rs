@@ -1,8 +1,7 @@
 use axum::{routing::post, Router};
 mod problem_solver_shuttle_axum_module_pattern;
+use aoc_core::problem_solver_contexts::solve_problem;
 use problem_solver_shuttle_axum_module_pattern::ProblemSolverPattern;
-mod problem_solver_shuttle_axum;
-use problem_solver_shuttle_axum::solve_problem;
 
 
 async fn problem_solver_axum(payload: String) -> &'static str {
@@ -1,10 +1,12 @@
-use super::problem_solver_shuttle_axum::ProblemSolver;
+use aoc_core::problem_solver_contexts::{ParseError, ProblemSolver};
+use serde::Serialize;
 
 
 pub struct PSInput {
   // Define the structure of input data for the problem
 }
 
+#[derive(Serialize)]
 pub struct PSSolution {
   // Define the structure of the problem solution
 }
@@ -32,7 +34,7 @@ impl ProblemSolver for ProblemSolverPattern {
   fn initialize(
     lines: impl Iterator<Item = String>,
     _context: PSContext,
-  ) -> Self::Input {
+  ) -> Result<Self::Input, ParseError> {
     /* Implement initialization logic to prepare the input to this
     solver */
     unimplemented!()
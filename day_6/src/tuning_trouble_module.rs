@@ -1,12 +1,13 @@
+use aoc_core::problem_solver_contexts::{ParseError, ProblemSolver};
+use serde::Serialize;
 use std::collections::HashSet;
 
-use super::problem_solver_shuttle_axum::ProblemSolver;
-
 
 pub struct PSInput {
   stream: String,
 }
 
+#[derive(Serialize)]
 pub struct PSSolution {
   position: i16,
 }
@@ -31,17 +32,18 @@ impl ProblemSolver for ProblemSolverPattern {
   fn initialize(
     lines: impl Iterator<Item = String>,
     _: Option<PSContext>,
-  ) -> Self::Input {
+  ) -> Result<Self::Input, ParseError> {
     let mut lines = lines.peekable();
     match lines.next() {
-      Some(stream) => {
-        if lines.next().is_none() {
-          Self::Input { stream }
-        } else {
-          panic!("Expected only one line, but found multiple lines");
-        }
-      }
-      None => panic!("No input lines found"),
+      Some(stream) => match lines.next() {
+        None => Ok(Self::Input { stream }),
+        Some(extra) => Err(ParseError::new(
+          1,
+          &extra,
+          "expected only one line, but found multiple lines",
+        )),
+      },
+      None => Err(ParseError::whole_input("no input lines found")),
     }
   }
 
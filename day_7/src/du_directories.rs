@@ -25,9 +25,10 @@ fn propagate_values_up_tree(
     let mut edges = graph.neighbors_directed(current_node, Incoming).detach();
     while let Some(edge) = edges.next_edge(graph) {
       let (source, target) = graph.edge_endpoints(edge).unwrap();
-      println!(
-        "{} | {} : {}",
-        graph[current_node], graph[source], graph[target]
+      tracing::trace!(
+        current = graph[current_node],
+        source = graph[source],
+        target = graph[target]
       );
       if let Some(new_value) = graph[source].checked_add(graph[node]) {
         graph[source] = new_value;
@@ -1,44 +1,61 @@
+use aoc_core::problem_solver::ParseError;
 use petgraph::stable_graph::{NodeIndex, StableGraph};
 use petgraph::Directed;
 use std::collections::HashMap;
 
 
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum LineType {
+  Command,
+  DirectoryResponse,
+  FileResponse,
+}
+
 pub fn factory_fs_graph(
   lines: impl Iterator<Item = String>,
-) -> StableGraph<u64, u64, Directed> {
+) -> Result<StableGraph<u64, u64, Directed>, ParseError> {
   let mut graph = StableGraph::<u64, u64, Directed>::new();
   let shadow_root_index = graph.add_node(0);
   let mut cwd: Vec<NodeIndex> = vec![shadow_root_index];
   let mut parent_to_child: HashMap<NodeIndex, Vec<(String, NodeIndex)>> =
     HashMap::new();
 
-  for line in lines {
+  for (index, line) in lines.enumerate() {
+    let error = |message: &str| ParseError::new(index, &line, message);
     match identify_line_type(&line) {
-      "Command" => change_directory(
-        factory_current_working_directory(&line),
-        &mut graph,
-        &mut cwd,
-        &mut parent_to_child,
-      ),
-      "Directory Response" => process_record(
-        factory_directory_node(&line),
+      Some(LineType::Command) if line.trim() == "$ ls" => {}
+      Some(LineType::Command) => {
+        let dir = factory_current_working_directory(&line)
+          .ok_or_else(|| error("expected `$ cd <dir>` or `$ ls`"))?;
+        if dir == ".." && cwd.len() < 2 {
+          return Err(error("cannot leave the root directory").at(".."));
+        }
+        change_directory(Some(dir), &mut graph, &mut cwd, &mut parent_to_child)
+      }
+      Some(LineType::DirectoryResponse) => process_record(
+        factory_directory_node(&line)
+          .ok_or_else(|| error("expected `dir <name>`"))?,
         &mut graph,
         &mut cwd,
         &mut parent_to_child,
       ),
-      "File Response" => process_record(
-        factory_file_node(&line),
+      Some(LineType::FileResponse) => process_record(
+        factory_file_node(&line)
+          .ok_or_else(|| error("expected `<size> <name>`"))?,
         &mut graph,
         &mut cwd,
         &mut parent_to_child,
       ),
-      _ => unreachable!(),
+      None => {
+        return Err(error(
+          "expected a `$` command, a `dir` entry or a file size",
+        ))
+      }
     }
   }
 
-  println!(
-    "{:?}",
-    graph
+  tracing::trace!(
+    edges = ?graph
       .edge_indices()
       .map(|i| graph.edge_endpoints(i).unwrap())
       .collect::<Vec<_>>()
@@ -49,16 +66,15 @@ pub fn factory_fs_graph(
   .flat_map(|vec| vec.iter().cloned().map(|(name, index)| (index, name)))
   .collect();*/
 
-  graph
+  Ok(graph)
 }
 
-fn identify_line_type(line: &str) -> &str {
-  match line.trim_start().chars().next() {
-    Some('$') => "Command",
-    Some('d') => "Directory Response",
-    Some(c) if c.is_ascii_digit() => "File Response",
-    Some(_) => unreachable!(),
-    None => unreachable!(),
+fn identify_line_type(line: &str) -> Option<LineType> {
+  match line.trim_start().chars().next()? {
+    '$' => Some(LineType::Command),
+    'd' => Some(LineType::DirectoryResponse),
+    c if c.is_ascii_digit() => Some(LineType::FileResponse),
+    _ => None,
   }
 }
 
@@ -96,22 +112,20 @@ fn change_directory(
 }
 
 fn process_record(
-  n: Option<(Option<String>, u64)>,
+  (dopt, size): (Option<String>, u64),
   graph: &mut StableGraph<u64, u64, Directed>,
   cwd: &mut [NodeIndex],
   parent_to_child: &mut HashMap<NodeIndex, Vec<(String, NodeIndex)>>,
 ) {
   let cwdi = cwd.last().cloned().unwrap();
-  if let Some((dopt, size)) = n {
-    let name = dopt.unwrap_or(String::new());
-    let i = graph.add_node(size);
-    graph.add_edge(cwdi, i, size);
-    if !name.is_empty() {
-      parent_to_child
-        .entry(cwdi)
-        .or_insert_with(Vec::<(String, NodeIndex)>::new);
-      parent_to_child.get_mut(&cwdi).unwrap().push((name, i));
-    }
+  let name = dopt.unwrap_or(String::new());
+  let i = graph.add_node(size);
+  graph.add_edge(cwdi, i, size);
+  if !name.is_empty() {
+    parent_to_child
+      .entry(cwdi)
+      .or_insert_with(Vec::<(String, NodeIndex)>::new);
+    parent_to_child.get_mut(&cwdi).unwrap().push((name, i));
   }
 }
 
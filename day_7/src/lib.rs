@@ -0,0 +1,4 @@
+pub mod du_directories;
+pub mod fs_graph;
+pub mod smallest_large_module;
+pub mod total_smalls_module;
@@ -1,9 +1,5 @@
-mod smallest_large_module;
-use smallest_large_module::ProblemSolverPattern;
-mod problem_solver;
-use problem_solver::solve_problem;
-mod du_directories;
-mod fs_graph;
+use aoc_core::problem_solver::solve_problem;
+use day_7::smallest_large_module::ProblemSolverPattern;
 
 
 fn main() {
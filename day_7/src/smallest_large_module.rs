@@ -1,18 +1,22 @@
+use aoc_core::problem_solver::{ParseError, ProblemSolver};
 use petgraph::stable_graph::{NodeIndex, StableGraph};
 use petgraph::Directed;
+use serde::Serialize;
 
 use super::du_directories::du_directories;
 use super::fs_graph::factory_fs_graph;
-use super::problem_solver::ProblemSolver;
 
 
 const TOTAL_SPACE: u64 = 70_000_000;
 const THRESHOLD: u64 = 30_000_000;
+/* the directory the first `$ cd` enters, holding every other one */
+const OUTERMOST: usize = 1;
 
 pub struct PSInput {
   graph: StableGraph<u64, u64, Directed>,
 }
 
+#[derive(Serialize)]
 pub struct PSSolution {
   smallest: u64,
 }
@@ -23,23 +27,40 @@ impl ProblemSolver for ProblemSolverPattern {
   type Input = PSInput;
   type Solution = PSSolution;
 
-  fn initialize(lines: impl Iterator<Item = String>) -> Self::Input {
-    let graph = factory_fs_graph(lines);
+  /* the directory sizes, checked to leave too little free space for the
+  update yet fit on the disk */
+  fn initialize(
+    lines: impl Iterator<Item = String>,
+  ) -> Result<Self::Input, ParseError> {
+    let graph = du_directories(factory_fs_graph(lines)?);
+    let Some(&used) = graph.node_weight(NodeIndex::new(OUTERMOST)) else {
+      return Err(ParseError::whole_input("the listing enters no directory"));
+    };
+    let Some(free_space) = TOTAL_SPACE.checked_sub(used) else {
+      return Err(ParseError::whole_input(format!(
+        "the listing uses {used}, more than the disk's {TOTAL_SPACE}"
+      )));
+    };
+    if free_space >= THRESHOLD {
+      return Err(ParseError::whole_input(format!(
+        "{free_space} is already free, no directory needs deleting"
+      )));
+    }
 
-    Self::Input { graph }
+    Ok(Self::Input { graph })
   }
 
   fn solve(input: Self::Input) -> Self::Solution {
-    let graph = du_directories(input.graph);
+    let graph = input.graph;
 
-    let free_space = TOTAL_SPACE - graph[NodeIndex::new(1)];
+    let free_space = TOTAL_SPACE - graph[NodeIndex::new(OUTERMOST)];
     let target = THRESHOLD - free_space;
 
     let smallest_large = graph
       .node_indices()
       .filter(|&node| graph[node] > target)
       .min_by_key(|&node| graph[node])
-      .unwrap();
+      .expect("the outermost directory frees enough");
 
     let smallest = graph[smallest_large];
 
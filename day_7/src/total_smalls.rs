@@ -1,9 +1,5 @@
-mod total_smalls_module;
-use total_smalls_module::ProblemSolverPattern;
-mod problem_solver;
-use problem_solver::solve_problem;
-mod du_directories;
-mod fs_graph;
+use aoc_core::problem_solver::solve_problem;
+use day_7::total_smalls_module::ProblemSolverPattern;
 
 
 fn main() {
@@ -1,15 +1,17 @@
+use aoc_core::problem_solver::{ParseError, ProblemSolver};
 use petgraph::stable_graph::StableGraph;
 use petgraph::Directed;
+use serde::Serialize;
 
 use super::du_directories::du_directories;
 use super::fs_graph::factory_fs_graph;
-use super::problem_solver::ProblemSolver;
 
 
 pub struct PSInput {
   graph: StableGraph<u64, u64, Directed>,
 }
 
+#[derive(Serialize)]
 pub struct PSSolution {
   sum: u64,
 }
@@ -20,10 +22,12 @@ impl ProblemSolver for ProblemSolverPattern {
   type Input = PSInput;
   type Solution = PSSolution;
 
-  fn initialize(lines: impl Iterator<Item = String>) -> Self::Input {
-    let graph = factory_fs_graph(lines);
+  fn initialize(
+    lines: impl Iterator<Item = String>,
+  ) -> Result<Self::Input, ParseError> {
+    let graph = factory_fs_graph(lines)?;
 
-    Self::Input { graph }
+    Ok(Self::Input { graph })
   }
 
   fn solve(input: Self::Input) -> Self::Solution {
@@ -31,11 +35,7 @@ impl ProblemSolver for ProblemSolverPattern {
 
     let sum = graph.node_indices().fold(0, |acc, curr| {
       if graph[curr] <= 100_000 && acc < u64::MAX {
-        if let Some(new_sum) = acc.checked_add(graph[curr]) {
-          new_sum
-        } else {
-          u64::MAX
-        }
+        acc.saturating_add(graph[curr])
       } else {
         acc
       }
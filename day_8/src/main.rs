@@ -1,22 +1,13 @@
+use aoc_core::problem_solver_service::solve_problem;
+use aoc_core::trace::{self, LevelFilter};
 use axum::{routing::post, Router};
-use std::env;
+use day_8::{scenic_score::Part2Solver, visible::Part1Solver};
 use std::net::SocketAddr;
 
-mod visible;
-use visible::Part1Solver;
-mod scenic_score;
-use scenic_score::Part2Solver;
-mod problem_solver_service;
-use problem_solver_service::solve_problem;
-mod common;
-
 
 #[tokio::main]
 async fn main() {
-  if env::var("RUST_LOG").is_err() {
-    env::set_var("RUST_LOG", "debug");
-  }
-  tracing_subscriber::fmt::init(); // initialize tracing
+  trace::init(LevelFilter::DEBUG);
 
   let app = Router::new()
     .route("/part_1", post(part_1))
@@ -1,10 +1,12 @@
-use super::problem_solver::ProblemSolver;
+use aoc_core::problem_solver_service::{ParseError, ProblemSolver};
+use serde::Serialize;
 
 
 pub struct PSInput {
   // Define the structure of input data for the problem
 }
 
+#[derive(Serialize)]
 pub struct PSSolution {
   // Define the structure of the problem solution
 }
@@ -15,7 +17,9 @@ impl ProblemSolver for Part2Solver {
   type Input = PSInput;
   type Solution = PSSolution;
 
-  fn initialize(lines: impl Iterator<Item = String>) -> Self::Input {
+  fn initialize(
+    lines: impl Iterator<Item = String>,
+  ) -> Result<Self::Input, ParseError> {
     /* Implement initialization logic to prepare the input to this
     solver */
     unimplemented!()
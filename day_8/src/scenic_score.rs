@@ -1,10 +1,14 @@
-use super::common::{get_map, rotate_2d_collection};
-use super::problem_solver_service::ProblemSolver;
+use aoc_core::grid::Grid;
+use aoc_core::problem_solver_service::{ParseError, ProblemSolver};
+use serde::Serialize;
+
+use super::common::get_map;
 
 pub struct PSInput {
-  map: Vec<Vec<u32>>,
+  map: Grid<u32>,
 }
 
+#[derive(Serialize)]
 pub struct PSSolution {
   score: usize,
 }
@@ -15,13 +19,15 @@ impl ProblemSolver for Part2Solver {
   type Input = PSInput;
   type Solution = PSSolution;
 
-  fn initialize(lines: impl Iterator<Item = String>) -> Self::Input {
-    let map = get_map::<u32>(lines);
-    Self::Input { map }
+  fn initialize(
+    lines: impl Iterator<Item = String>,
+  ) -> Result<Self::Input, ParseError> {
+    let map = get_map::<u32>(lines)?;
+    Ok(Self::Input { map })
   }
 
   fn solve(input: Self::Input) -> Self::Solution {
-    let rotated = rotate_2d_collection(&input.map);
+    let rotated = input.map.transpose();
 
     let score = calculate_best_score(&input.map, &rotated);
 
@@ -33,11 +39,11 @@ impl ProblemSolver for Part2Solver {
   }
 }
 
-fn calculate_best_score(map: &Vec<Vec<u32>>, rotated: &[Vec<u32>]) -> usize {
-  let last_y = map.len() - 1;
-  let last_x = map[0].len() - 1;
+fn calculate_best_score(map: &Grid<u32>, rotated: &Grid<u32>) -> usize {
+  let last_y = map.height() - 1;
+  let last_x = map.width() - 1;
   map
-    .iter()
+    .rows()
     .enumerate()
     .map(|(i, row)| {
       if i == 0 || i == last_y {
@@ -58,10 +64,10 @@ fn calculate_best_score(map: &Vec<Vec<u32>>, rotated: &[Vec<u32>]) -> usize {
 
 fn get_scenic_score(
   pos: (usize, usize),
-  map: &[Vec<u32>],
-  rotated: &[Vec<u32>],
+  map: &Grid<u32>,
+  rotated: &Grid<u32>,
 ) -> usize {
-  let tree = map[pos.0][pos.1];
+  let tree = map.row(pos.0)[pos.1];
   let cardinal_view = |(index, &t)| {
     if tree <= t {
       Some(index + 1)
@@ -69,41 +75,41 @@ fn get_scenic_score(
       None
     }
   };
-  let left = map[pos.0][..pos.1]
+  let left = map.row(pos.0)[..pos.1]
     .iter()
     .rev()
     .enumerate()
     .find_map(cardinal_view)
-    .unwrap_or(map[pos.0][..pos.1].len());
-  let right = map[pos.0][pos.1 + 1..]
+    .unwrap_or(map.row(pos.0)[..pos.1].len());
+  let right = map.row(pos.0)[pos.1 + 1..]
     .iter()
     .enumerate()
     .find_map(cardinal_view)
-    .unwrap_or(map[pos.0][pos.1 + 1..].len());
-  let up = rotated[pos.1][..pos.0]
+    .unwrap_or(map.row(pos.0)[pos.1 + 1..].len());
+  let up = rotated.row(pos.1)[..pos.0]
     .iter()
     .rev()
     .enumerate()
     .find_map(cardinal_view)
-    .unwrap_or(rotated[pos.1][..pos.0].len());
-  let down = rotated[pos.1][pos.0 + 1..]
+    .unwrap_or(rotated.row(pos.1)[..pos.0].len());
+  let down = rotated.row(pos.1)[pos.0 + 1..]
     .iter()
     .enumerate()
     .find_map(cardinal_view)
-    .unwrap_or(rotated[pos.1][pos.0 + 1..].len());
+    .unwrap_or(rotated.row(pos.1)[pos.0 + 1..].len());
 
-  println!(
+  tracing::debug!(
     "{} {:?} => {} [{:?}:{} {:?}:{} {:?}:{} {:?}:{}]",
     tree,
     pos,
     left * right * up * down,
-    map[pos.0][..pos.1].iter().rev().collect::<Vec<_>>(),
+    map.row(pos.0)[..pos.1].iter().rev().collect::<Vec<_>>(),
     left,
-    map[pos.0][pos.1 + 1..].to_vec(),
+    map.row(pos.0)[pos.1 + 1..].to_vec(),
     right,
-    rotated[pos.1][..pos.0].iter().rev().collect::<Vec<_>>(),
+    rotated.row(pos.1)[..pos.0].iter().rev().collect::<Vec<_>>(),
     up,
-    rotated[pos.1][pos.0 + 1..].to_vec(),
+    rotated.row(pos.1)[pos.0 + 1..].to_vec(),
     down
   );
 
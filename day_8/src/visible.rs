@@ -1,11 +1,15 @@
-use super::common::{get_map, rotate_2d_collection};
-use super::problem_solver_service::ProblemSolver;
+use aoc_core::grid::Grid;
+use aoc_core::problem_solver_service::{ParseError, ProblemSolver};
+use serde::Serialize;
+
+use super::common::get_map;
 
 
 pub struct PSInput {
-  map: Vec<Vec<u8>>,
+  map: Grid<u8>,
 }
 
+#[derive(Serialize)]
 pub struct PSSolution {
   visible: u32,
 }
@@ -16,15 +20,17 @@ impl ProblemSolver for Part1Solver {
   type Input = PSInput;
   type Solution = PSSolution;
 
-  fn initialize(lines: impl Iterator<Item = String>) -> Self::Input {
-    let map = get_map::<u8>(lines);
-    Self::Input { map }
+  fn initialize(
+    lines: impl Iterator<Item = String>,
+  ) -> Result<Self::Input, ParseError> {
+    let map = get_map::<u8>(lines)?;
+    Ok(Self::Input { map })
   }
 
   fn solve(input: Self::Input) -> Self::Solution {
-    let rotated = rotate_2d_collection(&input.map);
-    let last_y = input.map.len() - 1;
-    let last_x = input.map[0].len() - 1;
+    let rotated = input.map.transpose();
+    let last_y = input.map.height() - 1;
+    let last_x = input.map.width() - 1;
 
     let visible = calculate_visible(&input.map, &rotated, last_x, last_y);
 
@@ -37,20 +43,20 @@ impl ProblemSolver for Part1Solver {
 }
 
 fn calculate_visible(
-  map: &[Vec<u8>],
-  rotated: &[Vec<u8>],
+  map: &Grid<u8>,
+  rotated: &Grid<u8>,
   last_x: usize,
   last_y: usize,
 ) -> u32 {
   map
-    .iter()
+    .rows()
     .enumerate()
     .map(|(i, row)| {
       row.iter().enumerate().fold(0, |acc, (j, tree)| {
         let max_left = row[..j].iter().max().unwrap_or(&0);
         let max_right = row[j + 1..].iter().max().unwrap_or(&0);
-        let max_up = rotated[j][..i].iter().max().unwrap_or(&0);
-        let max_down = rotated[j][i + 1..].iter().max().unwrap_or(&0);
+        let max_up = rotated.row(j)[..i].iter().max().unwrap_or(&0);
+        let max_down = rotated.row(j)[i + 1..].iter().max().unwrap_or(&0);
 
         tracing::debug!(
           "{} | l{} r{} u{} d{}",
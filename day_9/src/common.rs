@@ -1,3 +1,4 @@
+use aoc_core::problem_solver_service::ParseError;
 use regex::Regex;
 use std::collections::HashSet;
 
@@ -25,21 +26,29 @@ impl Directions {
 #[derive(Debug, Clone, Copy)]
 pub struct Move {
   pub direction: Directions,
+  #[allow(dead_code)]
   pub noncardinal_ternary: Option<Directions>,
   pub distance: u8,
 }
 
-pub fn factory_move(record: String) -> Move {
+pub fn factory_move(index: usize, record: String) -> Result<Move, ParseError> {
   let re: Regex = Regex::new(r"([UDLR]) (\d+)").unwrap();
 
   if let Some(captures) = re.captures(&record) {
     let dir_str = captures[1].parse::<String>().unwrap();
-    let distance = captures[2].parse::<u8>().unwrap();
+    let distance = captures[2].parse::<u8>().map_err(|_| {
+      ParseError::new(index, &record, "expected a distance up to 255")
+        .at(&captures[2])
+    })?;
     let direction = Directions::from(&dir_str);
 
-    Move { direction, distance, noncardinal_ternary: None }
+    Ok(Move { direction, distance, noncardinal_ternary: None })
   } else {
-    panic!("invalid format for line:\n {}", record);
+    Err(ParseError::new(
+      index,
+      &record,
+      "expected '<U|D|L|R> <distance>'",
+    ))
   }
 }
 
@@ -65,10 +74,7 @@ pub fn get_tail_positions(
         Directions::Down => knots[0].1 += 1,
         Directions::Left => knots[0].0 -= 1,
         Directions::Right => knots[0].0 += 1,
-        _ => {
-          println!("Invalid direction");
-          unreachable!();
-        }
+        _ => unreachable!("invalid direction"),
       }
 
       for position in 0..positions - 1 {
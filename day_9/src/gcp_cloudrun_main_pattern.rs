@@ -5,11 +5,10 @@ use hyper::{
   Body, Request, Response, Server,
 };
 use std::convert::Infallible;
+use aoc_core::problem_solver_service::solve_problem;
 
 mod port_state_behavior;
 use port_state_behavior::get_port;
-mod problem_solver_service;
-use problem_solver_service::solve_problem;
 mod gcp_cloudrun_module;
 use gcp_cloudrun_module::CloudRunSolver;
 
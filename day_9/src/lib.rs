@@ -0,0 +1,3 @@
+pub mod common;
+pub mod part_1_module;
+pub mod part_2_module;
@@ -1,3 +1,5 @@
+use aoc_core::problem_solver_service::solve_problem;
+use day_9::part_2_module::CloudRunSolver;
 use hyper::{
   body::to_bytes,
   server::conn::AddrStream,
@@ -8,11 +10,6 @@ use std::convert::Infallible;
 
 mod port_state_behavior;
 use port_state_behavior::get_port;
-mod problem_solver_service;
-use problem_solver_service::solve_problem;
-mod part_2_module;
-use part_2_module::CloudRunSolver;
-mod common;
 
 
 #[tokio::main]
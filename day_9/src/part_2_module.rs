@@ -1,11 +1,14 @@
+use aoc_core::problem_solver_service::{ParseError, ProblemSolver};
+use serde::Serialize;
+
 use super::common::*;
-use super::problem_solver_service::ProblemSolver;
 
 
 pub struct PSInput {
   moves: Vec<Move>,
 }
 
+#[derive(Serialize)]
 pub struct PSSolution {
   number_of_positions: usize,
 }
@@ -16,10 +19,15 @@ impl ProblemSolver for CloudRunSolver {
   type Input = PSInput;
   type Solution = PSSolution;
 
-  fn initialize(lines: impl Iterator<Item = String>) -> Self::Input {
-    let moves: Vec<Move> = lines.map(factory_move).collect();
+  fn initialize(
+    lines: impl Iterator<Item = String>,
+  ) -> Result<Self::Input, ParseError> {
+    let moves: Vec<Move> = lines
+      .enumerate()
+      .map(|(index, record)| factory_move(index, record))
+      .collect::<Result<_, _>>()?;
 
-    Self::Input { moves }
+    Ok(Self::Input { moves })
   }
 
 